@@ -1,15 +1,62 @@
-use crypto::aes::{AesDecrypt, AesEncrypt};
+use crypto::aes::{Aes, AesDecrypt, AesEncrypt};
+use crypto::aria::{AriaDecrypt, AriaEncrypt};
 use crypto::base64::{FromBase64, ToBase64};
+use crypto::bigint::BigUint;
+use crypto::blowfish::{BlowfishDecrypt, BlowfishEncrypt, BlowfishTables};
 use crypto::bytes::Bytes;
-use crypto::des::{DesDecrypt, DesEncrypt, TripleDesDecrypt, TripleDesEncrypt};
+use crypto::camellia::{CamelliaDecrypt, CamelliaEncrypt};
+use crypto::cast::{Cast5Decrypt, Cast5Encrypt, Cast6Decrypt, Cast6Encrypt};
+use crypto::chacha20::{ChaCha20, XChaCha20};
+use crypto::chacha20_poly1305::{
+    ChaCha20Poly1305Decrypt, ChaCha20Poly1305Encrypt, XChaCha20Poly1305Decrypt,
+    XChaCha20Poly1305Encrypt,
+};
+use crypto::curve25519::{Ed25519KeyPair, Ed25519Sign, Ed25519Verify, X25519KeyPair, X25519};
+use crypto::des::{
+    check_odd_parity, is_degenerate_triple_des_key, is_weak_key, set_odd_parity, DesDecrypt,
+    DesEncrypt, DesXDecrypt, DesXEncrypt, TripleDesDecrypt, TripleDesEncrypt,
+};
+use crypto::dh::{Dh, DhGroup, DhKeyPair, DiscreteLog};
+use crypto::dsa::{DsaSign, DsaVerify};
+use crypto::ec::{EcCurve, EcKeyPair, Ecdh, EcdsaAttack, EcdsaSample, EcdsaSign, EcdsaVerify};
+use crypto::elgamal::{ElGamalDecrypt, ElGamalEncrypt, ElGamalSign, ElGamalVerify};
+use crypto::enums::Endian;
 use crypto::hex::{FromHex, ToHex};
-use crypto::mode::{Cbc, Cfb, Ctr, Ecb, Gcm, Ofb};
+use crypto::idea::{IdeaDecrypt, IdeaEncrypt};
+use crypto::key_wrap::{KeyUnwrap, KeyWrap};
+use crypto::kuznyechik::{KuznyechikDecrypt, KuznyechikEncrypt};
+use crypto::magma::{MagmaDecrypt, MagmaEncrypt, MagmaParams, MagmaSBox};
+use crypto::mode::{
+    Cbc, Ccm, Cfb, Ctr, Cts, CtsVariant, Eax, Ecb, Gcm, GcmSiv, Ige, Ocb, Ofb, Pcbc, Siv, Xts,
+};
+use crypto::operation::Operation;
 use crypto::padding::{NoPadding, Pkcs7Padding, ZeroPadding};
+use crypto::poly1305::Poly1305;
+use crypto::rc2::{Rc2Decrypt, Rc2Encrypt};
 use crypto::rc4::Rc4;
+use crypto::rc5::{Rc5Decrypt, Rc5Encrypt, Rc5Params};
+use crypto::rc6::{Rc6Decrypt, Rc6Encrypt, Rc6Params};
 use crypto::recipe::Recipe;
-use crypto::sm4::{Sm4Decrypt, Sm4Encrypt};
-use crypto::tea::{XxteaDecrypt, XxteaEncrypt};
+use crypto::rsa::{
+    Oaep, Pkcs1v15Encryption, Pkcs1v15Signature, Pss, RsaAttack, RsaDecrypt, RsaEncrypt,
+    RsaPrivateKey, RsaRaw, RsaSign, RsaVerify,
+};
+use crypto::salsa20::{Salsa20, XSalsa20};
+use crypto::seed::{SeedDecrypt, SeedEncrypt};
+use crypto::serpent::{SerpentDecrypt, SerpentEncrypt};
+use crypto::sha1::Sha1;
+use crypto::sha2::Sha256;
+use crypto::sm2::{
+    Sm2Decrypt, Sm2Encrypt, Sm2KeyExchange, Sm2KeyPair, Sm2Layout, Sm2Sign, Sm2Verify,
+    DEFAULT_USER_ID,
+};
+use crypto::sm4::{Sm4, Sm4Decrypt, Sm4Encrypt};
+use crypto::tea::{
+    TeaDecrypt, TeaEncrypt, TeaParams, XteaDecrypt, XteaEncrypt, XxteaDecrypt, XxteaEncrypt,
+};
+use crypto::twofish::{TwofishDecrypt, TwofishEncrypt};
 use crypto::types::Result;
+use crypto::zuc::{Eea3, Eia3, Zuc};
 
 fn main() -> Result<()> {
     // RC4
@@ -26,6 +73,97 @@ fn main() -> Result<()> {
     println!("---- ---- ---- ---- ----");
     println!();
 
+    // Salsa20 / ChaCha20
+    println!("---- ---- ChaCha20 ---- ----");
+    let stream_input = Bytes::new(b"Hello, World!".as_ref());
+    let stream_key = Bytes::new(b"0123456789abcdef0123456789abcdef".as_ref());
+
+    let salsa20_op = Salsa20::new(&stream_key, b"Nonce-08", 0, 20);
+    let recipe_salsa20 = Recipe::new(vec![Box::new(salsa20_op), Box::new(ToHex::default())]);
+    println!("{}", recipe_salsa20.bake(&stream_input)?);
+    let xsalsa20_op = XSalsa20::new(&stream_key, b"XSalsa20-Nonce-24-Bytes!", 0, 20);
+    println!(
+        "{}",
+        ToHex::default().run(&xsalsa20_op.run(&stream_input)?)?
+    );
+
+    // 8字节nonce为原始版本 12字节nonce为IETF版本
+    let chacha20_op = ChaCha20::new(&stream_key, b"Nonce-12Byte", 1, 20);
+    let chacha20_output = chacha20_op.run(&stream_input)?;
+    println!("{}", ToHex::default().run(&chacha20_output)?);
+    println!("{}", chacha20_op.run(&chacha20_output)?);
+    let chacha8_op = ChaCha20::new(&stream_key, b"Nonce-08", 0, 8);
+    println!("{}", ToHex::default().run(&chacha8_op.run(&stream_input)?)?);
+    let xchacha20_op = XChaCha20::new(&stream_key, b"XChaCha20-Nonce-24-Bytes", 0, 20);
+    println!(
+        "{}",
+        ToHex::default().run(&xchacha20_op.run(&stream_input)?)?
+    );
+
+    let poly1305_op = Poly1305::new(&stream_key);
+    println!(
+        "{}",
+        ToHex::default().run(&poly1305_op.run(&stream_input)?)?
+    );
+
+    let aead_aad = Bytes::new(b"Additional Data".as_ref());
+    let chacha20_poly1305_encrypt =
+        ChaCha20Poly1305Encrypt::new(&stream_key, b"Nonce-12Byte", Some(&aead_aad));
+    let recipe_chacha20_poly1305 = Recipe::new(vec![
+        Box::new(chacha20_poly1305_encrypt),
+        Box::new(ToHex::default()),
+    ]);
+    let chacha20_poly1305_output = recipe_chacha20_poly1305.bake(&stream_input)?;
+    println!("{}", chacha20_poly1305_output);
+    let chacha20_poly1305_decrypt =
+        ChaCha20Poly1305Decrypt::new(&stream_key, b"Nonce-12Byte", Some(&aead_aad));
+    let recipe_chacha20_poly1305_decrypt = Recipe::new(vec![
+        Box::new(FromHex::default()),
+        Box::new(chacha20_poly1305_decrypt),
+    ]);
+    println!(
+        "{}",
+        recipe_chacha20_poly1305_decrypt.bake(&chacha20_poly1305_output)?
+    );
+
+    let xchacha20_poly1305_encrypt =
+        XChaCha20Poly1305Encrypt::new(&stream_key, b"XChaCha20-Nonce-24-Bytes", None);
+    let xchacha20_poly1305_output = xchacha20_poly1305_encrypt.run(&stream_input)?;
+    println!("{}", ToHex::default().run(&xchacha20_poly1305_output)?);
+    let xchacha20_poly1305_decrypt =
+        XChaCha20Poly1305Decrypt::new(&stream_key, b"XChaCha20-Nonce-24-Bytes", None);
+    println!(
+        "{}",
+        xchacha20_poly1305_decrypt.run(&xchacha20_poly1305_output)?
+    );
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // ZUC
+    println!("---- ---- ZUC ---- ----");
+    let zuc_key = Bytes::new(b"ZUC 128 bit key!".as_ref());
+    let zuc_iv = Bytes::new(b"ZUC 128 bit iv!!".as_ref());
+    let zuc_op = Zuc::new(&zuc_key, &zuc_iv);
+    let zuc_output = zuc_op.run(&stream_input)?;
+    println!("{}", ToHex::default().run(&zuc_output)?);
+    println!("{}", zuc_op.run(&zuc_output)?);
+
+    // LTE 中由 COUNT、BEARER、DIRECTION 构造 iv
+    let eea3_op = Eea3::new(&zuc_key, 0x66035492, 0x0f, 0);
+    let eea3_output = eea3_op.run(&stream_input)?;
+    println!("{}", ToHex::default().run(&eea3_output)?);
+    println!("{}", eea3_op.run(&eea3_output)?);
+    let eia3_op = Eia3::new(&zuc_key, 0x66035492, 0x0f, 0);
+    println!("{}", ToHex::default().run(&eia3_op.run(&stream_input)?)?);
+    // 消息长度不是整字节时指定比特长度
+    let eia3_bits_op = Eia3::with_length(&zuc_key, 0x66035492, 0x0f, 0, 90);
+    println!(
+        "{}",
+        ToHex::default().run(&eia3_bits_op.run(&stream_input)?)?
+    );
+    println!("---- ---- ---- ---- ----");
+    println!();
+
     // DES
     println!("---- ---- DES ---- ----");
     let des_input = Bytes::new(b"Hello, World!Hello, World!Hello, World!".as_ref());
@@ -134,6 +272,21 @@ fn main() -> Result<()> {
     let triple_des_ctr_decrypt_result =
         recipe_ctr_3des_decrypt.bake(&triple_des_ctr_encrypt_result)?;
     println!("{}", triple_des_ctr_decrypt_result);
+
+    // DESX 密钥为 DES 密钥 || 前白化密钥 || 后白化密钥
+    let desx_key = Bytes::new("12345678abcdefgh87654321".as_bytes());
+    let desx_encrypt = DesXEncrypt::<_, Pkcs7Padding>::new(&desx_key, Cbc::new(&triple_des_iv));
+    let desx_output = desx_encrypt.run(&des_input)?;
+    println!("{}", ToHex::default().run(&desx_output)?);
+    let desx_decrypt = DesXDecrypt::<_, Pkcs7Padding>::new(&desx_key, Cbc::new(&triple_des_iv));
+    println!("{}", desx_decrypt.run(&desx_output)?);
+
+    // 密钥校验
+    let parity_key = set_odd_parity(&triple_des_key);
+    println!("{}", ToHex::default().run(&parity_key)?);
+    println!("{}", check_odd_parity(&parity_key));
+    println!("{}", is_weak_key(&[0x01; 8]));
+    println!("{}", is_degenerate_triple_des_key(b"1234567812345678"));
     println!("---- ---- ---- ---- ----");
     println!();
 
@@ -168,6 +321,28 @@ fn main() -> Result<()> {
     let aes_cfb_decrypt_result = recipe_cfb_aes_decrypt.bake(&aes_cfb_output)?;
     println!("{}", aes_cfb_decrypt_result);
 
+    // CFB-8 与 CFB-1 (openssl aes-256-cfb8 / aes-256-cfb1)
+    let aes_cfb8 = Cfb::with_segment_size(&aes_cfb_iv, 8);
+    let aes_cfb8_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_cfb8.clone());
+    let aes_cfb8_output = aes_cfb8_encrypt.run(&aes_input)?;
+    println!("{}", ToHex::default().run(&aes_cfb8_output)?);
+    let aes_cfb8_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_cfb8);
+    println!("{}", aes_cfb8_decrypt.run(&aes_cfb8_output)?);
+    let aes_cfb1 = Cfb::with_segment_size(&aes_cfb_iv, 1);
+    let aes_cfb1_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_cfb1);
+    println!(
+        "{}",
+        ToHex::default().run(&aes_cfb1_encrypt.run(&aes_input)?)?
+    );
+
+    // 8位反馈的 OFB
+    let aes_ofb8 = Ofb::with_feedback_size(&aes_cfb_iv, 8);
+    let aes_ofb8_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ofb8.clone());
+    let aes_ofb8_output = aes_ofb8_encrypt.run(&aes_input)?;
+    println!("{}", ToHex::default().run(&aes_ofb8_output)?);
+    let aes_ofb8_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ofb8);
+    println!("{}", aes_ofb8_decrypt.run(&aes_ofb8_output)?);
+
     let aes_ctr_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, Ctr::new(&aes_cfb_iv));
     let recipe_ctr_aes = Recipe::new(vec![Box::new(aes_ctr_encrypt), Box::new(ToHex::default())]);
     let aes_ctr_output = recipe_ctr_aes.bake(&aes_input)?;
@@ -180,6 +355,24 @@ fn main() -> Result<()> {
     let aes_ctr_decrypt_result = recipe_ctr_aes_decrypt.bake(&aes_ctr_output)?;
     println!("{}", aes_ctr_decrypt_result);
 
+    // 128位大端计数器 (openssl aes-256-ctr)
+    let aes_ctr128 = Ctr::with_counter(&aes_cfb_iv, 128, Endian::Big);
+    let aes_ctr128_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ctr128.clone());
+    let aes_ctr128_output = aes_ctr128_encrypt.run(&aes_input)?;
+    println!("{}", ToHex::default().run(&aes_ctr128_output)?);
+    // 从第20个字节开始解密
+    let aes_ctr128_decrypt =
+        AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ctr128.with_offset(20));
+    println!("{}", aes_ctr128_decrypt.run(&aes_ctr128_output[20..])?);
+
+    // nonce || 32位小端计数器
+    let aes_ctr_le = Ctr::from_nonce(b"Nonce-12Byte", 1, 32, Endian::Little)?;
+    let aes_ctr_le_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ctr_le.clone());
+    let aes_ctr_le_output = aes_ctr_le_encrypt.run(&aes_input)?;
+    println!("{}", ToHex::default().run(&aes_ctr_le_output)?);
+    let aes_ctr_le_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ctr_le);
+    println!("{}", aes_ctr_le_decrypt.run(&aes_ctr_le_output)?);
+
     let aes_gcm_iv = Bytes::new("co et eiusmod euABCD".as_bytes());
     let aes_gcm_aad = Bytes::new(b"Additional Auth Data".as_ref());
     let aes_gcm_encrypt =
@@ -203,6 +396,110 @@ fn main() -> Result<()> {
     */
     let aes_gcm_decrypt_result = recipe_gcm_aes_decrypt.bake(&aes_gcm_output)?;
     println!("{}", aes_gcm_decrypt_result);
+
+    let aes_ccm_nonce = Bytes::new(b"co et eiusmo".as_ref());
+    let aes_ccm_encrypt = AesEncrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Ccm::new(&aes_ccm_nonce, Some(&aes_gcm_aad), 16),
+    );
+    let recipe_ccm_aes = Recipe::new(vec![Box::new(aes_ccm_encrypt), Box::new(ToHex::default())]);
+    let aes_ccm_output = recipe_ccm_aes.bake(&aes_input)?;
+    println!("{}", aes_ccm_output);
+    let aes_ccm_decrypt = AesDecrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Ccm::new(&aes_ccm_nonce, Some(&aes_gcm_aad), 16),
+    );
+    let recipe_ccm_aes_decrypt = Recipe::new(vec![
+        Box::new(FromHex::default()),
+        Box::new(aes_ccm_decrypt),
+    ]);
+    let aes_ccm_decrypt_result = recipe_ccm_aes_decrypt.bake(&aes_ccm_output)?;
+    println!("{}", aes_ccm_decrypt_result);
+
+    let aes_eax_encrypt = AesEncrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Eax::new(&aes_gcm_iv, Some(&aes_gcm_aad), 16),
+    );
+    let aes_eax_output = aes_eax_encrypt.run(&aes_input)?;
+    println!("{:?}", aes_eax_output);
+    let aes_eax_decrypt = AesDecrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Eax::new(&aes_gcm_iv, Some(&aes_gcm_aad), 16),
+    );
+    println!("{}", aes_eax_decrypt.run(&aes_eax_output)?);
+
+    let aes_ocb_encrypt = AesEncrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Ocb::new(&aes_ccm_nonce, Some(&aes_gcm_aad), 16),
+    );
+    let aes_ocb_output = aes_ocb_encrypt.run(&aes_input)?;
+    println!("{:?}", aes_ocb_output);
+    let aes_ocb_decrypt = AesDecrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        Ocb::new(&aes_ccm_nonce, Some(&aes_gcm_aad), 16),
+    );
+    println!("{}", aes_ocb_decrypt.run(&aes_ocb_output)?);
+
+    // SIV 的密钥由两个等长的密钥拼接而成
+    let aes_siv_key = Bytes::new(b"FooBar Lorem ipsum dolor sit ame".as_ref());
+    let aes_siv_encrypt = AesEncrypt::<_, NoPadding>::new(
+        &aes_siv_key,
+        Siv::new(Some(&aes_ccm_nonce), &[&aes_gcm_aad, b"More AAD"]),
+    );
+    let aes_siv_output = aes_siv_encrypt.run(&aes_input)?;
+    println!("{:?}", aes_siv_output);
+    let aes_siv_decrypt = AesDecrypt::<_, NoPadding>::new(
+        &aes_siv_key,
+        Siv::new(Some(&aes_ccm_nonce), &[&aes_gcm_aad, b"More AAD"]),
+    );
+    println!("{}", aes_siv_decrypt.run(&aes_siv_output)?);
+
+    let aes_gcm_siv_encrypt = AesEncrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        GcmSiv::new(&aes_ccm_nonce, Some(&aes_gcm_aad)),
+    );
+    let aes_gcm_siv_output = aes_gcm_siv_encrypt.run(&aes_input)?;
+    println!("{:?}", aes_gcm_siv_output);
+    let aes_gcm_siv_decrypt = AesDecrypt::<_, NoPadding>::new(
+        &aes_cfb_key,
+        GcmSiv::new(&aes_ccm_nonce, Some(&aes_gcm_aad)),
+    );
+    println!("{}", aes_gcm_siv_decrypt.run(&aes_gcm_siv_output)?);
+
+    // PCBC 与 IGE 输入长度需为分组长度的整数倍
+    let aes_block_input =
+        Bytes::new(b"Lorem ipsum dolor sit amet, consectetur adipiscing elit".as_ref());
+    let aes_pcbc = Pcbc::new(&aes_cfb_iv);
+    let aes_pcbc_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_pcbc.clone());
+    let aes_pcbc_output = aes_pcbc_encrypt.run(&aes_block_input[..48])?;
+    println!("{}", ToHex::default().run(&aes_pcbc_output)?);
+    let aes_pcbc_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_pcbc);
+    println!("{}", aes_pcbc_decrypt.run(&aes_pcbc_output)?);
+
+    // IGE 的 iv 为两个分组
+    let aes_ige = Ige::new(b"co et eiusmod euFooBar Lorem ips");
+    let aes_ige_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ige.clone());
+    let aes_ige_output = aes_ige_encrypt.run(&aes_block_input[..48])?;
+    println!("{}", ToHex::default().run(&aes_ige_output)?);
+    let aes_ige_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_ige);
+    println!("{}", aes_ige_decrypt.run(&aes_ige_output)?);
+
+    // 密文窃取 密文与明文等长
+    let aes_cts = Cts::new(&aes_cfb_iv, CtsVariant::Cs3);
+    let aes_cts_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_cfb_key, aes_cts.clone());
+    let aes_cts_output = aes_cts_encrypt.run(&aes_block_input)?;
+    println!("{}", ToHex::default().run(&aes_cts_output)?);
+    let aes_cts_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_cfb_key, aes_cts);
+    println!("{}", aes_cts_decrypt.run(&aes_cts_output)?);
+
+    // XTS 密钥为两个 AES 密钥拼接 调整值为扇区号
+    let aes_xts_key = Bytes::new(b"FooBar Lorem ipsum dolor sit ame".as_ref());
+    let aes_xts = Xts::from_sector(7);
+    let aes_xts_encrypt = AesEncrypt::<_, NoPadding>::new(&aes_xts_key, aes_xts.clone());
+    let aes_xts_output = aes_xts_encrypt.run(&aes_block_input)?;
+    println!("{}", ToHex::default().run(&aes_xts_output)?);
+    let aes_xts_decrypt = AesDecrypt::<_, NoPadding>::new(&aes_xts_key, aes_xts);
+    println!("{}", aes_xts_decrypt.run(&aes_xts_output)?);
     println!("---- ---- ---- ---- ----");
     println!();
 
@@ -219,6 +516,36 @@ fn main() -> Result<()> {
     let recipe11 = Recipe::new(vec![Box::new(xxtea_decrypt)]);
     let xxtea_decrypt_result = recipe11.bake(&xxtea_output)?;
     println!("{:?}", xxtea_decrypt_result);
+
+    // TEA / XTEA 作为分组密码 可搭配任意模式和填充
+    let tea_input = Bytes::new(b"Hello, World!".as_ref());
+    let tea_key = Bytes::new(b"0123456789abcdef".as_ref());
+    let tea_encrypt = TeaEncrypt::<_, Pkcs7Padding>::new(&tea_key, Ecb);
+    let tea_output = tea_encrypt.run(&tea_input)?;
+    println!("{}", ToHex::default().run(&tea_output)?);
+    let tea_decrypt = TeaDecrypt::<_, Pkcs7Padding>::new(&tea_key, Ecb);
+    println!("{}", tea_decrypt.run(&tea_output)?);
+
+    let xtea_iv = Bytes::new(b"12345678".as_ref());
+    let xtea_encrypt = XteaEncrypt::<_, Pkcs7Padding>::new(&tea_key, Cbc::new(&xtea_iv));
+    let xtea_output = xtea_encrypt.run(&tea_input)?;
+    println!("{}", ToHex::default().run(&xtea_output)?);
+    let xtea_decrypt = XteaDecrypt::<_, Pkcs7Padding>::new(&tea_key, Cbc::new(&xtea_iv));
+    println!("{}", xtea_decrypt.run(&xtea_output)?);
+
+    // 自定义 delta、轮数 以及小端序的密钥和数据
+    let tea_params = TeaParams::new(0x61C88647, Some(16), Endian::Little, Endian::Little);
+    let custom_tea_encrypt = TeaEncrypt::<_, Pkcs7Padding>::with_params(&tea_key, Ecb, tea_params);
+    let custom_tea_output = custom_tea_encrypt.run(&tea_input)?;
+    println!("{}", ToHex::default().run(&custom_tea_output)?);
+    let custom_tea_decrypt = TeaDecrypt::<_, Pkcs7Padding>::with_params(&tea_key, Ecb, tea_params);
+    println!("{}", custom_tea_decrypt.run(&custom_tea_output)?);
+
+    let custom_xxtea_encrypt = XxteaEncrypt::with_params(&tea_key, true, tea_params);
+    let custom_xxtea_output = custom_xxtea_encrypt.run(&tea_input)?;
+    println!("{}", ToHex::default().run(&custom_xxtea_output)?);
+    let custom_xxtea_decrypt = XxteaDecrypt::with_params(&tea_key, true, tea_params);
+    println!("{}", custom_xxtea_decrypt.run(&custom_xxtea_output)?);
     println!("---- ---- ---- ---- ----");
     println!();
 
@@ -289,6 +616,493 @@ fn main() -> Result<()> {
     ]);
     let sm4_ctr_decrypt_result = recipe_sm4_ctr_decrypt.bake(&sm4_ctr_output)?;
     println!("{}", sm4_ctr_decrypt_result);
+
+    let sm4_ccm_nonce = Bytes::new(b"Nonce12".as_ref());
+    let sm4_ccm_encrypt =
+        Sm4Encrypt::<_, NoPadding>::new(&sm4_ofb_key, Ccm::new(&sm4_ccm_nonce, None, 8));
+    let recipe_sm4_ccm = Recipe::new(vec![Box::new(sm4_ccm_encrypt), Box::new(ToHex::default())]);
+    let sm4_ccm_output = recipe_sm4_ccm.bake(&sm4_ofb_input)?;
+    println!("{}", sm4_ccm_output);
+    let sm4_ccm_decrypt =
+        Sm4Decrypt::<_, NoPadding>::new(&sm4_ofb_key, Ccm::new(&sm4_ccm_nonce, None, 8));
+    let recipe_sm4_ccm_decrypt = Recipe::new(vec![
+        Box::new(FromHex::default()),
+        Box::new(sm4_ccm_decrypt),
+    ]);
+    let sm4_ccm_decrypt_result = recipe_sm4_ccm_decrypt.bake(&sm4_ccm_output)?;
+    println!("{}", sm4_ccm_decrypt_result);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Blowfish / Twofish
+    println!("---- ---- Blowfish ---- ----");
+    let blowfish_input = Bytes::new(b"Hello, World!".as_ref());
+    let blowfish_key = Bytes::new(b"BlowfishKey".as_ref());
+    let blowfish_iv = Bytes::new(b"12345678".as_ref());
+    let blowfish_encrypt =
+        BlowfishEncrypt::<_, Pkcs7Padding>::new(&blowfish_key, Cbc::new(&blowfish_iv));
+    let recipe_blowfish = Recipe::new(vec![Box::new(blowfish_encrypt), Box::new(ToHex::default())]);
+    let blowfish_output = recipe_blowfish.bake(&blowfish_input)?;
+    println!("{}", blowfish_output);
+    let blowfish_decrypt =
+        BlowfishDecrypt::<_, Pkcs7Padding>::new(&blowfish_key, Cbc::new(&blowfish_iv));
+    let recipe_blowfish_decrypt = Recipe::new(vec![
+        Box::new(FromHex::default()),
+        Box::new(blowfish_decrypt),
+    ]);
+    println!("{}", recipe_blowfish_decrypt.bake(&blowfish_output)?);
+
+    // 修改过初始值的 Blowfish
+    let mut blowfish_tables = BlowfishTables::default();
+    blowfish_tables.p_array[0] ^= 0x12345678;
+    let custom_blowfish_encrypt = BlowfishEncrypt::<_, Pkcs7Padding>::with_tables(
+        &blowfish_key,
+        Ecb,
+        blowfish_tables.clone(),
+    );
+    let custom_blowfish_output = custom_blowfish_encrypt.run(&blowfish_input)?;
+    println!("{}", ToHex::default().run(&custom_blowfish_output)?);
+    let custom_blowfish_decrypt =
+        BlowfishDecrypt::<_, Pkcs7Padding>::with_tables(&blowfish_key, Ecb, blowfish_tables);
+    println!("{}", custom_blowfish_decrypt.run(&custom_blowfish_output)?);
+
+    let twofish_key = Bytes::new(b"Twofish 128 Key!".as_ref());
+    let twofish_encrypt = TwofishEncrypt::<_, Pkcs7Padding>::new(&twofish_key, Ecb);
+    let twofish_output = twofish_encrypt.run(&blowfish_input)?;
+    println!("{}", ToHex::default().run(&twofish_output)?);
+    let twofish_decrypt = TwofishDecrypt::<_, Pkcs7Padding>::new(&twofish_key, Ecb);
+    println!("{}", twofish_decrypt.run(&twofish_output)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Camellia / ARIA / SEED
+    println!("---- ---- Camellia / ARIA / SEED ---- ----");
+    let national_input = Bytes::new(b"Hello, World!".as_ref());
+    let national_iv = Bytes::new(b"0123456789abcdef".as_ref());
+    let camellia_key = Bytes::new(b"Camellia 256 bit key for test!!!".as_ref());
+    let camellia_encrypt =
+        CamelliaEncrypt::<_, Pkcs7Padding>::new(&camellia_key, Cbc::new(&national_iv));
+    let camellia_output = camellia_encrypt.run(&national_input)?;
+    println!("{}", ToHex::default().run(&camellia_output)?);
+    let camellia_decrypt =
+        CamelliaDecrypt::<_, Pkcs7Padding>::new(&camellia_key, Cbc::new(&national_iv));
+    println!("{}", camellia_decrypt.run(&camellia_output)?);
+
+    let aria_key = Bytes::new(b"ARIA 192 bit key for te!".as_ref());
+    let aria_encrypt = AriaEncrypt::<_, Pkcs7Padding>::new(&aria_key, Cbc::new(&national_iv));
+    let aria_output = aria_encrypt.run(&national_input)?;
+    println!("{}", ToHex::default().run(&aria_output)?);
+    let aria_decrypt = AriaDecrypt::<_, Pkcs7Padding>::new(&aria_key, Cbc::new(&national_iv));
+    println!("{}", aria_decrypt.run(&aria_output)?);
+
+    let seed_key = Bytes::new(b"SEED 128 bit key".as_ref());
+    let seed_encrypt = SeedEncrypt::<_, Pkcs7Padding>::new(&seed_key, Ecb);
+    let seed_output = seed_encrypt.run(&national_input)?;
+    println!("{}", ToHex::default().run(&seed_output)?);
+    let seed_decrypt = SeedDecrypt::<_, Pkcs7Padding>::new(&seed_key, Ecb);
+    println!("{}", seed_decrypt.run(&seed_output)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Magma / Kuznyechik
+    println!("---- ---- Magma / Kuznyechik ---- ----");
+    let gost_input = Bytes::new(b"Hello, World!".as_ref());
+    let gost_key = Bytes::new(b"GOST 28147-89 / R 34.12-2015 key".as_ref());
+    let magma_iv = Bytes::new(b"12345678".as_ref());
+    let magma_encrypt = MagmaEncrypt::<_, Pkcs7Padding>::new(&gost_key, Cbc::new(&magma_iv));
+    let magma_output = magma_encrypt.run(&gost_input)?;
+    println!("{}", ToHex::default().run(&magma_output)?);
+    let magma_decrypt = MagmaDecrypt::<_, Pkcs7Padding>::new(&gost_key, Cbc::new(&magma_iv));
+    println!("{}", magma_decrypt.run(&magma_output)?);
+
+    // GOST 28147-89 常见实现 使用 CryptoPro-A S 盒 小端序
+    let magma_params = MagmaParams::new(MagmaSBox::CryptoProA, Endian::Little);
+    let custom_magma_encrypt =
+        MagmaEncrypt::<_, Pkcs7Padding>::with_params(&gost_key, Ecb, magma_params);
+    let custom_magma_output = custom_magma_encrypt.run(&gost_input)?;
+    println!("{}", ToHex::default().run(&custom_magma_output)?);
+    let custom_magma_decrypt =
+        MagmaDecrypt::<_, Pkcs7Padding>::with_params(&gost_key, Ecb, magma_params);
+    println!("{}", custom_magma_decrypt.run(&custom_magma_output)?);
+
+    let kuznyechik_encrypt = KuznyechikEncrypt::<_, Pkcs7Padding>::new(&gost_key, Ecb);
+    let kuznyechik_output = kuznyechik_encrypt.run(&gost_input)?;
+    println!("{}", ToHex::default().run(&kuznyechik_output)?);
+    let kuznyechik_decrypt = KuznyechikDecrypt::<_, Pkcs7Padding>::new(&gost_key, Ecb);
+    println!("{}", kuznyechik_decrypt.run(&kuznyechik_output)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Serpent / CAST / IDEA / RC2 / RC5 / RC6
+    println!("---- ---- Serpent / CAST / IDEA / RC2 / RC5 / RC6 ---- ----");
+    let legacy_input = Bytes::new(b"Hello, World!".as_ref());
+    let legacy_iv8 = Bytes::new(b"01234567".as_ref());
+    let legacy_iv16 = Bytes::new(b"0123456789abcdef".as_ref());
+    let legacy_key = Bytes::new(b"0123456789abcdef".as_ref());
+    let serpent_encrypt =
+        SerpentEncrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv16));
+    let serpent_output = serpent_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&serpent_output)?);
+    let serpent_decrypt =
+        SerpentDecrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv16));
+    println!("{}", serpent_decrypt.run(&serpent_output)?);
+
+    let cast5_encrypt = Cast5Encrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    let cast5_output = cast5_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&cast5_output)?);
+    let cast5_decrypt = Cast5Decrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    println!("{}", cast5_decrypt.run(&cast5_output)?);
+
+    let cast6_encrypt = Cast6Encrypt::<_, Pkcs7Padding>::new(&legacy_key, Ecb);
+    let cast6_output = cast6_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&cast6_output)?);
+    let cast6_decrypt = Cast6Decrypt::<_, Pkcs7Padding>::new(&legacy_key, Ecb);
+    println!("{}", cast6_decrypt.run(&cast6_output)?);
+
+    let idea_encrypt = IdeaEncrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    let idea_output = idea_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&idea_output)?);
+    let idea_decrypt = IdeaDecrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    println!("{}", idea_decrypt.run(&idea_output)?);
+
+    // RC2 有效密钥长度默认与密钥长度相同
+    let rc2_encrypt = Rc2Encrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    let rc2_output = rc2_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&rc2_output)?);
+    let rc2_decrypt = Rc2Decrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv8));
+    println!("{}", rc2_decrypt.run(&rc2_output)?);
+    // 40 位有效密钥长度 (RC2-40)
+    let rc2_40_encrypt =
+        Rc2Encrypt::<_, Pkcs7Padding>::with_effective_bits(&legacy_key[..5], Ecb, 40);
+    let rc2_40_output = rc2_40_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&rc2_40_output)?);
+    let rc2_40_decrypt =
+        Rc2Decrypt::<_, Pkcs7Padding>::with_effective_bits(&legacy_key[..5], Ecb, 40);
+    println!("{}", rc2_40_decrypt.run(&rc2_40_output)?);
+
+    // RC5-32/12 与 RC6-32/20 为默认参数
+    let rc5_encrypt = Rc5Encrypt::<_, Pkcs7Padding>::new(&legacy_key, Ecb);
+    let rc5_output = rc5_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&rc5_output)?);
+    let rc5_decrypt = Rc5Decrypt::<_, Pkcs7Padding>::new(&legacy_key, Ecb);
+    println!("{}", rc5_decrypt.run(&rc5_output)?);
+    // RC5-64/16 分组长度为 16 字节
+    let rc5_params = Rc5Params::new(64, 16);
+    let custom_rc5_encrypt =
+        Rc5Encrypt::<_, Pkcs7Padding>::with_params(&legacy_key, Cbc::new(&legacy_iv16), rc5_params);
+    let custom_rc5_output = custom_rc5_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&custom_rc5_output)?);
+    let custom_rc5_decrypt =
+        Rc5Decrypt::<_, Pkcs7Padding>::with_params(&legacy_key, Cbc::new(&legacy_iv16), rc5_params);
+    println!("{}", custom_rc5_decrypt.run(&custom_rc5_output)?);
+
+    let rc6_encrypt = Rc6Encrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv16));
+    let rc6_output = rc6_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&rc6_output)?);
+    let rc6_decrypt = Rc6Decrypt::<_, Pkcs7Padding>::new(&legacy_key, Cbc::new(&legacy_iv16));
+    println!("{}", rc6_decrypt.run(&rc6_output)?);
+    // RC6-16/12 分组长度为 8 字节
+    let rc6_params = Rc6Params::new(16, 12);
+    let custom_rc6_encrypt =
+        Rc6Encrypt::<_, Pkcs7Padding>::with_params(&legacy_key, Ecb, rc6_params);
+    let custom_rc6_output = custom_rc6_encrypt.run(&legacy_input)?;
+    println!("{}", ToHex::default().run(&custom_rc6_output)?);
+    let custom_rc6_decrypt =
+        Rc6Decrypt::<_, Pkcs7Padding>::with_params(&legacy_key, Ecb, rc6_params);
+    println!("{}", custom_rc6_decrypt.run(&custom_rc6_output)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // SM2
+    println!("---- ---- SM2 ---- ----");
+    let sm2_input = Bytes::new(b"Hello, World!".as_ref());
+    let sm2_key_pair = Sm2KeyPair::from_private_key(
+        &FromHex::default()
+            .run(b"3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8")?,
+    )?;
+    println!("{}", ToHex::default().run(&sm2_key_pair.public_key)?);
+
+    let sm2_sign = Sm2Sign::new(&sm2_key_pair.private_key, DEFAULT_USER_ID);
+    let sm2_signature = sm2_sign.run(&sm2_input)?;
+    println!("{}", ToHex::default().run(&sm2_signature)?);
+    let sm2_verify = Sm2Verify::new(&sm2_key_pair.public_key, DEFAULT_USER_ID, &sm2_signature);
+    println!("{}", sm2_verify.run(&sm2_input)?);
+
+    let sm2_encrypt = Sm2Encrypt::new(&sm2_key_pair.public_key, Sm2Layout::C1C3C2);
+    let sm2_output = sm2_encrypt.run(&sm2_input)?;
+    println!("{}", ToHex::default().run(&sm2_output)?);
+    let sm2_decrypt = Sm2Decrypt::new(&sm2_key_pair.private_key, Sm2Layout::C1C3C2);
+    println!("{}", sm2_decrypt.run(&sm2_output)?);
+
+    // 双方各持有长期密钥对和临时密钥对
+    let alice = Sm2KeyPair::generate()?;
+    let alice_ephemeral = Sm2KeyPair::generate()?;
+    let bob = Sm2KeyPair::generate()?;
+    let bob_ephemeral = Sm2KeyPair::generate()?;
+    let alice_exchange = Sm2KeyExchange::new(
+        &alice.private_key,
+        &alice_ephemeral.private_key,
+        b"ALICE123@YAHOO.COM",
+        &bob.public_key,
+        b"BILL456@YAHOO.COM",
+        true,
+        16,
+    );
+    let bob_exchange = Sm2KeyExchange::new(
+        &bob.private_key,
+        &bob_ephemeral.private_key,
+        b"BILL456@YAHOO.COM",
+        &alice.public_key,
+        b"ALICE123@YAHOO.COM",
+        false,
+        16,
+    );
+    let alice_agreement = alice_exchange.exchange(&bob_ephemeral.public_key)?;
+    let bob_agreement = bob_exchange.exchange(&alice_ephemeral.public_key)?;
+    println!("{}", ToHex::default().run(&alice_agreement.key)?);
+    println!("{}", ToHex::default().run(&bob_agreement.key)?);
+    // 校验对方发来的确认值
+    println!(
+        "{}",
+        alice_agreement.expected_confirmation.to_vec() == bob_agreement.confirmation.to_vec()
+    );
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // RSA
+    println!("---- ---- RSA ---- ----");
+    let rsa_input = Bytes::new(b"Hello, World!".as_ref());
+    let rsa_key = RsaPrivateKey::generate(1024)?;
+    println!("{:x}", rsa_key.n());
+    let rsa_public_key = rsa_key.public_key();
+
+    let rsa_pkcs1_encrypt = RsaEncrypt::new(rsa_public_key, Pkcs1v15Encryption);
+    let rsa_pkcs1_output = rsa_pkcs1_encrypt.run(&rsa_input)?;
+    println!("{}", ToHex::default().run(&rsa_pkcs1_output)?);
+    let rsa_pkcs1_decrypt = RsaDecrypt::new(&rsa_key, Pkcs1v15Encryption);
+    println!("{}", rsa_pkcs1_decrypt.run(&rsa_pkcs1_output)?);
+
+    // OAEP-SHA256, MGF1 使用 SHA1 (Java 默认)
+    let rsa_oaep_encrypt = RsaEncrypt::new(rsa_public_key, Oaep::<Sha256, Sha1>::new());
+    let rsa_oaep_output = rsa_oaep_encrypt.run(&rsa_input)?;
+    let rsa_oaep_decrypt = RsaDecrypt::new(&rsa_key, Oaep::<Sha256, Sha1>::new());
+    println!("{}", rsa_oaep_decrypt.run(&rsa_oaep_output)?);
+
+    let rsa_pkcs1_sign = RsaSign::new(&rsa_key, Pkcs1v15Signature::<Sha256>::new());
+    let rsa_pkcs1_signature = rsa_pkcs1_sign.run(&rsa_input)?;
+    println!("{}", ToHex::default().run(&rsa_pkcs1_signature)?);
+    let rsa_pkcs1_verify = RsaVerify::new(
+        rsa_public_key,
+        Pkcs1v15Signature::<Sha256>::new(),
+        &rsa_pkcs1_signature,
+    );
+    println!("{}", rsa_pkcs1_verify.run(&rsa_input)?);
+
+    let rsa_pss_sign = RsaSign::new(&rsa_key, Pss::<Sha256>::new());
+    let rsa_pss_signature = rsa_pss_sign.run(&rsa_input)?;
+    let rsa_pss_verify = RsaVerify::new(rsa_public_key, Pss::<Sha256>::new(), &rsa_pss_signature);
+    println!("{}", rsa_pss_verify.run(&rsa_input)?);
+
+    // 小素数构造的教科书 RSA
+    let rsa_small_key = RsaPrivateKey::from_primes(
+        BigUint::from(61u64),
+        BigUint::from(53u64),
+        BigUint::from(17u64),
+    )?;
+    println!("{}", rsa_small_key.d());
+    let rsa_raw_output = RsaEncrypt::new(rsa_small_key.public_key(), RsaRaw).run(&[65])?;
+    println!("{}", ToHex::default().run(&rsa_raw_output)?);
+    println!(
+        "{}",
+        ToHex::default().run(&RsaDecrypt::new(&rsa_small_key, RsaRaw).run(&rsa_raw_output)?)?
+    );
+    // 对弱密钥依次尝试各种攻击
+    let rsa_attack = RsaAttack::new(rsa_small_key.public_key());
+    print!("{}", rsa_attack.run(&rsa_raw_output)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // EC
+    println!("---- ---- EC ---- ----");
+    let ec_input = Bytes::new(b"Hello, World!".as_ref());
+    let p256 = EcCurve::p256();
+    let ec_alice = EcKeyPair::generate(&p256)?;
+    let ec_bob = EcKeyPair::generate(&p256)?;
+    println!("{}", ToHex::default().run(&ec_alice.public_key)?);
+    println!(
+        "{}",
+        ToHex::default().run(&p256.compress_point(&ec_alice.public_key)?)?
+    );
+
+    let alice_shared = Ecdh::new(&p256, &ec_alice.private_key).run(&ec_bob.public_key)?;
+    let bob_shared = Ecdh::new(&p256, &ec_bob.private_key).run(&ec_alice.public_key)?;
+    println!("{}", alice_shared.to_vec() == bob_shared.to_vec());
+
+    let ecdsa_signature = EcdsaSign::<Sha256>::new(&p256, &ec_alice.private_key).run(&ec_input)?;
+    println!("{}", ToHex::default().run(&ecdsa_signature)?);
+    let ecdsa_verify = EcdsaVerify::<Sha256>::new(&p256, &ec_alice.public_key, &ecdsa_signature);
+    println!("{}", ecdsa_verify.run(&ec_input)?);
+
+    // RFC 6979 确定性签名
+    let secp256k1 = EcCurve::secp256k1();
+    let ec_key = EcKeyPair::from_private_key(&secp256k1, &[0x01; 32])?;
+    let ecdsa_deterministic = EcdsaSign::<Sha256>::deterministic(&secp256k1, &ec_key.private_key);
+    println!(
+        "{}",
+        ToHex::default().run(&ecdsa_deterministic.run(&ec_input)?)?
+    );
+
+    // 自定义曲线 y^2 = x^3 + 5x + 12 (mod 65537)
+    let toy_curve = EcCurve::new(
+        BigUint::from(65537u64),
+        BigUint::from(5u64),
+        BigUint::from(12u64),
+        BigUint::from(1u64),
+        BigUint::from(12240u64),
+        BigUint::from(65647u64),
+    )?;
+    let toy_key = EcKeyPair::generate(&toy_curve)?;
+    let toy_signature =
+        EcdsaSign::<Sha256>::new(&toy_curve, &toy_key.private_key).run(&ec_input)?;
+    let toy_verify = EcdsaVerify::<Sha256>::new(&toy_curve, &toy_key.public_key, &toy_signature);
+    println!("{}", toy_verify.verify(&ec_input)?);
+
+    // 两个签名使用了相同的 k 时恢复私钥
+    let mut ecdsa_attack = EcdsaAttack::new(&p256, &ec_alice.public_key);
+    for message in [b"first".as_ref(), b"second"] {
+        let signature =
+            EcdsaSign::<Sha256>::with_k(&p256, &ec_alice.private_key, &[0x42; 32]).run(message)?;
+        ecdsa_attack
+            .samples
+            .push(EcdsaSample::new::<Sha256>(&p256, message, &signature)?);
+    }
+    print!("{}", ecdsa_attack.run(&[])?);
+    println!("{}", ToHex::default().run(&ec_alice.private_key)?);
+
+    // k 的高 64 位为 0 时, 6 个签名即可用格攻击恢复私钥
+    let mut ecdsa_attack = EcdsaAttack::new(&p256, &ec_alice.public_key);
+    ecdsa_attack.nonce_bits = Some(192);
+    for i in 0..6u8 {
+        let k = [[0x00; 8].as_ref(), &[i + 1; 24]].concat();
+        let signature = EcdsaSign::<Sha256>::with_k(&p256, &ec_alice.private_key, &k).run(&[i])?;
+        ecdsa_attack
+            .samples
+            .push(EcdsaSample::new::<Sha256>(&p256, &[i], &signature)?);
+    }
+    print!("{}", ecdsa_attack.run(&[])?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Curve25519
+    println!("---- ---- Curve25519 ---- ----");
+    let x25519_alice = X25519KeyPair::generate()?;
+    let x25519_bob = X25519KeyPair::generate()?;
+    let x25519_alice_shared = X25519::new(&x25519_alice.private_key).run(&x25519_bob.public_key)?;
+    let x25519_bob_shared = X25519::new(&x25519_bob.private_key).run(&x25519_alice.public_key)?;
+    println!("{}", ToHex::default().run(&x25519_alice_shared)?);
+    println!(
+        "{}",
+        x25519_alice_shared.to_vec() == x25519_bob_shared.to_vec()
+    );
+
+    let ed25519_input = Bytes::new(b"Hello, World!".as_ref());
+    let ed25519_key = Ed25519KeyPair::from_private_key(&[0x01; 32])?;
+    println!("{}", ToHex::default().run(&ed25519_key.public_key)?);
+    let ed25519_signature = Ed25519Sign::new(&ed25519_key.private_key).run(&ed25519_input)?;
+    println!("{}", ToHex::default().run(&ed25519_signature)?);
+    let ed25519_verify = Ed25519Verify::new(&ed25519_key.public_key, &ed25519_signature);
+    println!("{}", ed25519_verify.run(&ed25519_input)?);
+
+    // Ed25519ctx 与 Ed25519ph
+    let ed25519ctx_signature =
+        Ed25519Sign::with_context(&ed25519_key.private_key, b"update").run(&ed25519_input)?;
+    let ed25519ctx_verify =
+        Ed25519Verify::with_context(&ed25519_key.public_key, &ed25519ctx_signature, b"update");
+    println!("{}", ed25519ctx_verify.verify(&ed25519_input)?);
+    let ed25519ph_signature =
+        Ed25519Sign::prehashed(&ed25519_key.private_key, b"").run(&ed25519_input)?;
+    let ed25519ph_verify =
+        Ed25519Verify::prehashed(&ed25519_key.public_key, &ed25519ph_signature, b"");
+    println!("{}", ed25519ph_verify.verify(&ed25519_input)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // DH
+    println!("---- ---- DH ---- ----");
+    let modp2048 = DhGroup::modp2048();
+    let dh_alice = DhKeyPair::generate(&modp2048)?;
+    let dh_bob = DhKeyPair::generate(&modp2048)?;
+    let dh_alice_shared = Dh::new(&modp2048, &dh_alice.private_key).run(&dh_bob.public_key)?;
+    let dh_bob_shared = Dh::new(&modp2048, &dh_bob.private_key).run(&dh_alice.public_key)?;
+    println!("{}", dh_alice_shared.to_vec() == dh_bob_shared.to_vec());
+
+    // 阶为光滑数的弱群, 用 Pohlig-Hellman 求离散对数
+    let weak_group = DhGroup::new(BigUint::from(2357u64), BigUint::from(2u64))?;
+    let weak_key = DhKeyPair::from_private_key(&weak_group, &[0x06, 0xd7])?;
+    let discrete_log = DiscreteLog::new(&weak_group).run(&weak_key.public_key)?;
+    println!("{}", ToHex::default().run(&discrete_log)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // ElGamal
+    println!("---- ---- ElGamal ---- ----");
+    let elgamal_input = Bytes::new(b"Hello, World!".as_ref());
+    let ffdhe2048 = DhGroup::ffdhe2048();
+    let elgamal_key = DhKeyPair::generate(&ffdhe2048)?;
+    let elgamal_output =
+        ElGamalEncrypt::new(&ffdhe2048, &elgamal_key.public_key).run(&elgamal_input)?;
+    println!(
+        "{}",
+        ElGamalDecrypt::new(&ffdhe2048, &elgamal_key.private_key).run(&elgamal_output)?
+    );
+    let elgamal_signature =
+        ElGamalSign::<Sha256>::new(&ffdhe2048, &elgamal_key.private_key).run(&elgamal_input)?;
+    let elgamal_verify =
+        ElGamalVerify::<Sha256>::new(&ffdhe2048, &elgamal_key.public_key, &elgamal_signature);
+    println!("{}", elgamal_verify.run(&elgamal_input)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // DSA
+    println!("---- ---- DSA ---- ----");
+    let dsa_input = Bytes::new(b"Hello, World!".as_ref());
+    let dsa_group = DhGroup::generate(1024, 160)?;
+    println!("{:x}", dsa_group.q().unwrap());
+    let dsa_key = DhKeyPair::generate(&dsa_group)?;
+    let dsa_signature =
+        DsaSign::<Sha1>::deterministic(&dsa_group, &dsa_key.private_key).run(&dsa_input)?;
+    println!("{}", ToHex::default().run(&dsa_signature)?);
+    let dsa_verify = DsaVerify::<Sha1>::new(&dsa_group, &dsa_key.public_key, &dsa_signature);
+    println!("{}", dsa_verify.run(&dsa_input)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // Key Wrap
+    println!("---- ---- Key Wrap ---- ----");
+    let kek = Bytes::new(b"Key Encrypt Key!".as_ref());
+    let wrapped_key = Bytes::new(b"0123456789abcdef".as_ref());
+    // RFC 3394
+    let aes_key_wrap = KeyWrap::<Aes>::new(&kek, false);
+    let recipe_aes_key_wrap = Recipe::new(vec![Box::new(aes_key_wrap), Box::new(ToHex::default())]);
+    let aes_key_wrap_output = recipe_aes_key_wrap.bake(&wrapped_key)?;
+    println!("{}", aes_key_wrap_output);
+    let aes_key_unwrap = KeyUnwrap::<Aes>::new(&kek, false);
+    let recipe_aes_key_unwrap =
+        Recipe::new(vec![Box::new(FromHex::default()), Box::new(aes_key_unwrap)]);
+    println!("{}", recipe_aes_key_unwrap.bake(&aes_key_wrap_output)?);
+
+    // RFC 5649 任意长度
+    let aes_key_wrap_pad = KeyWrap::<Aes>::new(&kek, true);
+    let aes_key_wrap_pad_output = aes_key_wrap_pad.run(b"Hello")?;
+    println!("{}", ToHex::default().run(&aes_key_wrap_pad_output)?);
+    let aes_key_unwrap_pad = KeyUnwrap::<Aes>::new(&kek, true);
+    println!("{}", aes_key_unwrap_pad.run(&aes_key_wrap_pad_output)?);
+
+    let sm4_key_wrap = KeyWrap::<Sm4>::new(&kek, false);
+    let sm4_key_wrap_output = sm4_key_wrap.run(&wrapped_key)?;
+    println!("{}", ToHex::default().run(&sm4_key_wrap_output)?);
+    let sm4_key_unwrap = KeyUnwrap::<Sm4>::new(&kek, false);
+    println!("{}", sm4_key_unwrap.run(&sm4_key_wrap_output)?);
     println!("---- ---- ---- ---- ----");
     println!();
 
@@ -1,12 +1,16 @@
 use crypto::base64::ToBase64;
 use crypto::bytes::Bytes;
+use crypto::hex::ToHex;
 use crypto::hmac::Hmac;
 use crypto::md5::Md5;
+use crypto::pbkdf2::Pbkdf2;
 use crypto::recipe::Recipe;
 use crypto::sha1::Sha1;
 use crypto::sha2::Sha256;
+use crypto::sha2::Sha384;
 use crypto::sha2::Sha512;
 use crypto::sm3::Sm3;
+use crypto::streebog::{Streebog256, Streebog512};
 use crypto::types::Result;
 
 fn main() -> Result<()> {
@@ -41,6 +45,10 @@ fn main() -> Result<()> {
     let recipe4 = Recipe::new(vec![Box::new(Sha512)]);
     let sha512_output = recipe4.bake(&sha512_input)?;
     println!("{:?}", sha512_output);
+    let sha384_input = Bytes::new("123456".as_bytes());
+    let recipe_sha384 = Recipe::new(vec![Box::new(Sha384)]);
+    let sha384_output = recipe_sha384.bake(&sha384_input)?;
+    println!("{:?}", sha384_output);
     println!("---- ---- ---- ---- ----");
     println!();
 
@@ -76,5 +84,29 @@ fn main() -> Result<()> {
     println!("---- ---- ---- ---- ----");
     println!();
 
+    // Streebog
+    println!("---- ---- Streebog ---- ----");
+    let streebog_input =
+        Bytes::new("012345678901234567890123456789012345678901234567890123456789012".as_bytes());
+    let recipe8 = Recipe::new(vec![Box::new(Streebog256), Box::new(ToHex::default())]);
+    println!("{}", recipe8.bake(&streebog_input)?);
+    let recipe9 = Recipe::new(vec![Box::new(Streebog512), Box::new(ToHex::default())]);
+    println!("{}", recipe9.bake(&streebog_input)?);
+
+    let streebog_hmac_key = Bytes::new("key".as_bytes());
+    let streebog_hmac_op = Hmac::<Streebog256>::new(&streebog_hmac_key);
+    let recipe10 = Recipe::new(vec![Box::new(streebog_hmac_op), Box::new(ToHex::default())]);
+    println!("{}", recipe10.bake(&streebog_input)?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
+    // PBKDF2
+    println!("---- ---- PBKDF2 ---- ----");
+    let pbkdf2_op = Pbkdf2::<Sha256>::new(b"salt", 4096, 32);
+    let recipe11 = Recipe::new(vec![Box::new(pbkdf2_op), Box::new(ToHex::default())]);
+    println!("{}", recipe11.bake(b"password")?);
+    println!("---- ---- ---- ---- ----");
+    println!();
+
     Ok(())
 }
@@ -1,9 +1,13 @@
 use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
 use crate::enums::BlockSize;
 
 mod aes_decrypt;
 mod aes_encrypt;
 
+use aes_decrypt::block_decrypt;
+use aes_encrypt::block_encrypt;
+
 pub use aes_decrypt::{inv_mix_columns, inv_shift_rows, inv_sub_bytes, AesDecrypt};
 pub use aes_encrypt::{mix_columns, shift_rows, sub_bytes, AesEncrypt};
 
@@ -32,7 +36,7 @@ const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x
 
 fn key_schedule(key: &Bytes) -> Vec<Bytes> {
     let mut original_key = key.to_vec();
-    while original_key.len() % 4 != 0 || original_key.is_empty() {
+    while !original_key.len().is_multiple_of(4) || original_key.is_empty() {
         original_key.push(0);
     }
     // key 所占行数
@@ -91,3 +95,34 @@ fn key_schedule(key: &Bytes) -> Vec<Bytes> {
         .map(|row| Bytes::new(row.concat()))
         .collect()
 }
+
+#[derive(Debug)]
+pub struct Aes {
+    sub_keys: Vec<Bytes>,
+    inv_sub_keys: Vec<Bytes>,
+}
+
+impl BlockCipher for Aes {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        block_encrypt(&self.sub_keys)(block)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        block_decrypt(&self.inv_sub_keys)(block)
+    }
+
+    fn build(key: &[u8]) -> Self {
+        let sub_keys = key_schedule(&Bytes::new(key));
+        // 解密过程使用逆序的轮密钥
+        let mut inv_sub_keys = sub_keys.clone();
+        inv_sub_keys.reverse();
+        Self {
+            sub_keys,
+            inv_sub_keys,
+        }
+    }
+}
@@ -1,5 +1,4 @@
-use crate::aes::aes_encrypt::block_encrypt;
-use crate::aes::{key_schedule, BLOCK_SIZE};
+use crate::aes::{Aes, BLOCK_SIZE};
 use crate::bytes::Bytes;
 use crate::mode::Mode;
 use crate::operation::Operation;
@@ -119,21 +118,9 @@ impl<M: Mode, P: Padding> AesDecrypt<M, P> {
 
 impl<M: Mode, P: Padding> Operation for AesDecrypt<M, P> {
     fn run(&self, input: &[u8]) -> Result<Bytes> {
-        let mut sub_keys = key_schedule(&self.key);
+        let result = self.mode.cipher_decrypt::<Aes>(input, &self.key);
 
-        let mode_name = std::any::type_name_of_val(&self.mode);
-        if mode_name.contains("Ecb") || mode_name.contains("Cbc") {
-            sub_keys.reverse();
-            let decrypt_func = block_decrypt(&sub_keys);
-            let result = self.mode.bytes_decrypt(input, BLOCK_SIZE, decrypt_func);
-
-            Ok(Bytes::new(self.padding.unpad(&result)))
-        } else {
-            let encrypt_func = block_encrypt(&sub_keys);
-            let result = self.mode.bytes_decrypt(input, BLOCK_SIZE, encrypt_func);
-
-            Ok(Bytes::new(self.padding.unpad(&result)))
-        }
+        Ok(Bytes::new(self.padding.unpad(&result)))
     }
 }
 
@@ -180,7 +167,7 @@ pub fn inv_mix_columns(state: &[u8]) -> Bytes {
     Bytes::new(mixed)
 }
 
-fn block_decrypt(sub_keys: &[Bytes]) -> impl Fn(&[u8]) -> Bytes + '_ {
+pub(super) fn block_decrypt(sub_keys: &[Bytes]) -> impl Fn(&[u8]) -> Bytes + '_ {
     move |block| {
         let mut result = Bytes::new(block);
         let rounds = sub_keys.len() - 1;
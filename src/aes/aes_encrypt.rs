@@ -1,4 +1,4 @@
- use crate::aes::{key_schedule, BLOCK_SIZE, S_BOX_FORWARD};
+use crate::aes::{Aes, BLOCK_SIZE, S_BOX_FORWARD};
 use crate::bytes::Bytes;
 use crate::mode::Mode;
 use crate::operation::Operation;
@@ -63,13 +63,9 @@ impl<M: Mode, P: Padding> AesEncrypt<M, P> {
 
 impl<M: Mode, P: Padding> Operation for AesEncrypt<M, P> {
     fn run(&self, input: &[u8]) -> Result<Bytes> {
-        let sub_keys = key_schedule(&self.key);
-        let encrypt_func = block_encrypt(&sub_keys);
         let padded_data = self.padding.pad(input);
 
-        Ok(self
-            .mode
-            .bytes_encrypt(&padded_data, BLOCK_SIZE, encrypt_func))
+        Ok(self.mode.cipher_encrypt::<Aes>(&padded_data, &self.key))
     }
 }
 
@@ -0,0 +1,31 @@
+use crate::aria::{Aria, BLOCK_SIZE};
+use crate::bytes::Bytes;
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct AriaDecrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> AriaDecrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for AriaDecrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = self.mode.cipher_decrypt::<Aria>(input, &self.key);
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+
+mod asn1_parse;
+mod decoder;
+mod node;
+mod oid;
+mod oid_names;
+
+pub use asn1_parse::Asn1Parse;
+pub use node::Asn1;
+pub use oid::Oid;
+
+pub(crate) use decoder::read_header;
+
+// 嵌套层数上限, 防止恶意输入导致栈溢出
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TagClass {
+    Universal,
+    Application,
+    ContextSpecific,
+    Private,
+}
+
+// 标识符八位组: 类别、是否为构造型以及标签号
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tag {
+    pub class: TagClass,
+    pub constructed: bool,
+    pub number: u32,
+}
+
+impl Tag {
+    pub const EOC: Self = Self::universal(0, false);
+    pub const BOOLEAN: Self = Self::universal(1, false);
+    pub const INTEGER: Self = Self::universal(2, false);
+    pub const BIT_STRING: Self = Self::universal(3, false);
+    pub const OCTET_STRING: Self = Self::universal(4, false);
+    pub const NULL: Self = Self::universal(5, false);
+    pub const OBJECT_IDENTIFIER: Self = Self::universal(6, false);
+    pub const ENUMERATED: Self = Self::universal(10, false);
+    pub const UTF8_STRING: Self = Self::universal(12, false);
+    pub const SEQUENCE: Self = Self::universal(16, true);
+    pub const SET: Self = Self::universal(17, true);
+    pub const NUMERIC_STRING: Self = Self::universal(18, false);
+    pub const PRINTABLE_STRING: Self = Self::universal(19, false);
+    pub const T61_STRING: Self = Self::universal(20, false);
+    pub const IA5_STRING: Self = Self::universal(22, false);
+    pub const UTC_TIME: Self = Self::universal(23, false);
+    pub const GENERALIZED_TIME: Self = Self::universal(24, false);
+    pub const VISIBLE_STRING: Self = Self::universal(26, false);
+    pub const UNIVERSAL_STRING: Self = Self::universal(28, false);
+    pub const BMP_STRING: Self = Self::universal(30, false);
+
+    pub const fn new(class: TagClass, constructed: bool, number: u32) -> Self {
+        Self {
+            class,
+            constructed,
+            number,
+        }
+    }
+
+    pub const fn universal(number: u32, constructed: bool) -> Self {
+        Self::new(TagClass::Universal, constructed, number)
+    }
+
+    // [number], 用于 EXPLICIT / IMPLICIT 标签
+    pub const fn context(number: u32, constructed: bool) -> Self {
+        Self::new(TagClass::ContextSpecific, constructed, number)
+    }
+
+    // 标签号及类别相同即视为同一类型, 不比较是否为构造型 (BER 字符串可为构造型)
+    pub fn matches(&self, other: &Self) -> bool {
+        self.class == other.class && self.number == other.number
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        let class = match self.class {
+            TagClass::Universal => 0x00,
+            TagClass::Application => 0x40,
+            TagClass::ContextSpecific => 0x80,
+            TagClass::Private => 0xc0,
+        };
+        let first = class | if self.constructed { 0x20 } else { 0x00 };
+        if self.number < 31 {
+            return vec![first | self.number as u8];
+        }
+        // 高标签号: 以 7 位为一组的大端序, 除最后一组外最高位为 1
+        let mut encoded = vec![(self.number & 0x7f) as u8];
+        let mut number = self.number >> 7;
+        while number > 0 {
+            encoded.push(0x80 | (number & 0x7f) as u8);
+            number >>= 7;
+        }
+        encoded.push(first | 0x1f);
+        encoded.reverse();
+        encoded
+    }
+}
+
+// 与 openssl asn1parse 相同的类型名称
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number = self.number;
+        match self.class {
+            TagClass::Private => return write!(f, "priv [ {} ] ", number),
+            TagClass::ContextSpecific => return write!(f, "cont [ {} ]", number),
+            TagClass::Application => return write!(f, "appl [ {} ]", number),
+            TagClass::Universal => {}
+        }
+        let name = match number {
+            0 => "EOC",
+            1 => "BOOLEAN",
+            2 => "INTEGER",
+            3 => "BIT STRING",
+            4 => "OCTET STRING",
+            5 => "NULL",
+            6 => "OBJECT",
+            7 => "OBJECT DESCRIPTOR",
+            8 => "EXTERNAL",
+            9 => "REAL",
+            10 => "ENUMERATED",
+            12 => "UTF8STRING",
+            16 => "SEQUENCE",
+            17 => "SET",
+            18 => "NUMERICSTRING",
+            19 => "PRINTABLESTRING",
+            20 => "T61STRING",
+            21 => "VIDEOTEXSTRING",
+            22 => "IA5STRING",
+            23 => "UTCTIME",
+            24 => "GENERALIZEDTIME",
+            25 => "GRAPHICSTRING",
+            26 => "VISIBLESTRING",
+            27 => "GENERALSTRING",
+            28 => "UNIVERSALSTRING",
+            30 => "BMPSTRING",
+            _ => return write!(f, "<ASN1 {}>", number),
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::asn1::{read_header, Oid, Tag, TagClass, MAX_DEPTH};
+use crate::bigint::BigInt;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 输入 DER / BER 数据, 输出与 openssl asn1parse 相同格式的结构
+// 每行依次为偏移、深度、头部长度、内容长度、基本型或构造型、类型及值
+// 输入可包含多个相邻的顶层元素
+#[derive(Debug, Default)]
+pub struct Asn1Parse {
+    // 按深度缩进类型名称, 即 asn1parse -i
+    pub indent: bool,
+}
+
+impl Asn1Parse {
+    pub fn new(indent: bool) -> Self {
+        Self { indent }
+    }
+
+    // 打印 input 中的元素, until_eoc 时遇到结束标记即返回, 返回占用的字节数
+    fn print(
+        &self,
+        output: &mut String,
+        input: &[u8],
+        offset: usize,
+        depth: usize,
+        until_eoc: bool,
+    ) -> Result<usize> {
+        if depth > MAX_DEPTH {
+            return Err("[Asn1Parse] nesting is too deep".into());
+        }
+        let mut position = 0;
+        while position < input.len() {
+            let header = read_header(&input[position..], false).map_err(|e| {
+                format!(
+                    "[Asn1Parse] error in encoding at offset {}: {}",
+                    offset + position,
+                    e
+                )
+            })?;
+            let (tag, header_len) = (header.tag, header.header_len);
+            let start = position;
+            position += header_len;
+
+            output.push_str(&format!(
+                "{:5}:d={:<2} hl={} ",
+                offset + start,
+                depth,
+                header_len
+            ));
+            match header.length {
+                Some(length) => {
+                    output.push_str(&format!("l={:4} ", length));
+                }
+                None => output.push_str("l=inf  "),
+            }
+            output.push_str(if tag.constructed { "cons: " } else { "prim: " });
+            if self.indent {
+                output.push_str(&" ".repeat(depth));
+            }
+            output.push_str(&format!("{:<18}", tag.to_string()));
+
+            let length = match header.length {
+                Some(length) => length,
+                None => {
+                    output.push('\n');
+                    let body = &input[position..];
+                    position += self.print(output, body, offset + position, depth + 1, true)?;
+                    continue;
+                }
+            };
+            if length > input.len() - position {
+                return Err(format!(
+                    "[Asn1Parse] length at offset {} exceeds the remaining data",
+                    offset + start
+                )
+                .into());
+            }
+            let content = &input[position..position + length];
+            if tag.constructed {
+                output.push('\n');
+                self.print(output, content, offset + position, depth + 1, false)?;
+            } else {
+                output.push_str(&primitive_value(&tag, content));
+                output.push('\n');
+            }
+            position += length;
+
+            if tag == Tag::EOC {
+                if until_eoc {
+                    return Ok(position);
+                }
+                return Err(format!(
+                    "[Asn1Parse] unexpected end-of-contents at offset {}",
+                    offset + start
+                )
+                .into());
+            }
+        }
+        if until_eoc {
+            return Err("[Asn1Parse] missing end-of-contents".into());
+        }
+        Ok(position)
+    }
+}
+
+impl Operation for Asn1Parse {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let mut output = String::new();
+        self.print(&mut output, input, 0, 0, false)?;
+        Ok(Bytes::new(output.as_bytes()))
+    }
+}
+
+// 基本型的值, 规则与 openssl asn1parse 一致
+fn primitive_value(tag: &Tag, content: &[u8]) -> String {
+    if tag.class != TagClass::Universal {
+        return String::new();
+    }
+    match tag.number {
+        1 => match content {
+            [value] => format!(":{}", value),
+            _ => ":BAD BOOLEAN".to_string(),
+        },
+        2 | 10 => {
+            if content.is_empty() {
+                return format!(":BAD {}", tag);
+            }
+            let value = BigInt::from_signed_bytes(content, Endian::Big);
+            let sign = if value.is_negative() { "-" } else { "" };
+            let magnitude = value.magnitude().to_bytes(Endian::Big);
+            let magnitude = if magnitude.is_empty() {
+                vec![0]
+            } else {
+                magnitude.to_vec()
+            };
+            format!(":{}{}", sign, hex_upper(&magnitude))
+        }
+        4 if !content.is_empty() => {
+            let printable = content
+                .iter()
+                .all(|&b| (b' '..=b'~').contains(&b) || matches!(b, b'\n' | b'\r' | b'\t'));
+            if printable {
+                format!(":{}", String::from_utf8_lossy(content))
+            } else {
+                format!("[HEX DUMP]:{}", hex_upper(content))
+            }
+        }
+        6 => match Oid::from_der(content) {
+            Ok(oid) => match oid.name() {
+                Some(name) => format!(":{}", name),
+                None => format!(":{}", oid),
+            },
+            Err(_) => ":BAD OBJECT".to_string(),
+        },
+        12 | 18 | 19 | 20 | 22 | 23 | 24 | 26 => {
+            format!(":{}", String::from_utf8_lossy(content))
+        }
+        _ => String::new(),
+    }
+}
+
+fn hex_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
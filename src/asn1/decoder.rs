@@ -0,0 +1,170 @@
+use crate::asn1::{Asn1, Tag, TagClass, MAX_DEPTH};
+use crate::types::Result;
+
+// 标识符与长度八位组, length 为 None 表示不定长 (仅 BER)
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Header {
+    pub tag: Tag,
+    pub header_len: usize,
+    pub length: Option<usize>,
+}
+
+// der 为 true 时要求标签号与长度均为最短编码且不允许不定长
+pub(crate) fn read_header(input: &[u8], der: bool) -> Result<Header> {
+    let mut bytes = input.iter().copied();
+    let mut next = || bytes.next().ok_or("[Asn1] unexpected end of data");
+
+    let first = next()?;
+    let class = match first >> 6 {
+        0 => TagClass::Universal,
+        1 => TagClass::Application,
+        2 => TagClass::ContextSpecific,
+        _ => TagClass::Private,
+    };
+    let constructed = first & 0x20 != 0;
+    let mut number = (first & 0x1f) as u32;
+    let mut header_len = 1;
+    if number == 0x1f {
+        number = 0;
+        loop {
+            let byte = next()?;
+            header_len += 1;
+            if der && number == 0 && byte == 0x80 {
+                return Err("[Asn1] tag number is not minimally encoded".into());
+            }
+            if number >> 25 != 0 {
+                return Err("[Asn1] tag number is too large".into());
+            }
+            number = (number << 7) | (byte & 0x7f) as u32;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        if der && number < 31 {
+            return Err("[Asn1] tag number is not minimally encoded".into());
+        }
+    }
+
+    let byte = next()?;
+    header_len += 1;
+    let length = match byte {
+        0x00..=0x7f => Some(byte as usize),
+        0x80 => {
+            if der {
+                return Err("[Asn1] indefinite length is not allowed in DER".into());
+            }
+            if !constructed {
+                return Err("[Asn1] indefinite length requires a constructed encoding".into());
+            }
+            None
+        }
+        0xff => return Err("[Asn1] reserved length octet".into()),
+        _ => {
+            let count = (byte & 0x7f) as usize;
+            let mut length = 0usize;
+            for i in 0..count {
+                let byte = next()?;
+                if der && i == 0 && byte == 0 {
+                    return Err("[Asn1] length is not minimally encoded".into());
+                }
+                if length >> (usize::BITS - 8) != 0 {
+                    return Err("[Asn1] length is too large".into());
+                }
+                length = (length << 8) | byte as usize;
+            }
+            header_len += count;
+            if der && length < 0x80 {
+                return Err("[Asn1] length is not minimally encoded".into());
+            }
+            Some(length)
+        }
+    };
+
+    let tag = Tag::new(class, constructed, number);
+    check_form(&tag, der)?;
+    Ok(Header {
+        tag,
+        header_len,
+        length,
+    })
+}
+
+// X.690 规定部分通用类型只能为基本型或构造型, DER 中字符串只能为基本型
+fn check_form(tag: &Tag, der: bool) -> Result<()> {
+    if tag.class != TagClass::Universal {
+        return Ok(());
+    }
+    let valid = match tag.number {
+        0 | 1 | 2 | 5 | 6 | 9 | 10 => !tag.constructed,
+        8 | 11 | 16 | 17 => tag.constructed,
+        _ => !(der && tag.constructed),
+    };
+    if !valid {
+        return Err(format!("[Asn1] invalid {} encoding of {}", form(tag), tag).into());
+    }
+    Ok(())
+}
+
+fn form(tag: &Tag) -> &'static str {
+    if tag.constructed {
+        "constructed"
+    } else {
+        "primitive"
+    }
+}
+
+// 解码一个元素, 返回元素及其占用的字节数
+pub(super) fn decode(input: &[u8], der: bool, depth: usize) -> Result<(Asn1, usize)> {
+    if depth > MAX_DEPTH {
+        return Err("[Asn1] nesting is too deep".into());
+    }
+    let header = read_header(input, der)?;
+    let body = &input[header.header_len..];
+    let tag = header.tag;
+
+    let length = match header.length {
+        Some(length) => length,
+        None => {
+            // 不定长: 子元素直到结束标记 00 00
+            let mut children = vec![];
+            let mut position = 0;
+            loop {
+                if position >= body.len() {
+                    return Err("[Asn1] missing end-of-contents".into());
+                }
+                let (child, used) = decode(&body[position..], der, depth + 1)?;
+                position += used;
+                if child.tag == Tag::EOC {
+                    break;
+                }
+                children.push(child);
+            }
+            let node = Asn1::constructed(tag, children);
+            return Ok((node, header.header_len + position));
+        }
+    };
+    if length > body.len() {
+        return Err("[Asn1] length exceeds the remaining data".into());
+    }
+    let content = &body[..length];
+
+    let node = if tag.constructed {
+        let mut children = vec![];
+        let mut position = 0;
+        while position < length {
+            let (child, used) = decode(&content[position..], der, depth + 1)?;
+            if child.tag == Tag::EOC {
+                return Err("[Asn1] unexpected end-of-contents".into());
+            }
+            children.push(child);
+            position += used;
+        }
+        Asn1::constructed(tag, children)
+    } else {
+        if tag == Tag::EOC && length != 0 {
+            return Err("[Asn1] end-of-contents must be empty".into());
+        }
+        Asn1::new(tag, content)
+    };
+    Ok((node, header.header_len + length))
+}
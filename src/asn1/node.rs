@@ -0,0 +1,303 @@
+use crate::asn1::decoder::decode;
+use crate::asn1::{Oid, Tag, TagClass};
+use crate::bigint::{BigInt, BigUint};
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::types::Result;
+
+// ASN.1 语法树的节点
+// 基本型节点的值在 content 中, 构造型节点的值为 children
+#[derive(Clone, Debug)]
+pub struct Asn1 {
+    pub tag: Tag,
+    pub content: Bytes,
+    pub children: Vec<Asn1>,
+}
+
+impl Asn1 {
+    pub fn new(tag: Tag, content: &[u8]) -> Self {
+        Self {
+            tag: Tag {
+                constructed: false,
+                ..tag
+            },
+            content: Bytes::new(content),
+            children: vec![],
+        }
+    }
+
+    pub fn constructed(tag: Tag, children: Vec<Asn1>) -> Self {
+        Self {
+            tag: Tag {
+                constructed: true,
+                ..tag
+            },
+            content: Bytes::default(),
+            children,
+        }
+    }
+
+    // 严格的 DER: 最短长度、无不定长, 且输入恰好为一个元素
+    pub fn from_der(input: &[u8]) -> Result<Self> {
+        Self::decode_all(input, true)
+    }
+
+    // BER: 允许不定长与构造型字符串
+    pub fn from_ber(input: &[u8]) -> Result<Self> {
+        Self::decode_all(input, false)
+    }
+
+    fn decode_all(input: &[u8], der: bool) -> Result<Self> {
+        let (node, used) = decode(input, der, 0)?;
+        if node.tag == Tag::EOC {
+            return Err("[Asn1] unexpected end-of-contents".into());
+        }
+        if used != input.len() {
+            return Err("[Asn1] trailing data after the element".into());
+        }
+        Ok(node)
+    }
+
+    // 保持子元素原有顺序, 因此 DER 输入可以逐字节还原
+    // 不定长编码转为定长, BER 的构造型字符串合并为基本型
+    pub fn to_der(&self) -> Bytes {
+        let mut output = vec![];
+        self.encode(&mut output);
+        Bytes::new(output)
+    }
+
+    fn encode(&self, output: &mut Vec<u8>) {
+        if let Some(content) = self.flatten_string() {
+            return Self::new(self.tag, &content).encode(output);
+        }
+        output.extend(self.tag.to_der());
+        if self.tag.constructed {
+            let mut content = vec![];
+            for child in &self.children {
+                child.encode(&mut content);
+            }
+            encode_length(content.len(), output);
+            output.extend(content);
+        } else {
+            encode_length(self.content.len(), output);
+            output.extend(self.content.iter());
+        }
+    }
+
+    // 构造型的通用字符串类型合并后的内容
+    fn flatten_string(&self) -> Option<Vec<u8>> {
+        let is_string = matches!(self.tag.number, 3 | 4 | 12 | 18..=30);
+        if !self.tag.constructed || self.tag.class != TagClass::Universal || !is_string {
+            return None;
+        }
+        if self.tag.number != 3 {
+            return self.as_octet_string().ok().map(|value| value.to_vec());
+        }
+        // 比特串的各段只有最后一段可以有未使用的比特
+        let mut value = vec![0x00];
+        for child in &self.children {
+            let segment = match child.flatten_string() {
+                Some(segment) => segment,
+                None => child.content.to_vec(),
+            };
+            let (&unused, bits) = segment.split_first()?;
+            if value[0] != 0 {
+                return None;
+            }
+            value[0] = unused;
+            value.extend(bits);
+        }
+        Some(value)
+    }
+
+    pub fn boolean(value: bool) -> Self {
+        Self::new(Tag::BOOLEAN, &[if value { 0xff } else { 0x00 }])
+    }
+
+    pub fn integer(value: &BigInt) -> Self {
+        Self::new(Tag::INTEGER, &value.to_signed_bytes(Endian::Big))
+    }
+
+    pub fn uint(value: &BigUint) -> Self {
+        Self::integer(&BigInt::from(value.clone()))
+    }
+
+    pub fn null() -> Self {
+        Self::new(Tag::NULL, &[])
+    }
+
+    pub fn oid(oid: &Oid) -> Self {
+        Self::new(Tag::OBJECT_IDENTIFIER, &oid.to_der())
+    }
+
+    pub fn octet_string(value: &[u8]) -> Self {
+        Self::new(Tag::OCTET_STRING, value)
+    }
+
+    // 未使用比特数为 0 的比特串
+    pub fn bit_string(value: &[u8]) -> Self {
+        Self::new(Tag::BIT_STRING, &[&[0x00], value].concat())
+    }
+
+    pub fn utf8_string(value: &str) -> Self {
+        Self::new(Tag::UTF8_STRING, value.as_bytes())
+    }
+
+    pub fn printable_string(value: &str) -> Self {
+        Self::new(Tag::PRINTABLE_STRING, value.as_bytes())
+    }
+
+    pub fn ia5_string(value: &str) -> Self {
+        Self::new(Tag::IA5_STRING, value.as_bytes())
+    }
+
+    pub fn sequence(children: Vec<Asn1>) -> Self {
+        Self::constructed(Tag::SEQUENCE, children)
+    }
+
+    // DER 要求 SET OF 的元素按编码排序
+    pub fn set(children: Vec<Asn1>) -> Self {
+        let mut children: Vec<(Bytes, Asn1)> = children
+            .into_iter()
+            .map(|child| (child.to_der(), child))
+            .collect();
+        children.sort_by(|a, b| a.0.cmp(&b.0));
+        Self::constructed(Tag::SET, children.into_iter().map(|(_, c)| c).collect())
+    }
+
+    // [number] EXPLICIT: 外加一层构造型标签
+    pub fn explicit(number: u32, inner: Asn1) -> Self {
+        Self::constructed(Tag::context(number, true), vec![inner])
+    }
+
+    // [number] IMPLICIT: 替换原标签, 保留基本型或构造型
+    pub fn implicit(number: u32, inner: Asn1) -> Self {
+        Self {
+            tag: Tag::context(number, inner.tag.constructed),
+            ..inner
+        }
+    }
+
+    // 校验标签, 便于链式解析
+    pub fn expect(&self, tag: Tag) -> Result<&Self> {
+        if !self.tag.matches(&tag) {
+            return Err(format!("[Asn1] expected {}, found {}", tag, self.tag).into());
+        }
+        Ok(self)
+    }
+
+    pub fn child(&self, index: usize) -> Result<&Asn1> {
+        self.children
+            .get(index)
+            .ok_or_else(|| format!("[Asn1] missing element {} in {}", index, self.tag).into())
+    }
+
+    // 下列方法只检查编码是否为基本型, 不检查标签, 以便处理 IMPLICIT 标签
+    fn primitive(&self) -> Result<&[u8]> {
+        if self.tag.constructed {
+            return Err(format!("[Asn1] {} must be primitive", self.tag).into());
+        }
+        Ok(&self.content)
+    }
+
+    pub fn as_bool(&self) -> Result<bool> {
+        match self.primitive()? {
+            [0x00] => Ok(false),
+            [_] => Ok(true),
+            _ => Err("[Asn1] invalid BOOLEAN".into()),
+        }
+    }
+
+    pub fn as_integer(&self) -> Result<BigInt> {
+        let content = self.primitive()?;
+        if content.is_empty() {
+            return Err("[Asn1] empty INTEGER".into());
+        }
+        Ok(BigInt::from_signed_bytes(content, Endian::Big))
+    }
+
+    pub fn as_biguint(&self) -> Result<BigUint> {
+        self.as_integer()?
+            .to_biguint()
+            .ok_or_else(|| "[Asn1] INTEGER must be non-negative".into())
+    }
+
+    pub fn as_u64(&self) -> Result<u64> {
+        self.as_biguint()?
+            .to_u64()
+            .ok_or_else(|| "[Asn1] INTEGER is too large".into())
+    }
+
+    pub fn as_oid(&self) -> Result<Oid> {
+        Oid::from_der(self.primitive()?)
+    }
+
+    // 返回比特串内容及未使用的比特数
+    pub fn as_bit_string(&self) -> Result<(Bytes, u8)> {
+        let content = match self.flatten_string() {
+            Some(content) => content,
+            None => self.primitive()?.to_vec(),
+        };
+        match content.split_first() {
+            Some((&unused, value)) if unused < 8 && (unused == 0 || !value.is_empty()) => {
+                Ok((Bytes::new(value), unused))
+            }
+            _ => Err("[Asn1] invalid BIT STRING".into()),
+        }
+    }
+
+    // BER 的构造型八位组串按顺序拼接
+    pub fn as_octet_string(&self) -> Result<Bytes> {
+        if !self.tag.constructed {
+            return Ok(self.content.clone());
+        }
+        let mut value = vec![];
+        for child in &self.children {
+            value.extend(child.as_octet_string()?.iter());
+        }
+        Ok(Bytes::new(value))
+    }
+
+    // 各种字符串及时间类型的文本
+    pub fn as_string(&self) -> Result<String> {
+        let content = self.as_octet_string()?;
+        let invalid = || format!("[Asn1] invalid {}", self.tag);
+        match self.tag.number {
+            // BMPString 为 UTF-16BE, UniversalString 为 UTF-32BE
+            30 => {
+                if content.len() % 2 != 0 {
+                    return Err(invalid().into());
+                }
+                let units: Vec<u16> = content
+                    .chunks(2)
+                    .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16(&units).map_err(|_| invalid().into())
+            }
+            28 => {
+                if content.len() % 4 != 0 {
+                    return Err(invalid().into());
+                }
+                content
+                    .chunks(4)
+                    .map(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]])))
+                    .collect::<Option<String>>()
+                    .ok_or_else(|| invalid().into())
+            }
+            // T61String 按 Latin-1 处理
+            20 => Ok(content.iter().map(|&b| b as char).collect()),
+            _ => String::from_utf8(content.to_vec()).map_err(|_| invalid().into()),
+        }
+    }
+}
+
+fn encode_length(length: usize, output: &mut Vec<u8>) {
+    if length < 0x80 {
+        output.push(length as u8);
+        return;
+    }
+    let bytes = length.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&b| b == 0).count();
+    output.push(0x80 | (bytes.len() - skip) as u8);
+    output.extend(&bytes[skip..]);
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::asn1::oid_names::OID_NAMES;
+use crate::bytes::Bytes;
+use crate::types::{Error, Result};
+
+// 对象标识符, 如 1.2.840.113549.1.1.1
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Oid(Vec<u64>);
+
+impl Oid {
+    // 至少两段, 第一段为 0、1、2, 第一段小于 2 时第二段小于 40
+    pub fn new(arcs: &[u64]) -> Result<Self> {
+        match arcs {
+            [0 | 1, second, ..] if *second < 40 => {}
+            [2, second, ..] if *second <= u64::MAX - 80 => {}
+            _ => return Err("[Oid] invalid object identifier".into()),
+        }
+        Ok(Self(arcs.to_vec()))
+    }
+
+    // 由 DER 内容八位组 (不含标签与长度) 解码
+    pub fn from_der(content: &[u8]) -> Result<Self> {
+        if content.is_empty() || content.last().unwrap() & 0x80 != 0 {
+            return Err("[Oid] invalid object identifier encoding".into());
+        }
+        let mut values = vec![];
+        let mut value = 0u64;
+        let mut start = true;
+        for &byte in content {
+            if start && byte == 0x80 {
+                return Err("[Oid] subidentifier is not minimally encoded".into());
+            }
+            if value >> 57 != 0 {
+                return Err("[Oid] subidentifier is too large".into());
+            }
+            value = (value << 7) | (byte & 0x7f) as u64;
+            start = byte & 0x80 == 0;
+            if start {
+                values.push(value);
+                value = 0;
+            }
+        }
+        // 第一个子标识符为 40 * a + b
+        let (first, second) = match values[0] {
+            v @ 0..=39 => (0, v),
+            v @ 40..=79 => (1, v - 40),
+            v => (2, v - 80),
+        };
+        let mut arcs = vec![first, second];
+        arcs.extend(&values[1..]);
+        Ok(Self(arcs))
+    }
+
+    pub fn to_der(&self) -> Bytes {
+        let mut output = vec![];
+        for value in std::iter::once(first_arc(&self.0)).chain(self.0[2..].iter().copied()) {
+            let mut encoded = vec![(value & 0x7f) as u8];
+            let mut value = value >> 7;
+            while value > 0 {
+                encoded.push(0x80 | (value & 0x7f) as u8);
+                value >>= 7;
+            }
+            output.extend(encoded.iter().rev());
+        }
+        Bytes::new(output)
+    }
+
+    pub fn arcs(&self) -> &[u64] {
+        &self.0
+    }
+
+    // 常用对象标识符的名称
+    pub fn name(&self) -> Option<&'static str> {
+        let dotted = self.to_string();
+        OID_NAMES
+            .iter()
+            .find(|(oid, _)| *oid == dotted)
+            .map(|(_, name)| *name)
+    }
+}
+
+fn first_arc(arcs: &[u64]) -> u64 {
+    40 * arcs[0] + arcs[1]
+}
+
+impl FromStr for Oid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let arcs = s
+            .split('.')
+            .map(|arc| arc.parse::<u64>())
+            .collect::<std::result::Result<Vec<u64>, _>>()
+            .map_err(|_| format!("[Oid] invalid object identifier: {}", s))?;
+        Self::new(&arcs)
+    }
+}
+
+impl fmt::Display for Oid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arcs: Vec<String> = self.0.iter().map(|arc| arc.to_string()).collect();
+        f.write_str(&arcs.join("."))
+    }
+}
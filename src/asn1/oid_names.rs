@@ -0,0 +1,144 @@
+// 常用对象标识符及其名称 (与 OpenSSL 的长名称一致)
+pub(super) const OID_NAMES: &[(&str, &str)] = &[
+    ("0.9.2342.19200300.100.1.1", "userId"),
+    ("0.9.2342.19200300.100.1.25", "domainComponent"),
+    ("1.2.156.10197.1.104.2", "sm4-cbc"),
+    ("1.2.156.10197.1.301", "sm2"),
+    ("1.2.156.10197.1.401", "sm3"),
+    ("1.2.156.10197.1.501", "SM2-with-SM3"),
+    ("1.2.840.10040.4.1", "dsaEncryption"),
+    ("1.2.840.10040.4.3", "dsaWithSHA1"),
+    ("1.2.840.10045.1.1", "prime-field"),
+    ("1.2.840.10045.2.1", "id-ecPublicKey"),
+    ("1.2.840.10045.3.1.1", "prime192v1"),
+    ("1.2.840.10045.3.1.7", "prime256v1"),
+    ("1.2.840.10045.4.1", "ecdsa-with-SHA1"),
+    ("1.2.840.10045.4.3.1", "ecdsa-with-SHA224"),
+    ("1.2.840.10045.4.3.2", "ecdsa-with-SHA256"),
+    ("1.2.840.10045.4.3.3", "ecdsa-with-SHA384"),
+    ("1.2.840.10045.4.3.4", "ecdsa-with-SHA512"),
+    ("1.2.840.10046.2.1", "X9.42 DH"),
+    ("1.2.840.113549.1.1.1", "rsaEncryption"),
+    ("1.2.840.113549.1.1.3", "md4WithRSAEncryption"),
+    ("1.2.840.113549.1.1.4", "md5WithRSAEncryption"),
+    ("1.2.840.113549.1.1.5", "sha1WithRSAEncryption"),
+    ("1.2.840.113549.1.1.7", "rsaesOaep"),
+    ("1.2.840.113549.1.1.8", "mgf1"),
+    ("1.2.840.113549.1.1.9", "pSpecified"),
+    ("1.2.840.113549.1.1.10", "rsassaPss"),
+    ("1.2.840.113549.1.1.11", "sha256WithRSAEncryption"),
+    ("1.2.840.113549.1.1.12", "sha384WithRSAEncryption"),
+    ("1.2.840.113549.1.1.13", "sha512WithRSAEncryption"),
+    ("1.2.840.113549.1.1.14", "sha224WithRSAEncryption"),
+    ("1.2.840.113549.1.5.3", "pbeWithMD5AndDES-CBC"),
+    ("1.2.840.113549.1.5.10", "pbeWithSHA1AndDES-CBC"),
+    ("1.2.840.113549.1.5.12", "PBKDF2"),
+    ("1.2.840.113549.1.5.13", "PBES2"),
+    ("1.2.840.113549.1.7.1", "pkcs7-data"),
+    ("1.2.840.113549.1.7.2", "pkcs7-signedData"),
+    ("1.2.840.113549.1.7.3", "pkcs7-envelopedData"),
+    ("1.2.840.113549.1.7.5", "pkcs7-digestData"),
+    ("1.2.840.113549.1.7.6", "pkcs7-encryptedData"),
+    ("1.2.840.113549.1.9.1", "emailAddress"),
+    ("1.2.840.113549.1.9.3", "contentType"),
+    ("1.2.840.113549.1.9.4", "messageDigest"),
+    ("1.2.840.113549.1.9.5", "signingTime"),
+    ("1.2.840.113549.1.9.7", "challengePassword"),
+    ("1.2.840.113549.1.9.14", "Extension Request"),
+    ("1.2.840.113549.1.9.15", "S/MIME Capabilities"),
+    ("1.2.840.113549.1.9.16.1.4", "id-smime-ct-TSTInfo"),
+    (
+        "1.2.840.113549.1.9.16.2.47",
+        "id-smime-aa-signingCertificateV2",
+    ),
+    ("1.2.840.113549.1.9.20", "friendlyName"),
+    ("1.2.840.113549.1.9.21", "localKeyID"),
+    ("1.2.840.113549.1.9.22.1", "x509Certificate"),
+    ("1.2.840.113549.1.9.52", "id-aa-CMSAlgorithmProtection"),
+    (
+        "1.2.840.113549.1.12.1.3",
+        "pbeWithSHA1And3-KeyTripleDES-CBC",
+    ),
+    ("1.2.840.113549.1.12.1.6", "pbeWithSHA1And40BitRC2-CBC"),
+    ("1.2.840.113549.1.12.10.1.1", "keyBag"),
+    ("1.2.840.113549.1.12.10.1.2", "pkcs8ShroudedKeyBag"),
+    ("1.2.840.113549.1.12.10.1.3", "certBag"),
+    ("1.2.840.113549.2.5", "md5"),
+    ("1.2.840.113549.2.7", "hmacWithSHA1"),
+    ("1.2.840.113549.2.9", "hmacWithSHA256"),
+    ("1.2.840.113549.2.10", "hmacWithSHA384"),
+    ("1.2.840.113549.2.11", "hmacWithSHA512"),
+    ("1.2.840.113549.3.2", "rc2-cbc"),
+    ("1.2.840.113549.3.7", "des-ede3-cbc"),
+    ("1.3.6.1.4.1.11129.2.4.2", "CT Precertificate SCTs"),
+    ("1.3.6.1.5.5.7.1.1", "Authority Information Access"),
+    ("1.3.6.1.5.5.7.1.11", "Subject Information Access"),
+    ("1.3.6.1.5.5.7.2.1", "Policy Qualifier CPS"),
+    ("1.3.6.1.5.5.7.2.2", "Policy Qualifier User Notice"),
+    ("1.3.6.1.5.5.7.3.1", "TLS Web Server Authentication"),
+    ("1.3.6.1.5.5.7.3.2", "TLS Web Client Authentication"),
+    ("1.3.6.1.5.5.7.3.3", "Code Signing"),
+    ("1.3.6.1.5.5.7.3.4", "E-mail Protection"),
+    ("1.3.6.1.5.5.7.3.8", "Time Stamping"),
+    ("1.3.6.1.5.5.7.3.9", "OCSP Signing"),
+    ("1.3.6.1.5.5.7.48.1", "OCSP"),
+    ("1.3.6.1.5.5.7.48.1.5", "OCSP No Check"),
+    ("1.3.6.1.5.5.7.48.2", "CA Issuers"),
+    ("1.3.14.3.2.7", "des-cbc"),
+    ("1.3.14.3.2.12", "dsaEncryption-old"),
+    ("1.3.14.3.2.26", "sha1"),
+    ("1.3.101.110", "X25519"),
+    ("1.3.101.111", "X448"),
+    ("1.3.101.112", "ED25519"),
+    ("1.3.101.113", "ED448"),
+    ("1.3.132.0.10", "secp256k1"),
+    ("1.3.132.0.33", "secp224r1"),
+    ("1.3.132.0.34", "secp384r1"),
+    ("1.3.132.0.35", "secp521r1"),
+    ("2.5.4.3", "commonName"),
+    ("2.5.4.4", "surname"),
+    ("2.5.4.5", "serialNumber"),
+    ("2.5.4.6", "countryName"),
+    ("2.5.4.7", "localityName"),
+    ("2.5.4.8", "stateOrProvinceName"),
+    ("2.5.4.9", "streetAddress"),
+    ("2.5.4.10", "organizationName"),
+    ("2.5.4.11", "organizationalUnitName"),
+    ("2.5.4.12", "title"),
+    ("2.5.4.42", "givenName"),
+    ("2.5.4.65", "pseudonym"),
+    ("2.5.4.97", "organizationIdentifier"),
+    ("2.5.29.14", "X509v3 Subject Key Identifier"),
+    ("2.5.29.15", "X509v3 Key Usage"),
+    ("2.5.29.17", "X509v3 Subject Alternative Name"),
+    ("2.5.29.18", "X509v3 Issuer Alternative Name"),
+    ("2.5.29.19", "X509v3 Basic Constraints"),
+    ("2.5.29.20", "X509v3 CRL Number"),
+    ("2.5.29.21", "X509v3 CRL Reason Code"),
+    ("2.5.29.30", "X509v3 Name Constraints"),
+    ("2.5.29.31", "X509v3 CRL Distribution Points"),
+    ("2.5.29.32", "X509v3 Certificate Policies"),
+    ("2.5.29.32.0", "X509v3 Any Policy"),
+    ("2.5.29.33", "X509v3 Policy Mappings"),
+    ("2.5.29.35", "X509v3 Authority Key Identifier"),
+    ("2.5.29.36", "X509v3 Policy Constraints"),
+    ("2.5.29.37", "X509v3 Extended Key Usage"),
+    ("2.5.29.37.0", "Any Extended Key Usage"),
+    ("2.16.840.1.101.3.4.1.2", "aes-128-cbc"),
+    ("2.16.840.1.101.3.4.1.5", "id-aes128-wrap"),
+    ("2.16.840.1.101.3.4.1.6", "aes-128-gcm"),
+    ("2.16.840.1.101.3.4.1.22", "aes-192-cbc"),
+    ("2.16.840.1.101.3.4.1.42", "aes-256-cbc"),
+    ("2.16.840.1.101.3.4.1.45", "id-aes256-wrap"),
+    ("2.16.840.1.101.3.4.1.46", "aes-256-gcm"),
+    ("2.16.840.1.101.3.4.2.1", "sha256"),
+    ("2.16.840.1.101.3.4.2.2", "sha384"),
+    ("2.16.840.1.101.3.4.2.3", "sha512"),
+    ("2.16.840.1.101.3.4.2.4", "sha224"),
+    ("2.16.840.1.101.3.4.2.8", "sha3-256"),
+    ("2.16.840.1.101.3.4.2.10", "sha3-512"),
+    ("2.16.840.1.101.3.4.3.1", "dsa_with_SHA224"),
+    ("2.16.840.1.101.3.4.3.2", "dsa_with_SHA256"),
+    ("2.16.840.1.113730.1.1", "Netscape Cert Type"),
+    ("2.16.840.1.113730.1.13", "Netscape Comment"),
+];
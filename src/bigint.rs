@@ -0,0 +1,41 @@
+// 任意精度整数 用于公钥密码算法
+
+// 由引用版本的运算符实现其余的所有权组合
+macro_rules! forward_binop {
+    (impl $imp:ident, $method:ident for $t:ty) => {
+        impl $imp<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $imp<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: &$t) -> $t {
+                (&self).$method(other)
+            }
+        }
+
+        impl $imp<$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                self.$method(&other)
+            }
+        }
+    };
+}
+
+mod big_int;
+mod big_uint;
+mod lattice;
+mod montgomery;
+mod prime;
+
+pub use big_int::BigInt;
+pub use big_uint::BigUint;
+pub use lattice::lll_reduce;
+pub(crate) use prime::{pollard_rho, prime_rounds};
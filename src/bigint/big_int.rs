@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::types::{Error, Result};
+
+// 符号 + 绝对值, 零总是非负
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn new(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(BigUint::one())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from(self.magnitude.clone())
+    }
+
+    // 非负时转为 BigUint
+    pub fn to_biguint(&self) -> Option<BigUint> {
+        (!self.negative).then(|| self.magnitude.clone())
+    }
+
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        Ok(Self::new(negative, BigUint::from_str_radix(digits, radix)?))
+    }
+
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude.to_str_radix(radix);
+        if self.negative {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+
+    // 补码表示
+    pub fn from_signed_bytes(bytes: &[u8], endian: Endian) -> Self {
+        let mut bytes = bytes.to_vec();
+        if let Endian::Big = endian {
+            bytes.reverse();
+        }
+        if bytes.last().is_none_or(|top| top & 0x80 == 0) {
+            return Self::from(BigUint::from_bytes(&bytes, Endian::Little));
+        }
+        let magnitude =
+            &(BigUint::one() << (bytes.len() * 8)) - &BigUint::from_bytes(&bytes, Endian::Little);
+        Self::new(true, magnitude)
+    }
+
+    // 最短的补码表示 (如 DER INTEGER)
+    pub fn to_signed_bytes(&self, endian: Endian) -> Bytes {
+        let mut bytes = if self.negative {
+            // 2^(8k) - |x|, k 取能容纳符号位的最小字节数
+            let length = (self.magnitude.bits() + 8) / 8;
+            let value = &(BigUint::one() << (length * 8)) - &self.magnitude;
+            let mut bytes = value
+                .to_bytes_padded(length, Endian::Little)
+                .unwrap()
+                .to_vec();
+            while bytes.len() > 1
+                && bytes[bytes.len() - 1] == 0xff
+                && bytes[bytes.len() - 2] & 0x80 != 0
+            {
+                bytes.pop();
+            }
+            bytes
+        } else {
+            let mut bytes = self.magnitude.to_bytes(Endian::Little).to_vec();
+            if bytes[bytes.len() - 1] & 0x80 != 0 {
+                bytes.push(0);
+            }
+            bytes
+        };
+        if let Endian::Big = endian {
+            bytes.reverse();
+        }
+        Bytes::new(bytes)
+    }
+
+    // 截断除法 余数与被除数同号
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&other.magnitude);
+        (
+            Self::new(self.negative != other.negative, quotient),
+            Self::new(self.negative, remainder),
+        )
+    }
+
+    // 非负的最小剩余
+    pub fn rem_euclid(&self, modulus: &BigUint) -> BigUint {
+        let remainder = &self.magnitude % modulus;
+        if self.negative && !remainder.is_zero() {
+            modulus - &remainder
+        } else {
+            remainder
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        Self::new(
+            self.negative && exponent & 1 == 1,
+            self.magnitude.pow(exponent),
+        )
+    }
+
+    // 返回 (g, x, y) 满足 a*x + b*y = g, g 非负
+    pub fn extended_gcd(a: &Self, b: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (a.clone(), b.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+        while !r.is_zero() {
+            let (quotient, remainder) = old_r.div_rem(&r);
+            old_r = std::mem::replace(&mut r, remainder);
+            let next_s = &old_s - &(&quotient * &s);
+            old_s = std::mem::replace(&mut s, next_s);
+            let next_t = &old_t - &(&quotient * &t);
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+        if old_r.negative {
+            (-old_r, -old_s, -old_t)
+        } else {
+            (old_r, old_s, old_t)
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self::new(false, magnitude)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        Self::new(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "0x", &self.magnitude.to_str_radix(16))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, &self.magnitude + &other.magnitude);
+        }
+        // 异号相加 用大的绝对值减小的
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => BigInt::new(other.negative, &other.magnitude - &self.magnitude),
+            _ => BigInt::new(self.negative, &self.magnitude - &other.magnitude),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            &self.magnitude * &other.magnitude,
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+forward_binop!(impl Add, add for BigInt);
+forward_binop!(impl Sub, sub for BigInt);
+forward_binop!(impl Mul, mul for BigInt);
+forward_binop!(impl Div, div for BigInt);
+forward_binop!(impl Rem, rem for BigInt);
@@ -0,0 +1,651 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+use crate::bigint::montgomery::Montgomery;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::types::{Error, Result};
+
+// 小端序的 64 位字, 高位不含 0, 零为空数组
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    pub(crate) fn from_limbs(limbs: Vec<u64>) -> Self {
+        let mut value = Self { limbs };
+        value.normalize();
+        value
+    }
+
+    pub(crate) fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.limbs == [1]
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb & 1 == 0)
+    }
+
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0]),
+            _ => None,
+        }
+    }
+
+    // 比特长度 零为 0
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * 64 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn bit(&self, i: usize) -> bool {
+        self.limbs
+            .get(i / 64)
+            .is_some_and(|limb| limb >> (i % 64) & 1 == 1)
+    }
+
+    pub fn set_bit(&mut self, i: usize, value: bool) {
+        if self.limbs.len() <= i / 64 {
+            if !value {
+                return;
+            }
+            self.limbs.resize(i / 64 + 1, 0);
+        }
+        if value {
+            self.limbs[i / 64] |= 1 << (i % 64);
+        } else {
+            self.limbs[i / 64] &= !(1 << (i % 64));
+            self.normalize();
+        }
+    }
+
+    // 末尾 0 的个数 零没有定义
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let i = self.limbs.iter().position(|&limb| limb != 0)?;
+        Some(i * 64 + self.limbs[i].trailing_zeros() as usize)
+    }
+
+    pub fn from_bytes(bytes: &[u8], endian: Endian) -> Self {
+        let mut bytes = bytes.to_vec();
+        if let Endian::Big = endian {
+            bytes.reverse();
+        }
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| {
+                let mut limb = [0u8; 8];
+                limb[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(limb)
+            })
+            .collect();
+        Self::from_limbs(limbs)
+    }
+
+    // 最短表示 零输出一个字节 0
+    pub fn to_bytes(&self, endian: Endian) -> Bytes {
+        let length = self.bits().div_ceil(8).max(1);
+        let mut bytes: Vec<u8> = self
+            .limbs
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        bytes.resize(length, 0);
+        if let Endian::Big = endian {
+            bytes.reverse();
+        }
+        Bytes::new(bytes)
+    }
+
+    // 补齐到指定长度 (如 RSA 的 I2OSP)
+    pub fn to_bytes_padded(&self, length: usize, endian: Endian) -> Result<Bytes> {
+        if self.bits().div_ceil(8) > length {
+            return Err("[BigUint] integer too large for the given length".into());
+        }
+        let mut bytes = self.to_bytes(Endian::Little).to_vec();
+        bytes.resize(length, 0);
+        if let Endian::Big = endian {
+            bytes.reverse();
+        }
+        Ok(Bytes::new(bytes))
+    }
+
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
+        if !(2..=36).contains(&radix) {
+            return Err("[BigUint] radix must be in 2..=36".into());
+        }
+        let s = s.replace('_', "");
+        if s.is_empty() {
+            return Err("[BigUint] cannot parse integer from empty string".into());
+        }
+        let mut value = Self::zero();
+        for c in s.chars() {
+            let digit = c
+                .to_digit(radix)
+                .ok_or_else(|| format!("[BigUint] invalid digit '{}'", c))?;
+            value = mul_small(&value, radix as u64, digit as u64);
+        }
+        Ok(value)
+    }
+
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        if self.is_zero() {
+            return "0".into();
+        }
+        // 每次除以 radix 的最大幂 减少大数除法次数
+        let mut chunk_digits = 1;
+        let mut chunk = radix as u64;
+        while let Some(next) = chunk.checked_mul(radix as u64) {
+            chunk = next;
+            chunk_digits += 1;
+        }
+
+        let mut digits = Vec::new();
+        let mut value = self.clone();
+        while !value.is_zero() {
+            let (quotient, mut remainder) = div_rem_small(&value, chunk);
+            for _ in 0..chunk_digits {
+                digits
+                    .push(std::char::from_digit((remainder % radix as u64) as u32, radix).unwrap());
+                remainder /= radix as u64;
+                if quotient.is_zero() && remainder == 0 {
+                    break;
+                }
+            }
+            value = quotient;
+        }
+        digits.iter().rev().collect()
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if *self < *other {
+            return None;
+        }
+        Some(Self::from_limbs(sub_limbs(&self.limbs, &other.limbs)))
+    }
+
+    // 除数为零时 panic
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        if *self < *other {
+            return (Self::zero(), self.clone());
+        }
+        if other.limbs.len() == 1 {
+            let (quotient, remainder) = div_rem_small(self, other.limbs[0]);
+            return (quotient, Self::from(remainder));
+        }
+        let (quotient, remainder) = div_rem_limbs(&self.limbs, &other.limbs);
+        (Self::from_limbs(quotient), Self::from_limbs(remainder))
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    // 奇数模数使用 Montgomery 乘法
+    pub fn mod_pow(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(!modulus.is_zero(), "modulus must not be zero");
+        if modulus.is_one() {
+            return Self::zero();
+        }
+        if modulus.is_odd() {
+            return Montgomery::new(modulus).pow(self, exponent);
+        }
+
+        let mut base = self % modulus;
+        let mut result = Self::one();
+        for i in 0..exponent.bits() {
+            if exponent.bit(i) {
+                result = &(&result * &base) % modulus;
+            }
+            base = &(&base * &base) % modulus;
+        }
+        result
+    }
+
+    // 不互素时没有逆元
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        use crate::bigint::BigInt;
+
+        let (gcd, x, _) = BigInt::extended_gcd(
+            &BigInt::from(self % modulus),
+            &BigInt::from(modulus.clone()),
+        );
+        if !gcd.magnitude().is_one() {
+            return None;
+        }
+        Some(x.rem_euclid(modulus))
+    }
+
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        &(self / &self.gcd(other)) * other
+    }
+
+    // 向下取整的 n 次方根 牛顿迭代
+    pub fn nth_root(&self, n: u32) -> Self {
+        assert!(n > 0, "root degree must be positive");
+        if self.is_zero() || n == 1 {
+            return self.clone();
+        }
+        let bits = self.bits();
+        if bits <= n as usize {
+            return Self::one();
+        }
+
+        // 初值不小于真实的根 迭代单调递减
+        let mut x = Self::one() << bits.div_ceil(n as usize);
+        let n_minus_one = Self::from(n - 1);
+        let n = Self::from(n);
+        loop {
+            let y = &(&(&x * &n_minus_one) + &(self / &x.pow(n_minus_one.limbs[0] as u32))) / &n;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.nth_root(2)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from_limbs(vec![value])
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        Self::from(value as u64)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16))
+    }
+}
+
+impl fmt::UpperHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.limbs, &other.limbs)
+    }
+}
+
+fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = false;
+    for (i, &x) in long.iter().enumerate() {
+        let (sum, c1) = x.overflowing_add(*short.get(i).unwrap_or(&0));
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        result.push(sum);
+        carry = c1 | c2;
+    }
+    if carry {
+        result.push(1);
+    }
+    result
+}
+
+// 需保证 a >= b
+fn sub_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = false;
+    for (i, &x) in a.iter().enumerate() {
+        let (difference, b1) = x.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (difference, b2) = difference.overflowing_sub(borrow as u64);
+        result.push(difference);
+        borrow = b1 | b2;
+    }
+    result
+}
+
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let v = result[i + j] as u128 + x as u128 * y as u128 + carry;
+            result[i + j] = v as u64;
+            carry = v >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+    result
+}
+
+// value * factor + addend
+fn mul_small(value: &BigUint, factor: u64, addend: u64) -> BigUint {
+    let mut limbs = Vec::with_capacity(value.limbs.len() + 1);
+    let mut carry = addend as u128;
+    for &limb in &value.limbs {
+        let v = limb as u128 * factor as u128 + carry;
+        limbs.push(v as u64);
+        carry = v >> 64;
+    }
+    limbs.push(carry as u64);
+    BigUint::from_limbs(limbs)
+}
+
+fn div_rem_small(value: &BigUint, divisor: u64) -> (BigUint, u64) {
+    let mut quotient = vec![0u64; value.limbs.len()];
+    let mut remainder = 0u128;
+    for i in (0..value.limbs.len()).rev() {
+        let v = remainder << 64 | value.limbs[i] as u128;
+        quotient[i] = (v / divisor as u128) as u64;
+        remainder = v % divisor as u128;
+    }
+    (BigUint::from_limbs(quotient), remainder as u64)
+}
+
+fn shl_limbs(limbs: &[u64], shift: usize) -> Vec<u64> {
+    let (words, bits) = (shift / 64, shift % 64);
+    let mut result = vec![0u64; words];
+    if bits == 0 {
+        result.extend_from_slice(limbs);
+        return result;
+    }
+    let mut carry = 0u64;
+    for &limb in limbs {
+        result.push(limb << bits | carry);
+        carry = limb >> (64 - bits);
+    }
+    result.push(carry);
+    result
+}
+
+fn shr_limbs(limbs: &[u64], shift: usize) -> Vec<u64> {
+    let (words, bits) = (shift / 64, shift % 64);
+    if words >= limbs.len() {
+        return vec![];
+    }
+    let limbs = &limbs[words..];
+    if bits == 0 {
+        return limbs.to_vec();
+    }
+    (0..limbs.len())
+        .map(|i| limbs[i] >> bits | limbs.get(i + 1).map_or(0, |next| next << (64 - bits)))
+        .collect()
+}
+
+// Knuth 算法 D, 除数至少两个字
+fn div_rem_limbs(u: &[u64], v: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    // 规范化 使除数最高位为 1
+    let shift = v.last().unwrap().leading_zeros() as usize;
+    let v = shl_limbs(v, shift);
+    let v = &v[..v.len() - (shift != 0) as usize];
+    let mut u = shl_limbs(u, shift);
+    if shift == 0 {
+        u.push(0);
+    }
+    let n = v.len();
+    let m = u.len() - n - 1;
+    let mut quotient = vec![0u64; m + 1];
+    let (v_top, v_next) = (v[n - 1] as u128, v[n - 2] as u128);
+
+    for j in (0..=m).rev() {
+        // 估计商 最多偏大 2
+        let numerator = (u[j + n] as u128) << 64 | u[j + n - 1] as u128;
+        let mut q_hat = numerator / v_top;
+        let mut r_hat = numerator % v_top;
+        while q_hat >> 64 != 0 || q_hat * v_next > (r_hat << 64 | u[j + n - 2] as u128) {
+            q_hat -= 1;
+            r_hat += v_top;
+            if r_hat >> 64 != 0 {
+                break;
+            }
+        }
+
+        // u[j..j + n + 1] -= q_hat * v
+        let mut borrow = 0i128;
+        let mut carry = 0u128;
+        for i in 0..n {
+            let product = q_hat * v[i] as u128 + carry;
+            carry = product >> 64;
+            let t = u[i + j] as i128 - borrow - (product as u64) as i128;
+            u[i + j] = t as u64;
+            borrow = (t < 0) as i128;
+        }
+        let t = u[j + n] as i128 - borrow - carry as i128;
+        u[j + n] = t as u64;
+
+        // 减多了 加回一次
+        if t < 0 {
+            q_hat -= 1;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let sum = u[i + j] as u128 + v[i] as u128 + carry;
+                u[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+        quotient[j] = q_hat as u64;
+    }
+
+    (quotient, shr_limbs(&u[..n], shift))
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(add_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+// 结果为负时 panic, 需要时使用 checked_sub
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        BigUint::from_limbs(mul_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl BitAnd<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn bitand(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(
+            self.limbs
+                .iter()
+                .zip(&other.limbs)
+                .map(|(a, b)| a & b)
+                .collect(),
+        )
+    }
+}
+
+impl BitOr<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn bitor(self, other: &BigUint) -> BigUint {
+        let length = self.limbs.len().max(other.limbs.len());
+        BigUint::from_limbs(
+            (0..length)
+                .map(|i| self.limbs.get(i).unwrap_or(&0) | other.limbs.get(i).unwrap_or(&0))
+                .collect(),
+        )
+    }
+}
+
+impl BitXor<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn bitxor(self, other: &BigUint) -> BigUint {
+        let length = self.limbs.len().max(other.limbs.len());
+        BigUint::from_limbs(
+            (0..length)
+                .map(|i| self.limbs.get(i).unwrap_or(&0) ^ other.limbs.get(i).unwrap_or(&0))
+                .collect(),
+        )
+    }
+}
+
+forward_binop!(impl Add, add for BigUint);
+forward_binop!(impl Sub, sub for BigUint);
+forward_binop!(impl Mul, mul for BigUint);
+forward_binop!(impl Div, div for BigUint);
+forward_binop!(impl Rem, rem for BigUint);
+forward_binop!(impl BitAnd, bitand for BigUint);
+forward_binop!(impl BitOr, bitor for BigUint);
+forward_binop!(impl BitXor, bitxor for BigUint);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        BigUint::from_limbs(shl_limbs(&self.limbs, shift))
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        BigUint::from_limbs(shr_limbs(&self.limbs, shift))
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        &self >> shift
+    }
+}
@@ -0,0 +1,127 @@
+use crate::bigint::BigInt;
+use crate::types::Result;
+
+// 整数 LLL 约减 (Cohen 算法 2.6.7), δ = 99 / 100
+// 全程使用精确整数运算, 适用于维数较小的格, 输入各行须线性无关
+pub fn lll_reduce(basis: &[Vec<BigInt>]) -> Result<Vec<Vec<BigInt>>> {
+    let n = basis.len();
+    if n == 0 || basis.iter().any(|row| row.len() != basis[0].len()) {
+        return Err("[Lll] basis must be a non-empty matrix".into());
+    }
+    let mut lattice = Lattice {
+        b: basis.to_vec(),
+        d: vec![BigInt::one(); n + 1],
+        lambda: vec![vec![BigInt::zero(); n]; n],
+    };
+    lattice.d[1] = dot(&lattice.b[0], &lattice.b[0]);
+    if lattice.d[1].is_zero() {
+        return Err("[Lll] basis vectors must be linearly independent".into());
+    }
+
+    let (mut k, mut k_max) = (1, 0);
+    while k < n {
+        if k > k_max {
+            k_max = k;
+            lattice.gram_schmidt(k)?;
+        }
+        loop {
+            lattice.reduce(k, k - 1);
+            if lattice.lovasz_fails(k) {
+                lattice.swap(k, k_max);
+                k = (k - 1).max(1);
+            } else {
+                for l in (0..k - 1).rev() {
+                    lattice.reduce(k, l);
+                }
+                k += 1;
+                break;
+            }
+        }
+    }
+    Ok(lattice.b)
+}
+
+// d[i] 为前 i 行 Gram 矩阵的行列式, lambda[k][j] = d[j + 1] * μ(k, j)
+struct Lattice {
+    b: Vec<Vec<BigInt>>,
+    d: Vec<BigInt>,
+    lambda: Vec<Vec<BigInt>>,
+}
+
+impl Lattice {
+    fn gram_schmidt(&mut self, k: usize) -> Result<()> {
+        for j in 0..=k {
+            let mut u = dot(&self.b[k], &self.b[j]);
+            for i in 0..j {
+                u = (&(&self.d[i + 1] * &u) - &(&self.lambda[k][i] * &self.lambda[j][i]))
+                    / &self.d[i];
+            }
+            if j < k {
+                self.lambda[k][j] = u;
+            } else if u.is_zero() {
+                return Err("[Lll] basis vectors must be linearly independent".into());
+            } else {
+                self.d[k + 1] = u;
+            }
+        }
+        Ok(())
+    }
+
+    // b[k] -= round(μ(k, l)) * b[l]
+    fn reduce(&mut self, k: usize, l: usize) {
+        let two = BigInt::from(2);
+        let d = &self.d[l + 1];
+        if &two * &self.lambda[k][l].abs() <= *d {
+            return;
+        }
+        let q = div_floor(&(&(&two * &self.lambda[k][l]) + d), &(&two * d));
+        for i in 0..self.b[k].len() {
+            self.b[k][i] = &self.b[k][i] - &(&q * &self.b[l][i]);
+        }
+        self.lambda[k][l] = &self.lambda[k][l] - &(&q * d);
+        for i in 0..l {
+            self.lambda[k][i] = &self.lambda[k][i] - &(&q * &self.lambda[l][i]);
+        }
+    }
+
+    // 100 d[k + 1] d[k - 1] < 99 d[k]^2 - 100 lambda[k][k - 1]^2
+    fn lovasz_fails(&self, k: usize) -> bool {
+        let left = &BigInt::from(100) * &(&self.d[k + 1] * &self.d[k - 1]);
+        let right = &(&BigInt::from(99) * &(&self.d[k] * &self.d[k]))
+            - &(&BigInt::from(100) * &(&self.lambda[k][k - 1] * &self.lambda[k][k - 1]));
+        left < right
+    }
+
+    fn swap(&mut self, k: usize, k_max: usize) {
+        self.b.swap(k, k - 1);
+        for j in 0..k - 1 {
+            let t = self.lambda[k][j].clone();
+            self.lambda[k][j] = std::mem::replace(&mut self.lambda[k - 1][j], t);
+        }
+        let lambda = self.lambda[k][k - 1].clone();
+        let b = (&(&self.d[k - 1] * &self.d[k + 1]) + &(&lambda * &lambda)) / &self.d[k];
+        for i in k + 1..=k_max {
+            let t = self.lambda[i][k].clone();
+            self.lambda[i][k] =
+                (&(&self.d[k + 1] * &self.lambda[i][k - 1]) - &(&lambda * &t)) / &self.d[k];
+            self.lambda[i][k - 1] = (&(&b * &t) + &(&lambda * &self.lambda[i][k])) / &self.d[k + 1];
+        }
+        self.d[k] = b;
+    }
+}
+
+fn dot(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    a.iter()
+        .zip(b)
+        .fold(BigInt::zero(), |acc, (x, y)| &acc + &(x * y))
+}
+
+// 向下取整的除法, b > 0
+fn div_floor(a: &BigInt, b: &BigInt) -> BigInt {
+    let (q, r) = a.div_rem(b);
+    if r.is_negative() {
+        &q - &BigInt::one()
+    } else {
+        q
+    }
+}
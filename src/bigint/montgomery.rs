@@ -0,0 +1,120 @@
+use crate::bigint::BigUint;
+
+// 奇数模数下的 Montgomery 乘法 (CIOS), R = 2^(64n)
+pub(crate) struct Montgomery {
+    modulus: Vec<u64>,
+    // -m^-1 mod 2^64
+    m_inv: u64,
+    // R^2 mod m
+    r2: Vec<u64>,
+}
+
+impl Montgomery {
+    pub(crate) fn new(modulus: &BigUint) -> Self {
+        assert!(modulus.is_odd(), "montgomery modulus must be odd");
+        let limbs = modulus.limbs().to_vec();
+
+        // 牛顿迭代求 m^-1 mod 2^64, 每轮精度翻倍
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(limbs[0].wrapping_mul(inv)));
+        }
+
+        let r2 = &(BigUint::one() << (128 * limbs.len())) % modulus;
+        let r2 = pad(r2.limbs(), limbs.len());
+        Self {
+            modulus: limbs,
+            m_inv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    // a * b * R^-1 mod m, 输入均小于 m
+    fn mul(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let n = self.modulus.len();
+        let mut t = vec![0u64; n + 2];
+        for &b_i in b {
+            let mut carry = 0u128;
+            for j in 0..n {
+                let v = t[j] as u128 + a[j] as u128 * b_i as u128 + carry;
+                t[j] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[n] as u128 + carry;
+            t[n] = v as u64;
+            t[n + 1] = (v >> 64) as u64;
+
+            let u = t[0].wrapping_mul(self.m_inv);
+            let mut carry = (t[0] as u128 + u as u128 * self.modulus[0] as u128) >> 64;
+            for j in 1..n {
+                let v = t[j] as u128 + u as u128 * self.modulus[j] as u128 + carry;
+                t[j - 1] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[n] as u128 + carry;
+            t[n - 1] = v as u64;
+            t[n] = t[n + 1] + (v >> 64) as u64;
+        }
+
+        // 结果小于 2m, 至多减一次
+        let mut result = t[..=n].to_vec();
+        if !less_than(&result, &self.modulus) {
+            let mut borrow = false;
+            for (j, limb) in result.iter_mut().enumerate() {
+                let (d, b1) = limb.overflowing_sub(*self.modulus.get(j).unwrap_or(&0));
+                let (d, b2) = d.overflowing_sub(borrow as u64);
+                *limb = d;
+                borrow = b1 | b2;
+            }
+        }
+        result.truncate(n);
+        result
+    }
+
+    fn enter(&self, value: &BigUint) -> Vec<u64> {
+        let value = value % &BigUint::from_limbs(self.modulus.clone());
+        self.mul(&pad(value.limbs(), self.modulus.len()), &self.r2)
+    }
+
+    fn leave(&self, value: &[u64]) -> BigUint {
+        BigUint::from_limbs(self.mul(value, &pad(&[1], self.modulus.len())))
+    }
+
+    // 4 比特固定窗口
+    pub(crate) fn pow(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let base = self.enter(base);
+        let one = self.enter(&BigUint::one());
+        let mut table = vec![one.clone(), base];
+        for i in 2..16 {
+            table.push(self.mul(&table[i - 1], &table[1]));
+        }
+
+        let mut result = one;
+        let windows = exponent.bits().div_ceil(4);
+        for w in (0..windows).rev() {
+            for _ in 0..4 {
+                result = self.mul(&result, &result);
+            }
+            let index = (0..4).fold(0, |acc, i| acc | (exponent.bit(w * 4 + i) as usize) << i);
+            result = self.mul(&result, &table[index]);
+        }
+        self.leave(&result)
+    }
+}
+
+fn pad(limbs: &[u64], length: usize) -> Vec<u64> {
+    let mut limbs = limbs.to_vec();
+    limbs.resize(length, 0);
+    limbs
+}
+
+// a 可能比 b 多一个字
+fn less_than(a: &[u64], b: &[u64]) -> bool {
+    for i in (0..a.len()).rev() {
+        let y = *b.get(i).unwrap_or(&0);
+        if a[i] != y {
+            return a[i] < y;
+        }
+    }
+    false
+}
@@ -0,0 +1,162 @@
+use crate::bigint::BigUint;
+use crate::enums::Endian;
+use crate::random::random_bytes;
+use crate::types::Result;
+
+const SMALL_PRIMES: [u64; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+impl BigUint {
+    // 均匀随机的 bits 比特整数 (最高位不一定为 1)
+    pub fn random_bits(bits: usize) -> Result<Self> {
+        let mut bytes = random_bytes(bits.div_ceil(8))?;
+        if !bits.is_multiple_of(8) {
+            bytes[0] &= 0xff >> (8 - bits % 8);
+        }
+        Ok(Self::from_bytes(&bytes, Endian::Big))
+    }
+
+    // [0, bound) 内均匀随机 拒绝采样
+    pub fn random_below(bound: &Self) -> Result<Self> {
+        if bound.is_zero() {
+            return Err("[BigUint] random bound must be positive".into());
+        }
+        loop {
+            let candidate = Self::random_bits(bound.bits())?;
+            if candidate < *bound {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    // 最高两位为 1 的 bits 比特素数, 两个这样的素数相乘恰好 2*bits 比特
+    pub fn random_prime(bits: usize) -> Result<Self> {
+        if bits < 2 {
+            return Err("[BigUint] prime must be at least 2 bits".into());
+        }
+        let rounds = prime_rounds(bits);
+        loop {
+            let mut candidate = Self::random_bits(bits)?;
+            candidate.set_bit(bits - 1, true);
+            if bits > 2 {
+                candidate.set_bit(bits - 2, true);
+            }
+            candidate.set_bit(0, true);
+            if candidate.is_probable_prime(rounds) {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    // 小素数试除 + Miller-Rabin (底 2 加 rounds 个随机底)
+    pub fn is_probable_prime(&self, rounds: usize) -> bool {
+        if let Some(value) = self.to_u64() {
+            if value < 2 {
+                return false;
+            }
+            if SMALL_PRIMES.contains(&value) {
+                return true;
+            }
+        }
+        for &p in &SMALL_PRIMES {
+            if (self % &Self::from(p)).is_zero() {
+                return false;
+            }
+        }
+
+        let n_minus_one = self - &Self::one();
+        let s = n_minus_one.trailing_zeros().unwrap();
+        let d = &n_minus_one >> s;
+        let is_witness = |a: &Self| {
+            let mut x = a.mod_pow(&d, self);
+            if x.is_one() || x == n_minus_one {
+                return false;
+            }
+            for _ in 1..s {
+                x = &(&x * &x) % self;
+                if x == n_minus_one {
+                    return false;
+                }
+            }
+            true
+        };
+
+        if is_witness(&Self::from(2u64)) {
+            return false;
+        }
+        // 底取自 [2, n-2], 取随机数失败时退回到固定的小素数
+        let range = self - &Self::from(3u64);
+        for i in 0..rounds {
+            let a = match Self::random_below(&range) {
+                Ok(a) => &a + &Self::from(2u64),
+                Err(_) => Self::from(SMALL_PRIMES[(i + 1) % SMALL_PRIMES.len()]),
+            };
+            if is_witness(&a) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Pollard rho (Brent 变体) 寻找 n 的一个非平凡因子
+pub(crate) fn pollard_rho(n: &BigUint, iterations: usize) -> Option<BigUint> {
+    let f = |x: &BigUint, c: &BigUint| &(&(x * x) + c) % n;
+    let distance = |x: &BigUint, y: &BigUint| if x > y { x - y } else { y - x };
+
+    for c in 1u64..=4 {
+        let c = BigUint::from(c);
+        let mut y = BigUint::from(2u64);
+        let mut steps = 0;
+        let mut power = 1;
+        while steps < iterations {
+            let x = y.clone();
+            // 累乘 |x - y| 每 128 步求一次 gcd
+            let mut checkpoint = y.clone();
+            let mut product = BigUint::one();
+            for i in 1..=power {
+                y = f(&y, &c);
+                product = &(&product * &distance(&x, &y)) % n;
+                steps += 1;
+                if i % 128 != 0 && i != power && steps < iterations {
+                    continue;
+                }
+                let mut g = product.gcd(n);
+                if g == *n {
+                    // 越过了因子, 从检查点逐步回溯
+                    let mut z = checkpoint.clone();
+                    loop {
+                        z = f(&z, &c);
+                        g = distance(&x, &z).gcd(n);
+                        if !g.is_one() {
+                            break;
+                        }
+                    }
+                }
+                if g != *n && !g.is_one() {
+                    return Some(g);
+                }
+                if g == *n || steps >= iterations {
+                    break;
+                }
+                checkpoint = y.clone();
+                product = BigUint::one();
+            }
+            power *= 2;
+        }
+    }
+    None
+}
+
+// Miller-Rabin 轮数 参考 FIPS 186-5 表 B.1
+pub(crate) fn prime_rounds(bits: usize) -> usize {
+    match bits {
+        0..=512 => 7,
+        513..=1024 => 5,
+        1025..=1536 => 4,
+        _ => 3,
+    }
+}
@@ -0,0 +1,52 @@
+use crate::blowfish::{Blowfish, BlowfishTables, BLOCK_SIZE};
+use crate::bytes::Bytes;
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct BlowfishEncrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+    pub tables: Option<BlowfishTables>, // 自定义 P 数组和 S 盒初始值
+}
+
+impl<M: Mode, P: Padding> BlowfishEncrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            tables: None,
+        }
+    }
+
+    pub fn with_tables(key: &[u8], mode: M, tables: BlowfishTables) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            tables: Some(tables),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for BlowfishEncrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() < 4 || self.key.len() > 56 {
+            return Err("[Blowfish] key length must be between 4 and 56 bytes".into());
+        }
+        let padded_data = self.padding.pad(input);
+
+        Ok(match &self.tables {
+            Some(tables) => self
+                .mode
+                .block_cipher_encrypt(&padded_data, &Blowfish::with_tables(&self.key, tables)),
+            None => self
+                .mode
+                .cipher_encrypt::<Blowfish>(&padded_data, &self.key),
+        })
+    }
+}
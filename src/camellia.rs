@@ -0,0 +1,255 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod camellia_decrypt;
+mod camellia_encrypt;
+
+pub use camellia_decrypt::CamelliaDecrypt;
+pub use camellia_encrypt::CamelliaEncrypt;
+
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes16;
+
+// SBOX2 SBOX3 SBOX4 均由 SBOX1 循环移位得到
+const S_BOX: [u8; 256] = [
+    0x70, 0x82, 0x2c, 0xec, 0xb3, 0x27, 0xc0, 0xe5, 0xe4, 0x85, 0x57, 0x35, 0xea, 0x0c, 0xae, 0x41,
+    0x23, 0xef, 0x6b, 0x93, 0x45, 0x19, 0xa5, 0x21, 0xed, 0x0e, 0x4f, 0x4e, 0x1d, 0x65, 0x92, 0xbd,
+    0x86, 0xb8, 0xaf, 0x8f, 0x7c, 0xeb, 0x1f, 0xce, 0x3e, 0x30, 0xdc, 0x5f, 0x5e, 0xc5, 0x0b, 0x1a,
+    0xa6, 0xe1, 0x39, 0xca, 0xd5, 0x47, 0x5d, 0x3d, 0xd9, 0x01, 0x5a, 0xd6, 0x51, 0x56, 0x6c, 0x4d,
+    0x8b, 0x0d, 0x9a, 0x66, 0xfb, 0xcc, 0xb0, 0x2d, 0x74, 0x12, 0x2b, 0x20, 0xf0, 0xb1, 0x84, 0x99,
+    0xdf, 0x4c, 0xcb, 0xc2, 0x34, 0x7e, 0x76, 0x05, 0x6d, 0xb7, 0xa9, 0x31, 0xd1, 0x17, 0x04, 0xd7,
+    0x14, 0x58, 0x3a, 0x61, 0xde, 0x1b, 0x11, 0x1c, 0x32, 0x0f, 0x9c, 0x16, 0x53, 0x18, 0xf2, 0x22,
+    0xfe, 0x44, 0xcf, 0xb2, 0xc3, 0xb5, 0x7a, 0x91, 0x24, 0x08, 0xe8, 0xa8, 0x60, 0xfc, 0x69, 0x50,
+    0xaa, 0xd0, 0xa0, 0x7d, 0xa1, 0x89, 0x62, 0x97, 0x54, 0x5b, 0x1e, 0x95, 0xe0, 0xff, 0x64, 0xd2,
+    0x10, 0xc4, 0x00, 0x48, 0xa3, 0xf7, 0x75, 0xdb, 0x8a, 0x03, 0xe6, 0xda, 0x09, 0x3f, 0xdd, 0x94,
+    0x87, 0x5c, 0x83, 0x02, 0xcd, 0x4a, 0x90, 0x33, 0x73, 0x67, 0xf6, 0xf3, 0x9d, 0x7f, 0xbf, 0xe2,
+    0x52, 0x9b, 0xd8, 0x26, 0xc8, 0x37, 0xc6, 0x3b, 0x81, 0x96, 0x6f, 0x4b, 0x13, 0xbe, 0x63, 0x2e,
+    0xe9, 0x79, 0xa7, 0x8c, 0x9f, 0x6e, 0xbc, 0x8e, 0x29, 0xf5, 0xf9, 0xb6, 0x2f, 0xfd, 0xb4, 0x59,
+    0x78, 0x98, 0x06, 0x6a, 0xe7, 0x46, 0x71, 0xba, 0xd4, 0x25, 0xab, 0x42, 0x88, 0xa2, 0x8d, 0xfa,
+    0x72, 0x07, 0xb9, 0x55, 0xf8, 0xee, 0xac, 0x0a, 0x36, 0x49, 0x2a, 0x68, 0x3c, 0x38, 0xf1, 0xa4,
+    0x40, 0x28, 0xd3, 0x7b, 0xbb, 0xc9, 0x43, 0xc1, 0x15, 0xe3, 0xad, 0xf4, 0x77, 0xc7, 0x80, 0x9e,
+];
+
+const SIGMA: [u64; 6] = [
+    0xa09e667f3bcc908b,
+    0xb67ae8584caa73b2,
+    0xc6ef372fe94f82be,
+    0x54ff53a5f1d36f1c,
+    0x10e527fade682d1d,
+    0xb05688c2b3e6c1fd,
+];
+
+// 子密钥来源 (KL, KR, KA, KB) 以及循环左移的位数
+#[derive(Clone, Copy)]
+enum Source {
+    Kl,
+    Kr,
+    Ka,
+    Kb,
+}
+
+// 128位密钥 依次为 kw1-kw2, k1-k18, ke1-ke4, kw3-kw4
+const SCHEDULE_128: [(Source, u32); 14] = [
+    (Source::Kl, 0),
+    (Source::Ka, 0),
+    (Source::Kl, 15),
+    (Source::Ka, 15),
+    (Source::Ka, 30),
+    (Source::Kl, 45),
+    (Source::Ka, 45), // 仅使用高64位 k9
+    (Source::Kl, 60), // 仅使用低64位 k10
+    (Source::Ka, 60),
+    (Source::Kl, 77),
+    (Source::Kl, 94),
+    (Source::Ka, 94),
+    (Source::Kl, 111),
+    (Source::Ka, 111),
+];
+
+// 192/256位密钥 依次为 kw1-kw2, k1-k24, ke1-ke6, kw3-kw4
+const SCHEDULE_256: [(Source, u32); 17] = [
+    (Source::Kl, 0),
+    (Source::Kb, 0),
+    (Source::Kr, 15),
+    (Source::Ka, 15),
+    (Source::Kr, 30),
+    (Source::Kb, 30),
+    (Source::Kl, 45),
+    (Source::Ka, 45),
+    (Source::Kl, 60),
+    (Source::Kr, 60),
+    (Source::Kb, 60),
+    (Source::Kl, 77),
+    (Source::Ka, 77),
+    (Source::Kr, 94),
+    (Source::Ka, 94),
+    (Source::Kl, 111),
+    (Source::Kb, 111),
+];
+
+fn s_box(i: usize, x: u8) -> u8 {
+    match i {
+        1 => S_BOX[x as usize].rotate_left(1),
+        2 => S_BOX[x as usize].rotate_left(7),
+        3 => S_BOX[x.rotate_left(1) as usize],
+        _ => S_BOX[x as usize],
+    }
+}
+
+fn f(input: u64, key: u64) -> u64 {
+    let x = (input ^ key).to_be_bytes();
+    let t: Vec<u8> = [0, 1, 2, 3, 1, 2, 3, 0]
+        .iter()
+        .zip(x)
+        .map(|(&i, x)| s_box(i, x))
+        .collect();
+    // P 函数
+    u64::from_be_bytes([
+        t[0] ^ t[2] ^ t[3] ^ t[5] ^ t[6] ^ t[7],
+        t[0] ^ t[1] ^ t[3] ^ t[4] ^ t[6] ^ t[7],
+        t[0] ^ t[1] ^ t[2] ^ t[4] ^ t[5] ^ t[7],
+        t[1] ^ t[2] ^ t[3] ^ t[4] ^ t[5] ^ t[6],
+        t[0] ^ t[1] ^ t[5] ^ t[6] ^ t[7],
+        t[1] ^ t[2] ^ t[4] ^ t[6] ^ t[7],
+        t[2] ^ t[3] ^ t[4] ^ t[5] ^ t[7],
+        t[0] ^ t[3] ^ t[4] ^ t[5] ^ t[6],
+    ])
+}
+
+fn split(x: u64) -> (u32, u32) {
+    ((x >> 32) as u32, x as u32)
+}
+
+fn fl(input: u64, key: u64) -> u64 {
+    let ((mut x1, mut x2), (k1, k2)) = (split(input), split(key));
+    x2 ^= (x1 & k1).rotate_left(1);
+    x1 ^= x2 | k2;
+    ((x1 as u64) << 32) | x2 as u64
+}
+
+fn fl_inv(input: u64, key: u64) -> u64 {
+    let ((mut y1, mut y2), (k1, k2)) = (split(input), split(key));
+    y1 ^= y2 | k2;
+    y2 ^= (y1 & k1).rotate_left(1);
+    ((y1 as u64) << 32) | y2 as u64
+}
+
+#[derive(Debug)]
+pub struct Camellia {
+    kw: [u64; 4],
+    k: Vec<u64>,
+    ke: Vec<u64>,
+}
+
+// 每6轮之间插入 FL 与 FL^-1 层
+fn block_crypt(block: &[u8], kw: &[u64], k: &[u64], ke: &[u64]) -> Bytes {
+    let m = u128::from_be_bytes(block.try_into().unwrap());
+    let mut d1 = (m >> 64) as u64 ^ kw[0];
+    let mut d2 = m as u64 ^ kw[1];
+    for (i, pair) in k.chunks(2).enumerate() {
+        if i > 0 && i % 3 == 0 {
+            d1 = fl(d1, ke[i / 3 * 2 - 2]);
+            d2 = fl_inv(d2, ke[i / 3 * 2 - 1]);
+        }
+        d2 ^= f(d1, pair[0]);
+        d1 ^= f(d2, pair[1]);
+    }
+    d2 ^= kw[2];
+    d1 ^= kw[3];
+    Bytes::new((((d2 as u128) << 64) | d1 as u128).to_be_bytes().as_ref())
+}
+
+impl BlockCipher for Camellia {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(block, &self.kw, &self.k, &self.ke)
+    }
+
+    // 解密时子密钥逆序使用
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let kw = [self.kw[2], self.kw[3], self.kw[0], self.kw[1]];
+        let k: Vec<u64> = self.k.iter().rev().copied().collect();
+        let ke: Vec<u64> = self.ke.iter().rev().copied().collect();
+        block_crypt(block, &kw, &k, &ke)
+    }
+
+    // 密钥长度为 16, 24, 32 字节 其它长度补0到下一个标准长度
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        let length = match key.len() {
+            0..=16 => 16,
+            17..=24 => 24,
+            _ => 32,
+        };
+        key.resize(length, 0);
+
+        // 192位密钥的 KR 低64位为高64位取反
+        let kl = u128::from_be_bytes(key[..16].try_into().unwrap());
+        let kr = match length {
+            16 => 0,
+            24 => {
+                let high = u64::from_be_bytes(key[16..24].try_into().unwrap());
+                ((high as u128) << 64) | !high as u128
+            }
+            _ => u128::from_be_bytes(key[16..32].try_into().unwrap()),
+        };
+
+        let halves = |x: u128| ((x >> 64) as u64, x as u64);
+        let (mut d1, mut d2) = halves(kl ^ kr);
+        d2 ^= f(d1, SIGMA[0]);
+        d1 ^= f(d2, SIGMA[1]);
+        d1 ^= (kl >> 64) as u64;
+        d2 ^= kl as u64;
+        d2 ^= f(d1, SIGMA[2]);
+        d1 ^= f(d2, SIGMA[3]);
+        let ka = ((d1 as u128) << 64) | d2 as u128;
+        let (mut d1, mut d2) = halves(ka ^ kr);
+        d2 ^= f(d1, SIGMA[4]);
+        d1 ^= f(d2, SIGMA[5]);
+        let kb = ((d1 as u128) << 64) | d2 as u128;
+
+        let schedule: &[(Source, u32)] = if length == 16 {
+            &SCHEDULE_128
+        } else {
+            &SCHEDULE_256
+        };
+        let mut sub_keys: Vec<u64> = Vec::new();
+        for (i, &(source, shift)) in schedule.iter().enumerate() {
+            let x = match source {
+                Source::Kl => kl,
+                Source::Kr => kr,
+                Source::Ka => ka,
+                Source::Kb => kb,
+            }
+            .rotate_left(shift);
+            let (high, low) = halves(x);
+            // 128位密钥时 k9 与 k10 分别取自不同的来源
+            match (length, i) {
+                (16, 6) => sub_keys.push(high),
+                (16, 7) => sub_keys.push(low),
+                _ => sub_keys.extend([high, low]),
+            }
+        }
+
+        // 按 kw1-kw2, 轮密钥与 ke 交错, kw3-kw4 的顺序拆分
+        let rounds = if length == 16 { 18 } else { 24 };
+        let mut kw = [0u64; 4];
+        kw[..2].copy_from_slice(&sub_keys[..2]);
+        kw[2..].copy_from_slice(&sub_keys[sub_keys.len() - 2..]);
+        let mut k = Vec::with_capacity(rounds);
+        let mut ke = Vec::new();
+        for (i, chunk) in sub_keys[2..sub_keys.len() - 2].chunks(2).enumerate() {
+            // 每6轮(3组)后为一组 ke
+            if i % 4 == 3 {
+                ke.extend_from_slice(chunk);
+            } else {
+                k.extend_from_slice(chunk);
+            }
+        }
+
+        Self { kw, k, ke }
+    }
+}
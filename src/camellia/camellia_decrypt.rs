@@ -0,0 +1,31 @@
+use crate::bytes::Bytes;
+use crate::camellia::{Camellia, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct CamelliaDecrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> CamelliaDecrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for CamelliaDecrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = self.mode.cipher_decrypt::<Camellia>(input, &self.key);
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::bytes::Bytes;
+use crate::cast::{Cast5, CAST5_BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct Cast5Decrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> Cast5Decrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(CAST5_BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for Cast5Decrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = self.mode.cipher_decrypt::<Cast5>(input, &self.key);
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
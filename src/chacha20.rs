@@ -0,0 +1,178 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// "expand 32-byte k"
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+// nonce 长度为 8 字节时使用原始版本(64位计数器)
+// nonce 长度为 12 字节时使用 IETF 版本(32位计数器)
+#[derive(Debug)]
+pub struct ChaCha20 {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub counter: u64,
+    pub rounds: usize,
+}
+
+impl ChaCha20 {
+    // 轮数一般为 20, 也可以是 12 或 8
+    pub fn new(key: &[u8], nonce: &[u8], counter: u64, rounds: usize) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            counter,
+            rounds,
+        }
+    }
+}
+
+// nonce 长度为 24 字节
+#[derive(Debug)]
+pub struct XChaCha20 {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub counter: u64,
+    pub rounds: usize,
+}
+
+impl XChaCha20 {
+    pub fn new(key: &[u8], nonce: &[u8], counter: u64, rounds: usize) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            counter,
+            rounds,
+        }
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+// 置换 不包含最后的相加
+fn permute(state: &[u32; 16], rounds: usize) -> [u32; 16] {
+    let mut x = *state;
+    for _ in 0..rounds / 2 {
+        // 列变换
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        // 对角线变换
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+    x
+}
+
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+// 密钥为32字节 后4个字为 计数器 + nonce
+fn init_state(key: &[u8], tail: &[u8]) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[0..4].copy_from_slice(&SIGMA);
+    state[4..12].copy_from_slice(&to_words(key));
+    state[12..16].copy_from_slice(&to_words(tail));
+    state
+}
+
+// HChaCha20 由密钥和16字节nonce派生子密钥
+pub(crate) fn hchacha20(key: &[u8], nonce: &[u8]) -> Vec<u8> {
+    let x = permute(&init_state(key, nonce), 20);
+    [0, 1, 2, 3, 12, 13, 14, 15]
+        .iter()
+        .flat_map(|&i| x[i].to_le_bytes())
+        .collect()
+}
+
+// 生成密钥流并与输入异或
+pub(crate) fn keystream_xor(
+    key: &[u8],
+    nonce: &[u8],
+    counter: u64,
+    rounds: usize,
+    input: &[u8],
+) -> Result<Bytes> {
+    if key.len() != 32 {
+        return Err("[ChaCha20] key length must be 32 bytes".into());
+    }
+    if !matches!(rounds, 8 | 12 | 20) {
+        return Err("[ChaCha20] rounds must be 8, 12 or 20".into());
+    }
+    // 最后一个分组的计数器
+    let last_counter = counter.saturating_add((input.len().div_ceil(64) as u64).saturating_sub(1));
+    // 计数器所占字数
+    let counter_words = match nonce.len() {
+        8 => 2,
+        12 if last_counter > u32::MAX as u64 => {
+            return Err("[ChaCha20] counter must fit in 32 bits with a 12-byte nonce".into())
+        }
+        12 => 1,
+        _ => return Err("[ChaCha20] nonce length must be 8 or 12 bytes".into()),
+    };
+    let mut state = init_state(
+        key,
+        &[vec![0u8; counter_words * 4], nonce.to_vec()].concat(),
+    );
+
+    let mut output = Vec::with_capacity(input.len());
+    for (i, chunk) in input.chunks(64).enumerate() {
+        let block_counter = counter.wrapping_add(i as u64);
+        state[12] = block_counter as u32;
+        if counter_words == 2 {
+            state[13] = (block_counter >> 32) as u32;
+        }
+
+        let x = permute(&state, rounds);
+        let key_stream: Vec<u8> = x
+            .iter()
+            .zip(state.iter())
+            .flat_map(|(x, s)| x.wrapping_add(*s).to_le_bytes())
+            .collect();
+        output.extend(chunk.iter().zip(key_stream).map(|(byte, k)| byte ^ k));
+    }
+
+    Ok(Bytes::new(output))
+}
+
+// XChaCha20 的子密钥与 IETF 版本使用的 nonce
+pub(crate) fn xchacha20_sub_key(key: &[u8], nonce: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    if key.len() != 32 {
+        return Err("[XChaCha20] key length must be 32 bytes".into());
+    }
+    if nonce.len() != 24 {
+        return Err("[XChaCha20] nonce length must be 24 bytes".into());
+    }
+    let sub_key = hchacha20(key, &nonce[..16]);
+    let sub_nonce = [&[0u8; 4], &nonce[16..]].concat();
+    Ok((sub_key, sub_nonce))
+}
+
+impl Operation for ChaCha20 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        keystream_xor(&self.key, &self.nonce, self.counter, self.rounds, input)
+    }
+}
+
+impl Operation for XChaCha20 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let (sub_key, sub_nonce) = xchacha20_sub_key(&self.key, &self.nonce)?;
+        keystream_xor(&sub_key, &sub_nonce, self.counter, self.rounds, input)
+    }
+}
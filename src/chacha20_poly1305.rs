@@ -0,0 +1,70 @@
+mod chacha20_poly1305_decrypt;
+mod chacha20_poly1305_encrypt;
+mod xchacha20_poly1305_decrypt;
+mod xchacha20_poly1305_encrypt;
+
+pub use chacha20_poly1305_decrypt::ChaCha20Poly1305Decrypt;
+pub use chacha20_poly1305_encrypt::ChaCha20Poly1305Encrypt;
+pub use xchacha20_poly1305_decrypt::XChaCha20Poly1305Decrypt;
+pub use xchacha20_poly1305_encrypt::XChaCha20Poly1305Encrypt;
+
+use crate::bytes::Bytes;
+use crate::chacha20::keystream_xor;
+use crate::poly1305::poly1305_mac;
+use crate::types::Result;
+
+const TAG_LENGTH: usize = 16;
+
+// 认证标签 aad || 填充 || 密文 || 填充 || aad长度 || 密文长度
+fn compute_tag(key: &[u8], nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+    // 计数器为0的块 前32字节作为一次性密钥
+    let one_time_key = keystream_xor(key, nonce, 0, 20, &[0u8; 32])?;
+
+    let mut mac_data = aad.to_vec();
+    mac_data.resize(aad.len().div_ceil(16) * 16, 0);
+    mac_data.extend_from_slice(ciphertext);
+    mac_data.resize(mac_data.len().div_ceil(16) * 16, 0);
+    mac_data.extend_from_slice(&(aad.len() as u64).to_le_bytes());
+    mac_data.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+
+    Ok(poly1305_mac(&one_time_key, &mac_data))
+}
+
+// 加密从计数器1开始 返回值包含密文和标签
+fn aead_encrypt(key: &[u8], nonce: &[u8], aad: &[u8], input: &[u8]) -> Result<Bytes> {
+    if key.len() != 32 {
+        return Err("[ChaCha20Poly1305] key length must be 32 bytes".into());
+    }
+    if nonce.len() != 12 {
+        return Err("[ChaCha20Poly1305] nonce length must be 12 bytes".into());
+    }
+    let mut output = keystream_xor(key, nonce, 1, 20, input)?.to_vec();
+    let tag = compute_tag(key, nonce, aad, &output)?;
+    output.extend_from_slice(&tag);
+    Ok(Bytes::new(output))
+}
+
+fn aead_decrypt(key: &[u8], nonce: &[u8], aad: &[u8], input: &[u8]) -> Result<Bytes> {
+    if key.len() != 32 {
+        return Err("[ChaCha20Poly1305] key length must be 32 bytes".into());
+    }
+    if nonce.len() != 12 {
+        return Err("[ChaCha20Poly1305] nonce length must be 12 bytes".into());
+    }
+    if input.len() < TAG_LENGTH {
+        return Err("[ChaCha20Poly1305] input must include authentication tag".into());
+    }
+    let ciphertext = &input[..input.len() - TAG_LENGTH];
+    let received_tag = &input[input.len() - TAG_LENGTH..];
+
+    // 先验证标签 再解密
+    let computed_tag = compute_tag(key, nonce, aad, ciphertext)?;
+    let diff = computed_tag
+        .iter()
+        .zip(received_tag)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    if diff != 0 {
+        return Err("[ChaCha20Poly1305] authentication tag verification failed".into());
+    }
+    keystream_xor(key, nonce, 1, 20, ciphertext)
+}
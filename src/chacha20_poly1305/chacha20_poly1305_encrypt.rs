@@ -0,0 +1,29 @@
+use crate::bytes::Bytes;
+use crate::chacha20_poly1305::aead_encrypt;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 密钥32字节 nonce 12字节
+#[derive(Debug)]
+pub struct ChaCha20Poly1305Encrypt {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+}
+
+impl ChaCha20Poly1305Encrypt {
+    pub fn new(key: &[u8], nonce: &[u8], additional_data: Option<&[u8]>) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+        }
+    }
+}
+
+impl Operation for ChaCha20Poly1305Encrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+        aead_encrypt(&self.key, &self.nonce, aad, input)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::bytes::Bytes;
+use crate::chacha20::xchacha20_sub_key;
+use crate::chacha20_poly1305::aead_encrypt;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 密钥32字节 nonce 24字节
+#[derive(Debug)]
+pub struct XChaCha20Poly1305Encrypt {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+}
+
+impl XChaCha20Poly1305Encrypt {
+    pub fn new(key: &[u8], nonce: &[u8], additional_data: Option<&[u8]>) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+        }
+    }
+}
+
+impl Operation for XChaCha20Poly1305Encrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() != 32 {
+            return Err("[XChaCha20Poly1305] key length must be 32 bytes".into());
+        }
+        // 由 HChaCha20 派生子密钥 再使用 IETF 版本
+        let (sub_key, sub_nonce) = xchacha20_sub_key(&self.key, &self.nonce)?;
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+        aead_encrypt(&sub_key, &sub_nonce, aad, input)
+    }
+}
@@ -0,0 +1,14 @@
+use std::fmt::Debug;
+
+use crate::bytes::Bytes;
+use crate::enums::BlockSize;
+
+// 分组密码
+// 供需要自行派生/拆分密钥 或 同时使用加解密函数的模式调用
+pub trait BlockCipher: Debug {
+    fn block_size(&self) -> BlockSize;
+    fn encrypt_block(&self, block: &[u8]) -> Bytes;
+    fn decrypt_block(&self, block: &[u8]) -> Bytes;
+
+    fn build(key: &[u8]) -> Self;
+}
@@ -0,0 +1,126 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::random::random_bytes;
+use crate::sha2::Sha512;
+use crate::types::Result;
+
+mod ed25519_sign;
+mod ed25519_verify;
+mod field;
+mod point;
+mod x25519;
+
+use point::EdwardsPoint;
+
+pub use ed25519_sign::Ed25519Sign;
+pub use ed25519_verify::Ed25519Verify;
+pub use x25519::{X25519KeyPair, X25519};
+
+// Ed25519 的变体 (RFC 8032)
+#[derive(Clone, Debug, Default)]
+pub enum Ed25519Variant {
+    #[default]
+    Pure,
+    // Ed25519ctx, 上下文为 1 到 255 字节
+    Context(Bytes),
+    // Ed25519ph, 先对消息计算 SHA-512, 上下文不超过 255 字节
+    Prehash(Bytes),
+}
+
+impl Ed25519Variant {
+    // 返回 dom2(F, C) 与实际参与签名的消息
+    fn prepare(&self, message: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+        let (flag, context, message) = match self {
+            Self::Pure => return Ok((vec![], message.to_vec())),
+            Self::Context(context) => {
+                if context.is_empty() {
+                    return Err("[Ed25519] context must not be empty".into());
+                }
+                (0x00, context, message.to_vec())
+            }
+            Self::Prehash(context) => (0x01, context, Sha512.run(message)?.to_vec()),
+        };
+        if context.len() > 255 {
+            return Err("[Ed25519] context must not exceed 255 bytes".into());
+        }
+        let dom2 = [
+            b"SigEd25519 no Ed25519 collisions".as_ref(),
+            &[flag, context.len() as u8],
+            context,
+        ]
+        .concat();
+        Ok((dom2, message))
+    }
+}
+
+// 私钥为 32 字节的种子, 公钥为 32 字节的压缩点
+#[derive(Debug)]
+pub struct Ed25519KeyPair {
+    pub private_key: Bytes,
+    pub public_key: Bytes,
+}
+
+impl Ed25519KeyPair {
+    pub fn generate() -> Result<Self> {
+        Self::from_private_key(&random_bytes(32)?)
+    }
+
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self> {
+        let (scalar, _) = expand_private_key(private_key)?;
+        Ok(Self {
+            private_key: Bytes::new(private_key),
+            public_key: Bytes::new(EdwardsPoint::base().mul(&scalar).compress().as_ref()),
+        })
+    }
+}
+
+// 清除低 3 位与最高位, 置第 254 位
+fn clamp(bytes: &[u8]) -> [u8; 32] {
+    let mut scalar = [0u8; 32];
+    scalar.copy_from_slice(&bytes[..32]);
+    scalar[0] &= 0xf8;
+    scalar[31] &= 0x7f;
+    scalar[31] |= 0x40;
+    scalar
+}
+
+// SHA-512(seed) 的前半部分为私钥标量, 后半部分用于生成随机数
+fn expand_private_key(private_key: &[u8]) -> Result<([u8; 32], Vec<u8>)> {
+    if private_key.len() != 32 {
+        return Err("[Ed25519] private key length must be 32 bytes".into());
+    }
+    let digest = Sha512.run(private_key)?;
+    Ok((clamp(&digest), digest[32..].to_vec()))
+}
+
+pub(crate) fn parse_public_key(public_key: &[u8]) -> Result<EdwardsPoint> {
+    let bytes: [u8; 32] = public_key
+        .try_into()
+        .map_err(|_| "[Ed25519] public key length must be 32 bytes")?;
+    EdwardsPoint::decompress(&bytes).ok_or_else(|| "[Ed25519] invalid public key".into())
+}
+
+// 基点的阶 L = 2^252 + 27742317777372353535851937790883648493
+fn group_order() -> BigUint {
+    (BigUint::one() << 252)
+        + "27742317777372353535851937790883648493"
+            .parse::<BigUint>()
+            .unwrap()
+}
+
+// 小端序字节对 L 取模
+fn reduce_scalar(bytes: &[u8]) -> BigUint {
+    &BigUint::from_bytes(bytes, Endian::Little) % &group_order()
+}
+
+fn scalar_to_bytes(scalar: &BigUint) -> Result<Bytes> {
+    scalar.to_bytes_padded(32, Endian::Little)
+}
+
+// SHA-512(dom2 || parts...) mod L
+fn hash_to_scalar(parts: &[&[u8]]) -> Result<BigUint> {
+    let digest = Sha512.run(&parts.concat())?;
+    Ok(reduce_scalar(&digest))
+}
@@ -0,0 +1,58 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::curve25519::point::EdwardsPoint;
+use crate::curve25519::{
+    expand_private_key, group_order, hash_to_scalar, scalar_to_bytes, Ed25519Variant,
+};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 输出 R || S 共 64 字节
+#[derive(Debug)]
+pub struct Ed25519Sign {
+    pub private_key: Bytes,
+    pub variant: Ed25519Variant,
+}
+
+impl Ed25519Sign {
+    pub fn new(private_key: &[u8]) -> Self {
+        Self {
+            private_key: Bytes::new(private_key),
+            variant: Ed25519Variant::Pure,
+        }
+    }
+
+    pub fn with_context(private_key: &[u8], context: &[u8]) -> Self {
+        Self {
+            variant: Ed25519Variant::Context(Bytes::new(context)),
+            ..Self::new(private_key)
+        }
+    }
+
+    pub fn prehashed(private_key: &[u8], context: &[u8]) -> Self {
+        Self {
+            variant: Ed25519Variant::Prehash(Bytes::new(context)),
+            ..Self::new(private_key)
+        }
+    }
+}
+
+impl Operation for Ed25519Sign {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let (scalar, prefix) = expand_private_key(&self.private_key)?;
+        let (dom2, message) = self.variant.prepare(input)?;
+        let public_key = EdwardsPoint::base().mul(&scalar).compress();
+
+        // r = SHA-512(dom2 || prefix || M) mod L
+        let r = hash_to_scalar(&[&dom2, &prefix, &message])?;
+        let big_r = EdwardsPoint::base().mul(&scalar_to_bytes(&r)?).compress();
+        // S = (r + k * s) mod L, k = SHA-512(dom2 || R || A || M) mod L
+        let k = hash_to_scalar(&[&dom2, &big_r, &public_key, &message])?;
+        let s = BigUint::from_bytes(&scalar, Endian::Little);
+        let big_s = &(&r + &(&k * &s)) % &group_order();
+        Ok(Bytes::new(
+            [big_r.as_ref(), &scalar_to_bytes(&big_s)?].concat(),
+        ))
+    }
+}
@@ -0,0 +1,73 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::curve25519::point::EdwardsPoint;
+use crate::curve25519::{group_order, hash_to_scalar, parse_public_key, Ed25519Variant};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct Ed25519Verify {
+    pub public_key: Bytes,
+    pub signature: Bytes, // R || S
+    pub variant: Ed25519Variant,
+}
+
+impl Ed25519Verify {
+    pub fn new(public_key: &[u8], signature: &[u8]) -> Self {
+        Self {
+            public_key: Bytes::new(public_key),
+            signature: Bytes::new(signature),
+            variant: Ed25519Variant::Pure,
+        }
+    }
+
+    pub fn with_context(public_key: &[u8], signature: &[u8], context: &[u8]) -> Self {
+        Self {
+            variant: Ed25519Variant::Context(Bytes::new(context)),
+            ..Self::new(public_key, signature)
+        }
+    }
+
+    pub fn prehashed(public_key: &[u8], signature: &[u8], context: &[u8]) -> Self {
+        Self {
+            variant: Ed25519Variant::Prehash(Bytes::new(context)),
+            ..Self::new(public_key, signature)
+        }
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let public_key = parse_public_key(&self.public_key)?;
+        if self.signature.len() != 64 {
+            return Err("[Ed25519Verify] signature length must be 64 bytes".into());
+        }
+        let (dom2, message) = self.variant.prepare(message)?;
+
+        let big_r = match EdwardsPoint::decompress(self.signature[..32].try_into().unwrap()) {
+            Some(point) => point,
+            None => return Ok(false),
+        };
+        let s = BigUint::from_bytes(&self.signature[32..], Endian::Little);
+        if s >= group_order() {
+            return Ok(false);
+        }
+
+        // [8][S]B = [8]R + [8][k]A
+        let k = hash_to_scalar(&[&dom2, &self.signature[..32], &self.public_key, &message])?;
+        let k = k.to_bytes(Endian::Little);
+        let check = EdwardsPoint::base()
+            .mul(&self.signature[32..])
+            .add(&public_key.mul(&k).add(&big_r).neg());
+        Ok(check.mul_by_cofactor().is_identity())
+    }
+}
+
+impl Operation for Ed25519Verify {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[Ed25519Verify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
@@ -0,0 +1,166 @@
+// GF(2^255 - 19) 上的元素, 5 个 51 比特的小端序字
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fe([u64; 5]);
+
+const MASK: u64 = (1 << 51) - 1;
+
+impl Fe {
+    pub const ZERO: Self = Self([0; 5]);
+    pub const ONE: Self = Self([1, 0, 0, 0, 0]);
+
+    // 小端序 32 字节, 忽略最高位
+    pub const fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let mut words = [0u64; 4];
+        let mut i = 0;
+        while i < 32 {
+            words[i / 8] |= (bytes[i] as u64) << (i % 8 * 8);
+            i += 1;
+        }
+        Self([
+            words[0] & MASK,
+            (words[0] >> 51 | words[1] << 13) & MASK,
+            (words[1] >> 38 | words[2] << 26) & MASK,
+            (words[2] >> 25 | words[3] << 39) & MASK,
+            words[3] >> 12 & MASK,
+        ])
+    }
+
+    // 完全约减后的小端序 32 字节
+    pub fn to_bytes(self) -> [u8; 32] {
+        let mut l = self.carry().0;
+        // 值不小于 p 时 q = 1
+        let mut q = (l[0] + 19) >> 51;
+        for limb in &l[1..] {
+            q = (limb + q) >> 51;
+        }
+        l[0] += 19 * q;
+        for i in 0..4 {
+            l[i + 1] += l[i] >> 51;
+            l[i] &= MASK;
+        }
+        l[4] &= MASK;
+
+        let words = [
+            l[0] | l[1] << 51,
+            l[1] >> 13 | l[2] << 38,
+            l[2] >> 26 | l[3] << 25,
+            l[3] >> 39 | l[4] << 12,
+        ];
+        let mut bytes = [0u8; 32];
+        for (i, word) in words.iter().enumerate() {
+            bytes[i * 8..i * 8 + 8].copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Self([value & MASK, value >> 51, 0, 0, 0])
+    }
+
+    // 进位使每个字不超过 52 比特
+    fn carry(self) -> Self {
+        let mut l = self.0;
+        for i in 0..4 {
+            l[i + 1] += l[i] >> 51;
+            l[i] &= MASK;
+        }
+        l[0] += 19 * (l[4] >> 51);
+        l[4] &= MASK;
+        l[1] += l[0] >> 51;
+        l[0] &= MASK;
+        Self(l)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut l = self.0;
+        for (a, b) in l.iter_mut().zip(other.0) {
+            *a += b;
+        }
+        Self(l).carry()
+    }
+
+    // 先加上 4p 避免下溢
+    pub fn sub(&self, other: &Self) -> Self {
+        let four_p = [4 * ((1 << 51) - 19), 4 * MASK, 4 * MASK, 4 * MASK, 4 * MASK];
+        let mut l = self.0;
+        for i in 0..5 {
+            l[i] = l[i] + four_p[i] - other.0[i];
+        }
+        Self(l).carry()
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    // 2^255 = 19 (mod p)
+    pub fn mul(&self, other: &Self) -> Self {
+        let (a, b) = (self.0, other.0);
+        let m = |x: u64, y: u64| x as u128 * y as u128;
+        let b19 = [b[0], b[1] * 19, b[2] * 19, b[3] * 19, b[4] * 19];
+        let r = [
+            m(a[0], b[0]) + m(a[1], b19[4]) + m(a[2], b19[3]) + m(a[3], b19[2]) + m(a[4], b19[1]),
+            m(a[0], b[1]) + m(a[1], b[0]) + m(a[2], b19[4]) + m(a[3], b19[3]) + m(a[4], b19[2]),
+            m(a[0], b[2]) + m(a[1], b[1]) + m(a[2], b[0]) + m(a[3], b19[4]) + m(a[4], b19[3]),
+            m(a[0], b[3]) + m(a[1], b[2]) + m(a[2], b[1]) + m(a[3], b[0]) + m(a[4], b19[4]),
+            m(a[0], b[4]) + m(a[1], b[3]) + m(a[2], b[2]) + m(a[3], b[1]) + m(a[4], b[0]),
+        ];
+
+        let mut l = [0u64; 5];
+        let mut carry = 0u128;
+        for i in 0..5 {
+            let v = r[i] + carry;
+            l[i] = v as u64 & MASK;
+            carry = v >> 51;
+        }
+        let v = l[0] as u128 + carry * 19;
+        l[0] = v as u64 & MASK;
+        l[1] += (v >> 51) as u64;
+        Self(l).carry()
+    }
+
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    // 指数为小端序字节
+    fn pow(&self, exponent: &[u8; 32]) -> Self {
+        let mut result = Self::ONE;
+        for i in (0..256).rev() {
+            result = result.square();
+            if exponent[i / 8] >> (i % 8) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+
+    // a^(p - 2)
+    pub fn invert(&self) -> Self {
+        let mut exponent = [0xff; 32];
+        exponent[0] = 0xeb;
+        exponent[31] = 0x7f;
+        self.pow(&exponent)
+    }
+
+    // a^((p - 5) / 8), 用于求平方根
+    pub fn pow_p58(&self) -> Self {
+        let mut exponent = [0xff; 32];
+        exponent[0] = 0xfd;
+        exponent[31] = 0x0f;
+        self.pow(&exponent)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.to_bytes() == [0; 32]
+    }
+
+    // 约减后的最低位为 1 视为负数
+    pub fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    pub fn equals(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
@@ -0,0 +1,140 @@
+use crate::curve25519::field::Fe;
+
+// d = -121665 / 121666
+const D: Fe = Fe::from_bytes(&[
+    0xa3, 0x78, 0x59, 0x13, 0xca, 0x4d, 0xeb, 0x75, 0xab, 0xd8, 0x41, 0x41, 0x4d, 0x0a, 0x70, 0x00,
+    0x98, 0xe8, 0x79, 0x77, 0x79, 0x40, 0xc7, 0x8c, 0x73, 0xfe, 0x6f, 0x2b, 0xee, 0x6c, 0x03, 0x52,
+]);
+
+// 2^((p - 1) / 4), 即 -1 的平方根
+const SQRT_M1: Fe = Fe::from_bytes(&[
+    0xb0, 0xa0, 0x0e, 0x4a, 0x27, 0x1b, 0xee, 0xc4, 0x78, 0xe4, 0x2f, 0xad, 0x06, 0x18, 0x43, 0x2f,
+    0xa7, 0xd7, 0xfb, 0x3d, 0x99, 0x00, 0x4d, 0x2b, 0x0b, 0xdf, 0xc1, 0x4f, 0x80, 0x24, 0x83, 0x2b,
+]);
+
+// 基点 B 的编码, y = 4 / 5
+const BASE: [u8; 32] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+
+// 扭曲 Edwards 曲线 -x^2 + y^2 = 1 + d x^2 y^2 上的扩展坐标点
+// x = X / Z, y = Y / Z, x * y = T / Z
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EdwardsPoint {
+    x: Fe,
+    y: Fe,
+    z: Fe,
+    t: Fe,
+}
+
+impl EdwardsPoint {
+    pub fn identity() -> Self {
+        Self {
+            x: Fe::ZERO,
+            y: Fe::ONE,
+            z: Fe::ONE,
+            t: Fe::ZERO,
+        }
+    }
+
+    pub fn base() -> Self {
+        Self::decompress(&BASE).unwrap()
+    }
+
+    // RFC 8032 5.1.3, y 须小于 p
+    pub fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let y = Fe::from_bytes(bytes);
+        let mut canonical = y.to_bytes();
+        canonical[31] |= bytes[31] & 0x80;
+        if canonical != *bytes {
+            return None;
+        }
+        let sign = bytes[31] >> 7 == 1;
+
+        // x^2 = (y^2 - 1) / (d y^2 + 1) = u / v
+        let y2 = y.square();
+        let u = y2.sub(&Fe::ONE);
+        let v = D.mul(&y2).add(&Fe::ONE);
+        // x = u v^3 (u v^7)^((p - 5) / 8)
+        let v3 = v.square().mul(&v);
+        let v7 = v3.square().mul(&v);
+        let mut x = u.mul(&v3).mul(&u.mul(&v7).pow_p58());
+        let vx2 = v.mul(&x.square());
+        if vx2.equals(&u.neg()) {
+            x = x.mul(&SQRT_M1);
+        } else if !vx2.equals(&u) {
+            return None;
+        }
+        if x.is_zero() && sign {
+            return None;
+        }
+        if x.is_negative() != sign {
+            x = x.neg();
+        }
+        Some(Self {
+            x,
+            y,
+            z: Fe::ONE,
+            t: x.mul(&y),
+        })
+    }
+
+    pub fn compress(&self) -> [u8; 32] {
+        let z_inv = self.z.invert();
+        let x = self.x.mul(&z_inv);
+        let mut bytes = self.y.mul(&z_inv).to_bytes();
+        bytes[31] |= (x.is_negative() as u8) << 7;
+        bytes
+    }
+
+    // 完备的加法公式, 同样适用于倍点
+    pub fn add(&self, other: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&other.y.sub(&other.x));
+        let b = self.y.add(&self.x).mul(&other.y.add(&other.x));
+        let c = self.t.mul(&D).mul(&other.t);
+        let c = c.add(&c);
+        let d = self.z.mul(&other.z);
+        let d = d.add(&d);
+        let (e, f, g, h) = (b.sub(&a), d.sub(&c), d.add(&c), b.add(&a));
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            t: self.t.neg(),
+            ..*self
+        }
+    }
+
+    // 标量为小端序字节
+    pub fn mul(&self, scalar: &[u8]) -> Self {
+        let mut result = Self::identity();
+        for i in (0..scalar.len() * 8).rev() {
+            result = result.add(&result);
+            if scalar[i / 8] >> (i % 8) & 1 == 1 {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+
+    // 乘以余因子 8
+    pub fn mul_by_cofactor(&self) -> Self {
+        let mut result = *self;
+        for _ in 0..3 {
+            result = result.add(&result);
+        }
+        result
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y.equals(&self.z)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::bytes::Bytes;
+use crate::curve25519::clamp;
+use crate::curve25519::field::Fe;
+use crate::operation::Operation;
+use crate::random::random_bytes;
+use crate::types::Result;
+
+// 私钥与公钥均为 32 字节
+#[derive(Debug)]
+pub struct X25519KeyPair {
+    pub private_key: Bytes,
+    pub public_key: Bytes,
+}
+
+impl X25519KeyPair {
+    pub fn generate() -> Result<Self> {
+        Self::from_private_key(&random_bytes(32)?)
+    }
+
+    // 公钥为私钥与基点 u = 9 的乘积
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self> {
+        let mut base = [0u8; 32];
+        base[0] = 9;
+        Ok(Self {
+            private_key: Bytes::new(private_key),
+            public_key: Bytes::new(scalar_mul(private_key, &base)?.as_ref()),
+        })
+    }
+}
+
+// 输入对方公钥, 输出 32 字节的共享密钥
+#[derive(Debug)]
+pub struct X25519 {
+    pub private_key: Bytes,
+}
+
+impl X25519 {
+    pub fn new(private_key: &[u8]) -> Self {
+        Self {
+            private_key: Bytes::new(private_key),
+        }
+    }
+}
+
+impl Operation for X25519 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let shared = scalar_mul(&self.private_key, input)?;
+        // 对方公钥为小阶点时结果全零
+        if shared == [0; 32] {
+            return Err("[X25519] shared secret is all zero".into());
+        }
+        Ok(Bytes::new(shared.as_ref()))
+    }
+}
+
+// RFC 7748 Montgomery 阶梯
+fn scalar_mul(private_key: &[u8], u: &[u8]) -> Result<[u8; 32]> {
+    if private_key.len() != 32 {
+        return Err("[X25519] private key length must be 32 bytes".into());
+    }
+    let u: &[u8; 32] = u
+        .try_into()
+        .map_err(|_| "[X25519] public key length must be 32 bytes")?;
+    let k = clamp(private_key);
+    let a24 = Fe::from_u64(121665);
+
+    let x1 = Fe::from_bytes(u);
+    let (mut x2, mut z2, mut x3, mut z3) = (Fe::ONE, Fe::ZERO, x1, Fe::ONE);
+    let mut swap = false;
+    for t in (0..255).rev() {
+        let bit = k[t / 8] >> (t % 8) & 1 == 1;
+        if swap != bit {
+            std::mem::swap(&mut x2, &mut x3);
+            std::mem::swap(&mut z2, &mut z3);
+        }
+        swap = bit;
+
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.sub(&z2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+        x3 = da.add(&cb).square();
+        z3 = x1.mul(&da.sub(&cb).square());
+        x2 = aa.mul(&bb);
+        z2 = e.mul(&aa.add(&a24.mul(&e)));
+    }
+    if swap {
+        std::mem::swap(&mut x2, &mut x3);
+        std::mem::swap(&mut z2, &mut z3);
+    }
+    Ok(x2.mul(&z2.invert()).to_bytes())
+}
@@ -1,15 +1,24 @@
 use crate::bits::Bits;
 use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
 use crate::enums::Bit::{self, One, Zero};
 use crate::enums::BlockSize;
 
 mod des_decrypt;
 mod des_encrypt;
+mod des_key;
+mod desx_decrypt;
+mod desx_encrypt;
 mod triple_des_decrypt;
 mod triple_des_encrypt;
 
 pub use des_decrypt::DesDecrypt;
 pub use des_encrypt::DesEncrypt;
+pub use des_key::{
+    check_odd_parity, is_degenerate_triple_des_key, is_semi_weak_key, is_weak_key, set_odd_parity,
+};
+pub use desx_decrypt::DesXDecrypt;
+pub use desx_encrypt::DesXEncrypt;
 pub use triple_des_decrypt::TripleDesDecrypt;
 pub use triple_des_encrypt::TripleDesEncrypt;
 
@@ -709,3 +718,113 @@ fn block_crypt(sub_keys: &[Bits]) -> impl Fn(&[Bit]) -> Bits + '_ {
         final_bits.permutation(&FP)
     }
 }
+
+#[derive(Debug)]
+pub struct Des {
+    sub_keys: Vec<Bits>,
+    inv_sub_keys: Vec<Bits>,
+}
+
+impl BlockCipher for Des {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(&self.sub_keys)(&Bytes::new(block).to_bits()).to_bytes()
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(&self.inv_sub_keys)(&Bytes::new(block).to_bits()).to_bytes()
+    }
+
+    fn build(key: &[u8]) -> Self {
+        let sub_keys = key_schedule(&Bytes::new(key));
+        // 解密需要反转子密钥顺序
+        let mut inv_sub_keys = sub_keys.clone();
+        inv_sub_keys.reverse();
+        Self {
+            sub_keys,
+            inv_sub_keys,
+        }
+    }
+}
+
+// DESX 密钥为 24 字节: DES 密钥 || 前白化密钥 || 后白化密钥
+#[derive(Debug)]
+pub struct DesX {
+    des: Des,
+    pre_whitening: Bytes,
+    post_whitening: Bytes,
+}
+
+impl BlockCipher for DesX {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    // C = K2 ^ DES(K, P ^ K1)
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let block = Bytes::new(block).xor(&self.pre_whitening);
+        self.des.encrypt_block(&block).xor(&self.post_whitening)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let block = Bytes::new(block).xor(&self.post_whitening);
+        self.des.decrypt_block(&block).xor(&self.pre_whitening)
+    }
+
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        key.resize(24, 0);
+        Self {
+            des: Des::build(&key[0..8]),
+            pre_whitening: Bytes::new(&key[8..16]),
+            post_whitening: Bytes::new(&key[16..24]),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TripleDes {
+    des1: Des,
+    des2: Des,
+    des3: Des,
+}
+
+impl BlockCipher for TripleDes {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    // 加密 -> 解密 -> 加密
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let block = self.des1.encrypt_block(block);
+        let block = self.des2.decrypt_block(&block);
+        self.des3.encrypt_block(&block)
+    }
+
+    // 解密 -> 加密 -> 解密
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let block = self.des3.decrypt_block(block);
+        let block = self.des2.encrypt_block(&block);
+        self.des1.decrypt_block(&block)
+    }
+
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        // 对 2-key 3DES (也被称为2TDEA) 进行特殊处理
+        let (key1, key2, key3) = if key.len() == 16 {
+            (&key[0..8], &key[8..16], &key[0..8])
+        } else {
+            // 其它情况兼容
+            key.resize(24, 0);
+            (&key[0..8], &key[8..16], &key[16..24])
+        };
+        Self {
+            des1: Des::build(key1),
+            des2: Des::build(key2),
+            des3: Des::build(key3),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// DES 密钥每字节的最低位为奇校验位, 不参与加密
+
+// 弱密钥 加密与解密相同
+const WEAK_KEYS: [[u8; 8]; 4] = [
+    [0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01],
+    [0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe],
+    [0xe0, 0xe0, 0xe0, 0xe0, 0xf1, 0xf1, 0xf1, 0xf1],
+    [0x1f, 0x1f, 0x1f, 0x1f, 0x0e, 0x0e, 0x0e, 0x0e],
+];
+
+// 半弱密钥 成对出现, 一个密钥加密等同于另一个密钥解密
+const SEMI_WEAK_KEYS: [[u8; 8]; 12] = [
+    [0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe],
+    [0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01],
+    [0x1f, 0xe0, 0x1f, 0xe0, 0x0e, 0xf1, 0x0e, 0xf1],
+    [0xe0, 0x1f, 0xe0, 0x1f, 0xf1, 0x0e, 0xf1, 0x0e],
+    [0x01, 0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1],
+    [0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1, 0x01],
+    [0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e, 0xfe],
+    [0xfe, 0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e],
+    [0x01, 0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e],
+    [0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e, 0x01],
+    [0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1, 0xfe],
+    [0xfe, 0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1],
+];
+
+// 设置奇校验位 适用于 DES / 3DES 密钥
+pub fn set_odd_parity(key: &[u8]) -> Vec<u8> {
+    key.iter()
+        .map(|&byte| {
+            let byte = byte & 0xfe;
+            byte | ((byte.count_ones() as u8 + 1) & 1)
+        })
+        .collect()
+}
+
+// 每个字节都满足奇校验
+pub fn check_odd_parity(key: &[u8]) -> bool {
+    key.iter().all(|byte| byte.count_ones() % 2 == 1)
+}
+
+// 比较时忽略校验位
+fn same_key(a: &[u8], b: &[u8]) -> bool {
+    set_odd_parity(a) == set_odd_parity(b)
+}
+
+pub fn is_weak_key(key: &[u8]) -> bool {
+    key.len() == 8 && WEAK_KEYS.iter().any(|weak| same_key(key, weak))
+}
+
+pub fn is_semi_weak_key(key: &[u8]) -> bool {
+    key.len() == 8
+        && SEMI_WEAK_KEYS
+            .iter()
+            .any(|semi_weak| same_key(key, semi_weak))
+}
+
+// K1 == K2 或 K2 == K3 时 3DES 退化为单 DES
+pub fn is_degenerate_triple_des_key(key: &[u8]) -> bool {
+    match key.len() {
+        16 => same_key(&key[0..8], &key[8..16]),
+        24 => same_key(&key[0..8], &key[8..16]) || same_key(&key[8..16], &key[16..24]),
+        _ => false,
+    }
+}
@@ -0,0 +1,33 @@
+use crate::bytes::Bytes;
+use crate::des::{DesX, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct DesXDecrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> DesXDecrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for DesXDecrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() != 24 {
+            return Err("[DesX] key length must be 24 bytes".into());
+        }
+        let result = self.mode.cipher_decrypt::<DesX>(input, &self.key);
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
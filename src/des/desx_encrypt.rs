@@ -0,0 +1,35 @@
+use crate::bytes::Bytes;
+use crate::des::{DesX, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct DesXEncrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> DesXEncrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for DesXEncrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() != 24 {
+            return Err("[DesX] key length must be 24 bytes".into());
+        }
+        // 填充
+        let padded_data = self.padding.pad(input);
+
+        Ok(self.mode.cipher_encrypt::<DesX>(&padded_data, &self.key))
+    }
+}
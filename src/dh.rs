@@ -0,0 +1,211 @@
+use crate::bigint::{prime_rounds, BigUint};
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::types::Result;
+
+mod dh_key_exchange;
+mod discrete_log;
+mod groups;
+
+pub use dh_key_exchange::Dh;
+pub use discrete_log::{baby_step_giant_step, pohlig_hellman, DiscreteLog};
+
+// 模 p 乘法群中由 g 生成的子群, q 为 g 的阶 (未知时为 None)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DhGroup {
+    p: BigUint,
+    g: BigUint,
+    q: Option<BigUint>,
+}
+
+impl DhGroup {
+    // 自定义群不检查 p 的素性, 以便分析弱参数
+    pub fn new(p: BigUint, g: BigUint) -> Result<Self> {
+        let two = BigUint::from(2u64);
+        if p.bits() < 8 || p.is_even() {
+            return Err("[DhGroup] p must be an odd integer of at least 8 bits".into());
+        }
+        if g < two || g >= &p - &BigUint::one() {
+            return Err("[DhGroup] g must be in [2, p - 2]".into());
+        }
+        Ok(Self { p, g, q: None })
+    }
+
+    // q 须整除 p - 1 且 g^q = 1 (mod p)
+    pub fn with_order(p: BigUint, g: BigUint, q: BigUint) -> Result<Self> {
+        let group = Self::new(p, g)?;
+        let p_minus_one = &group.p - &BigUint::one();
+        if q.is_zero() || !(&p_minus_one % &q).is_zero() {
+            return Err("[DhGroup] q must divide p - 1".into());
+        }
+        if !group.g.mod_pow(&q, &group.p).is_one() {
+            return Err("[DhGroup] g^q must be 1 mod p".into());
+        }
+        Ok(Self {
+            q: Some(q),
+            ..group
+        })
+    }
+
+    // FIPS 186-4 风格的 (L, N) 参数: q 为 N 比特素数, p = k q + 1 为 L 比特素数
+    pub fn generate(p_bits: usize, q_bits: usize) -> Result<Self> {
+        if q_bits < 16 || p_bits <= q_bits + 8 {
+            return Err("[DhGroup] p must be longer than q, and q at least 16 bits".into());
+        }
+        let one = BigUint::one();
+        let q = BigUint::random_prime(q_bits)?;
+        let rounds = prime_rounds(p_bits);
+        let p = loop {
+            // 令最高位为 1, 再调整为 p = 1 (mod 2q)
+            let mut candidate = BigUint::random_bits(p_bits)?;
+            candidate.set_bit(p_bits - 1, true);
+            let remainder = &candidate % &(&q << 1);
+            let candidate = &(&candidate - &remainder) + &one;
+            if candidate.bits() == p_bits && candidate.is_probable_prime(rounds) {
+                break candidate;
+            }
+        };
+        // g = h^((p - 1) / q) != 1
+        let exponent = &(&p - &one) / &q;
+        let mut h = BigUint::from(2u64);
+        loop {
+            let g = h.mod_pow(&exponent, &p);
+            if !g.is_one() {
+                return Self::with_order(p, g, q);
+            }
+            h = &h + &one;
+        }
+    }
+
+    // 安全素数 p = 2q + 1, g = 2 生成 q 阶子群
+    fn safe_prime(hex: &str) -> Self {
+        let p = BigUint::from_str_radix(hex, 16).unwrap();
+        let q = &(&p - &BigUint::one()) >> 1;
+        Self {
+            p,
+            g: BigUint::from(2u64),
+            q: Some(q),
+        }
+    }
+
+    // RFC 3526 MODP 群
+    pub fn modp1536() -> Self {
+        Self::safe_prime(groups::MODP_1536)
+    }
+
+    pub fn modp2048() -> Self {
+        Self::safe_prime(groups::MODP_2048)
+    }
+
+    pub fn modp3072() -> Self {
+        Self::safe_prime(groups::MODP_3072)
+    }
+
+    pub fn modp4096() -> Self {
+        Self::safe_prime(groups::MODP_4096)
+    }
+
+    pub fn modp6144() -> Self {
+        Self::safe_prime(groups::MODP_6144)
+    }
+
+    pub fn modp8192() -> Self {
+        Self::safe_prime(groups::MODP_8192)
+    }
+
+    // RFC 7919 有限域 DHE 群
+    pub fn ffdhe2048() -> Self {
+        Self::safe_prime(groups::FFDHE2048)
+    }
+
+    pub fn ffdhe3072() -> Self {
+        Self::safe_prime(groups::FFDHE3072)
+    }
+
+    pub fn ffdhe4096() -> Self {
+        Self::safe_prime(groups::FFDHE4096)
+    }
+
+    pub fn ffdhe6144() -> Self {
+        Self::safe_prime(groups::FFDHE6144)
+    }
+
+    pub fn ffdhe8192() -> Self {
+        Self::safe_prime(groups::FFDHE8192)
+    }
+
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn g(&self) -> &BigUint {
+        &self.g
+    }
+
+    pub fn q(&self) -> Option<&BigUint> {
+        self.q.as_ref()
+    }
+
+    // 指数的取值范围, q 未知时为 p - 1
+    pub fn order(&self) -> BigUint {
+        match &self.q {
+            Some(q) => q.clone(),
+            None => &self.p - &BigUint::one(),
+        }
+    }
+
+    // p 的字节长度
+    pub fn size(&self) -> usize {
+        self.p.bits().div_ceil(8)
+    }
+
+    // g^x mod p
+    pub fn exp(&self, x: &BigUint) -> BigUint {
+        self.g.mod_pow(x, &self.p)
+    }
+}
+
+// DH、ElGamal 与 DSA 共用的密钥对
+// 私钥为 [1, order) 中的大端序整数, 公钥 g^x mod p 按 p 的长度补齐
+#[derive(Debug)]
+pub struct DhKeyPair {
+    pub private_key: Bytes,
+    pub public_key: Bytes,
+}
+
+impl DhKeyPair {
+    pub fn generate(group: &DhGroup) -> Result<Self> {
+        let x = &BigUint::random_below(&(&group.order() - &BigUint::one()))? + &BigUint::one();
+        Self::from_private_key(group, &x.to_bytes(Endian::Big))
+    }
+
+    pub fn from_private_key(group: &DhGroup, private_key: &[u8]) -> Result<Self> {
+        let x = parse_private_key(group, private_key)?;
+        Ok(Self {
+            private_key: Bytes::new(private_key),
+            public_key: group.exp(&x).to_bytes_padded(group.size(), Endian::Big)?,
+        })
+    }
+}
+
+pub(crate) fn parse_private_key(group: &DhGroup, private_key: &[u8]) -> Result<BigUint> {
+    let x = BigUint::from_bytes(private_key, Endian::Big);
+    if x.is_zero() || x >= group.order() {
+        return Err("[Dh] private key is out of range".into());
+    }
+    Ok(x)
+}
+
+// 1 < y < p - 1, 已知 q 时还须满足 y^q = 1 (mod p)
+pub(crate) fn parse_public_key(group: &DhGroup, public_key: &[u8]) -> Result<BigUint> {
+    let y = BigUint::from_bytes(public_key, Endian::Big);
+    if y <= BigUint::one() || y >= &group.p - &BigUint::one() {
+        return Err("[Dh] public key is out of range".into());
+    }
+    if let Some(q) = &group.q {
+        if !y.mod_pow(q, &group.p).is_one() {
+            return Err("[Dh] public key is not in the subgroup".into());
+        }
+    }
+    Ok(y)
+}
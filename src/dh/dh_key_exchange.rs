@@ -0,0 +1,33 @@
+use crate::bytes::Bytes;
+use crate::dh::{parse_private_key, parse_public_key, DhGroup};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 输入对方公钥, 输出按 p 的长度补齐的共享密钥
+#[derive(Debug)]
+pub struct Dh {
+    pub group: DhGroup,
+    pub private_key: Bytes,
+}
+
+impl Dh {
+    pub fn new(group: &DhGroup, private_key: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            private_key: Bytes::new(private_key),
+        }
+    }
+}
+
+impl Operation for Dh {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let x = parse_private_key(&self.group, &self.private_key)?;
+        let y = parse_public_key(&self.group, input)?;
+        let shared = y.mod_pow(&x, self.group.p());
+        if shared.is_one() {
+            return Err("[Dh] shared secret is 1".into());
+        }
+        shared.to_bytes_padded(self.group.size(), Endian::Big)
+    }
+}
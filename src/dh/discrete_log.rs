@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::bigint::{pollard_rho, BigUint};
+use crate::bytes::Bytes;
+use crate::dh::DhGroup;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 大步小步法的表长上限, 即可求解的阶约为 2^48
+const MAX_BABY_STEPS: u64 = 1 << 24;
+const RHO_ITERATIONS: usize = 1 << 20;
+
+// 输入 h (大端序), 输出 x 使 g^x = h (mod p)
+// factors 为 g 的阶 (或其倍数) 的素因子分解, 为空时自动分解
+#[derive(Debug)]
+pub struct DiscreteLog {
+    pub group: DhGroup,
+    pub factors: Vec<(BigUint, u32)>,
+}
+
+impl DiscreteLog {
+    pub fn new(group: &DhGroup) -> Self {
+        Self {
+            group: group.clone(),
+            factors: vec![],
+        }
+    }
+
+    pub fn with_factors(group: &DhGroup, factors: &[(BigUint, u32)]) -> Self {
+        Self {
+            factors: factors.to_vec(),
+            ..Self::new(group)
+        }
+    }
+}
+
+impl Operation for DiscreteLog {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let (p, g) = (self.group.p(), self.group.g());
+        let h = BigUint::from_bytes(input, Endian::Big);
+        if h.is_zero() || h >= *p {
+            return Err("[DiscreteLog] target is out of range".into());
+        }
+        let factors = if self.factors.is_empty() {
+            factorize(&self.group.order()).ok_or("[DiscreteLog] failed to factor group order")?
+        } else {
+            self.factors.clone()
+        };
+        let order = factors
+            .iter()
+            .fold(BigUint::one(), |acc, (q, e)| &acc * &q.pow(*e));
+        if !g.mod_pow(&order, p).is_one() {
+            return Err("[DiscreteLog] factors do not match the order of g".into());
+        }
+        let x = pohlig_hellman(g, &h, p, &factors).ok_or("[DiscreteLog] no solution found")?;
+        Ok(x.to_bytes(Endian::Big))
+    }
+}
+
+// 求 0 <= x < order 使 g^x = h (mod p), 时间和空间均为 O(sqrt(order))
+pub fn baby_step_giant_step(
+    g: &BigUint,
+    h: &BigUint,
+    p: &BigUint,
+    order: &BigUint,
+) -> Option<BigUint> {
+    let m = &order.sqrt() + &BigUint::one();
+    let steps = m.to_u64().filter(|&m| m <= MAX_BABY_STEPS)?;
+    // 以低 64 位为键, 命中后再验证
+    let key = |value: &BigUint| value.limbs().first().copied().unwrap_or(0);
+
+    let mut table = HashMap::with_capacity(steps as usize);
+    let mut value = BigUint::one();
+    for j in 0..steps {
+        table.entry(key(&value)).or_insert(j);
+        value = &(&value * g) % p;
+    }
+
+    // 巨步因子 g^-m
+    let factor = g.mod_inverse(p)?.mod_pow(&m, p);
+    let mut gamma = h % p;
+    for i in 0..steps {
+        if let Some(&j) = table.get(&key(&gamma)) {
+            let x = &(&BigUint::from(i) * &m) + &BigUint::from(j);
+            if g.mod_pow(&x, p) == *h {
+                return Some(&x % order);
+            }
+        }
+        gamma = &(&gamma * &factor) % p;
+    }
+    None
+}
+
+// 阶为光滑数时, 在每个素数幂子群中逐位求解, 再用中国剩余定理合并
+// factors 为 g 的阶 (或其倍数) 的素因子分解
+pub fn pohlig_hellman(
+    g: &BigUint,
+    h: &BigUint,
+    p: &BigUint,
+    factors: &[(BigUint, u32)],
+) -> Option<BigUint> {
+    let n = factors
+        .iter()
+        .fold(BigUint::one(), |acc, (q, e)| &acc * &q.pow(*e));
+    let (mut x, mut modulus) = (BigUint::zero(), BigUint::one());
+
+    for (q, e) in factors {
+        let cofactor = &n / &q.pow(*e);
+        let g_i = g.mod_pow(&cofactor, p);
+        let h_i = h.mod_pow(&cofactor, p);
+
+        // g_i 的实际阶为 q^e_i, e_i <= e
+        let mut e_i = 0;
+        let mut t = g_i.clone();
+        while !t.is_one() {
+            t = t.mod_pow(q, p);
+            e_i += 1;
+            if e_i > *e {
+                return None;
+            }
+        }
+        if e_i == 0 {
+            if !h_i.is_one() {
+                return None;
+            }
+            continue;
+        }
+
+        // x_i = d_0 + d_1 q + ... + d_{e_i-1} q^(e_i-1), gamma 的阶为 q
+        let gamma = g_i.mod_pow(&q.pow(e_i - 1), p);
+        let g_i_inv = g_i.mod_inverse(p)?;
+        let mut x_i = BigUint::zero();
+        for k in 0..e_i {
+            let h_k = (&g_i_inv.mod_pow(&x_i, p) * &h_i) % p;
+            let h_k = h_k.mod_pow(&q.pow(e_i - 1 - k), p);
+            let d = baby_step_giant_step(&gamma, &h_k, p, q)?;
+            x_i = &x_i + &(&d * &q.pow(k));
+        }
+
+        // x = x + modulus * ((x_i - x) / modulus mod q^e_i)
+        let m_i = q.pow(e_i);
+        let difference = &(&(&x_i + &m_i) - &(&x % &m_i)) % &m_i;
+        let t = &(&difference * &modulus.mod_inverse(&m_i)?) % &m_i;
+        x = &x + &(&modulus * &t);
+        modulus = &modulus * &m_i;
+    }
+
+    (g.mod_pow(&x, p) == h % p).then_some(x)
+}
+
+// 小素数试除后用 Pollard rho 分解, 失败时返回 None
+fn factorize(n: &BigUint) -> Option<Vec<(BigUint, u32)>> {
+    let mut factors: Vec<(BigUint, u32)> = vec![];
+    let mut add = |q: BigUint| match factors.iter_mut().find(|(f, _)| *f == q) {
+        Some((_, e)) => *e += 1,
+        None => factors.push((q, 1)),
+    };
+
+    let mut n = n.clone();
+    for d in (2u64..3).chain((3..1 << 16).step_by(2)) {
+        let d = BigUint::from(d);
+        while (&n % &d).is_zero() {
+            n = &n / &d;
+            add(d.clone());
+        }
+    }
+
+    let mut composites = vec![n];
+    while let Some(m) = composites.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if m.is_probable_prime(20) {
+            add(m);
+            continue;
+        }
+        let d = pollard_rho(&m, RHO_ITERATIONS)?;
+        composites.push(&m / &d);
+        composites.push(d);
+    }
+    factors.sort();
+    Some(factors)
+}
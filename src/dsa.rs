@@ -0,0 +1,30 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::DhGroup;
+use crate::ec::bits_to_int;
+use crate::operation::Hashing;
+use crate::types::Result;
+
+mod dsa_sign;
+mod dsa_verify;
+
+pub use dsa_sign::{DsaNonce, DsaSign};
+pub use dsa_verify::DsaVerify;
+
+// DSA 要求已知子群的阶 q
+fn subgroup_order(group: &DhGroup) -> Result<&BigUint> {
+    group
+        .q()
+        .ok_or_else(|| "[Dsa] group order q is required".into())
+}
+
+// q 的字节长度, r 和 s 均按此长度输出
+fn scalar_size(q: &BigUint) -> usize {
+    q.bits().div_ceil(8)
+}
+
+// 杂凑值最左边 q 的比特长度个比特
+fn message_scalar<H: Hashing>(q: &BigUint, message: &[u8]) -> Result<BigUint> {
+    let digest: Bytes = H::default().run(message)?;
+    Ok(bits_to_int(&digest, q))
+}
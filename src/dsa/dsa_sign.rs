@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_private_key, DhGroup};
+use crate::dsa::{message_scalar, scalar_size, subgroup_order};
+use crate::ec::Rfc6979;
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// r 或 s 为 0 时重新选取 k 的次数上限, 防止弱参数下无限循环
+const MAX_NONCE_ATTEMPTS: usize = 64;
+
+// 签名随机数 k 的来源
+#[derive(Debug, Default)]
+pub enum DsaNonce {
+    #[default]
+    Random,
+    // RFC 6979 确定性随机数
+    Deterministic,
+    // 指定 k 以复现签名
+    Fixed(Bytes),
+}
+
+// 输出 r || s, 各占 q 的字节长度
+#[derive(Debug)]
+pub struct DsaSign<H: Hashing> {
+    pub group: DhGroup,
+    pub private_key: Bytes,
+    pub nonce: DsaNonce,
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> DsaSign<H> {
+    pub fn new(group: &DhGroup, private_key: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            private_key: Bytes::new(private_key),
+            nonce: DsaNonce::Random,
+            hash_function: PhantomData,
+        }
+    }
+
+    pub fn deterministic(group: &DhGroup, private_key: &[u8]) -> Self {
+        Self {
+            nonce: DsaNonce::Deterministic,
+            ..Self::new(group, private_key)
+        }
+    }
+
+    pub fn with_k(group: &DhGroup, private_key: &[u8], k: &[u8]) -> Self {
+        Self {
+            nonce: DsaNonce::Fixed(Bytes::new(k)),
+            ..Self::new(group, private_key)
+        }
+    }
+}
+
+impl<H: Hashing> Operation for DsaSign<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let group = &self.group;
+        let q = subgroup_order(group)?;
+        let x = parse_private_key(group, &self.private_key)?;
+        let z = message_scalar::<H>(q, input)?;
+        let mut deterministic = match self.nonce {
+            DsaNonce::Deterministic => Some(Rfc6979::<H>::new(q, &x, input)?),
+            _ => None,
+        };
+
+        for _ in 0..MAX_NONCE_ATTEMPTS {
+            let k = match (&self.nonce, &mut deterministic) {
+                (DsaNonce::Fixed(k), _) => {
+                    let k = BigUint::from_bytes(k, Endian::Big);
+                    if k.is_zero() || k >= *q {
+                        return Err("[DsaSign] k is out of range".into());
+                    }
+                    k
+                }
+                (_, Some(generator)) => generator.next_nonce()?,
+                _ => &BigUint::random_below(&(q - &BigUint::one()))? + &BigUint::one(),
+            };
+
+            // r = (g^k mod p) mod q, s = k^-1 (z + x r) mod q
+            let r = &group.exp(&k) % q;
+            let k_inv = k.mod_inverse(q).ok_or("[DsaSign] k is not invertible")?;
+            let s = &(&k_inv * &(&(&z + &(&x * &r)) % q)) % q;
+            if r.is_zero() || s.is_zero() {
+                if let DsaNonce::Fixed(_) = self.nonce {
+                    return Err("[DsaSign] k is not suitable for this message".into());
+                }
+                continue;
+            }
+
+            let size = scalar_size(q);
+            return Ok(Bytes::new(
+                [
+                    r.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                    s.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                ]
+                .concat(),
+            ));
+        }
+        Err("[DsaSign] failed to find a valid nonce".into())
+    }
+}
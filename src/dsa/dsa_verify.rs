@@ -0,0 +1,64 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_public_key, DhGroup};
+use crate::dsa::{message_scalar, scalar_size, subgroup_order};
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct DsaVerify<H: Hashing> {
+    pub group: DhGroup,
+    pub public_key: Bytes,
+    pub signature: Bytes, // r || s
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> DsaVerify<H> {
+    pub fn new(group: &DhGroup, public_key: &[u8], signature: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            public_key: Bytes::new(public_key),
+            signature: Bytes::new(signature),
+            hash_function: PhantomData,
+        }
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let group = &self.group;
+        let (p, q) = (group.p(), subgroup_order(group)?);
+        let y = parse_public_key(group, &self.public_key)?;
+        let size = scalar_size(q);
+        if self.signature.len() != 2 * size {
+            return Err(format!("[DsaVerify] signature length must be {} bytes", 2 * size).into());
+        }
+        let r = BigUint::from_bytes(&self.signature[..size], Endian::Big);
+        let s = BigUint::from_bytes(&self.signature[size..], Endian::Big);
+        if r.is_zero() || r >= *q || s.is_zero() || s >= *q {
+            return Ok(false);
+        }
+
+        // v = (g^u1 y^u2 mod p) mod q, u1 = z w, u2 = r w, w = s^-1
+        let z = message_scalar::<H>(q, message)?;
+        let w = match s.mod_inverse(q) {
+            Some(w) => w,
+            None => return Ok(false),
+        };
+        let u1 = &(&z * &w) % q;
+        let u2 = &(&r * &w) % q;
+        let v = &(&(&group.exp(&u1) * &y.mod_pow(&u2, p)) % p) % q;
+        Ok(v == r)
+    }
+}
+
+impl<H: Hashing> Operation for DsaVerify<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[DsaVerify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
@@ -0,0 +1,147 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::hmac::Hmac;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+mod curve;
+mod ecdh;
+mod ecdsa_attack;
+mod ecdsa_sign;
+mod ecdsa_verify;
+mod point;
+
+pub use curve::EcCurve;
+pub use ecdh::Ecdh;
+pub use ecdsa_attack::{
+    hidden_number_attack, nonce_reuse_attack, EcdsaAttack, EcdsaAttackMethod, EcdsaAttackReport,
+    EcdsaSample,
+};
+pub use ecdsa_sign::{EcdsaNonce, EcdsaSign};
+pub use ecdsa_verify::EcdsaVerify;
+pub use point::EcPoint;
+
+// 私钥为大端序标量, 公钥为未压缩点 04 || x || y
+#[derive(Debug)]
+pub struct EcKeyPair {
+    pub private_key: Bytes,
+    pub public_key: Bytes,
+}
+
+impl EcKeyPair {
+    pub fn generate(curve: &EcCurve) -> Result<Self> {
+        let d = random_scalar(curve.n())?;
+        Self::from_private_key(curve, &d.to_bytes(Endian::Big))
+    }
+
+    pub fn from_private_key(curve: &EcCurve, private_key: &[u8]) -> Result<Self> {
+        let d = parse_private_key(curve, private_key)?;
+        Ok(Self {
+            private_key: d.to_bytes_padded(curve.scalar_size(), Endian::Big)?,
+            public_key: curve.encode_point(&curve.mul_generator(&d), false),
+        })
+    }
+}
+
+// 取 [1, n) 中的随机数
+fn random_scalar(n: &BigUint) -> Result<BigUint> {
+    Ok(&BigUint::random_below(&(n - &BigUint::one()))? + &BigUint::one())
+}
+
+// 私钥取值范围为 [1, n - 1]
+fn parse_private_key(curve: &EcCurve, private_key: &[u8]) -> Result<BigUint> {
+    let d = BigUint::from_bytes(private_key, Endian::Big);
+    if d.is_zero() || d >= *curve.n() {
+        return Err("[Ec] private key is out of range".into());
+    }
+    Ok(d)
+}
+
+fn parse_public_key(curve: &EcCurve, public_key: &[u8]) -> Result<EcPoint> {
+    let point = curve.decode_point(public_key)?;
+    if point.is_infinity() {
+        return Err("[Ec] public key is the point at infinity".into());
+    }
+    Ok(point)
+}
+
+// 取最左边 n 的比特长度个比特 (RFC 6979 bits2int)
+pub(crate) fn bits_to_int(data: &[u8], n: &BigUint) -> BigUint {
+    let value = BigUint::from_bytes(data, Endian::Big);
+    let (data_bits, n_bits) = (data.len() * 8, n.bits());
+    if data_bits > n_bits {
+        value >> (data_bits - n_bits)
+    } else {
+        value
+    }
+}
+
+// 消息杂凑值对应的整数 e
+pub(crate) fn message_scalar<H: Hashing>(curve: &EcCurve, message: &[u8]) -> Result<BigUint> {
+    let digest = H::default().run(message)?;
+    Ok(bits_to_int(&digest, curve.n()))
+}
+
+// RFC 6979 中基于 HMAC 的确定性随机数生成
+pub(crate) struct Rfc6979<H: Hashing> {
+    n: BigUint,
+    k: Vec<u8>,
+    v: Vec<u8>,
+    started: bool,
+    hash_function: std::marker::PhantomData<H>,
+}
+
+impl<H: Hashing> Rfc6979<H> {
+    pub(crate) fn new(n: &BigUint, d: &BigUint, message: &[u8]) -> Result<Self> {
+        let h_len = H::default().output_size();
+        let size = n.bits().div_ceil(8);
+        // int2octets(x) || bits2octets(h1)
+        let h1 = H::default().run(message)?;
+        let reduced = &bits_to_int(&h1, n) % n;
+        let seed = [
+            d.to_bytes_padded(size, Endian::Big)?.to_vec(),
+            reduced.to_bytes_padded(size, Endian::Big)?.to_vec(),
+        ]
+        .concat();
+
+        let mut k = vec![0x00; h_len];
+        let mut v = vec![0x01; h_len];
+        for separator in [0x00, 0x01] {
+            k = Self::hmac(&k, &[v.as_slice(), &[separator], &seed].concat())?;
+            v = Self::hmac(&k, &v)?;
+        }
+        Ok(Self {
+            n: n.clone(),
+            k,
+            v,
+            started: false,
+            hash_function: std::marker::PhantomData,
+        })
+    }
+
+    fn hmac(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        Ok(Hmac::<H>::new(key).run(data)?.to_vec())
+    }
+
+    pub(crate) fn next_nonce(&mut self) -> Result<BigUint> {
+        loop {
+            // 上一个候选值不可用时更新 K 和 V
+            if self.started {
+                self.k = Self::hmac(&self.k, &[self.v.as_slice(), &[0x00]].concat())?;
+                self.v = Self::hmac(&self.k, &self.v)?;
+            }
+            self.started = true;
+
+            let mut t = vec![];
+            while t.len() * 8 < self.n.bits() {
+                self.v = Self::hmac(&self.k, &self.v)?;
+                t.extend_from_slice(&self.v);
+            }
+            let k = bits_to_int(&t, &self.n);
+            if !k.is_zero() && k < self.n {
+                return Ok(k);
+            }
+        }
+    }
+}
@@ -0,0 +1,427 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::ec::point::{EcPoint, JacobianPoint};
+use crate::enums::Endian;
+use crate::types::Result;
+
+// 短 Weierstrass 曲线 y^2 = x^3 + a x + b (mod p), 基点 G 的阶为 n
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EcCurve {
+    p: BigUint,
+    a: BigUint,
+    b: BigUint,
+    generator: EcPoint,
+    n: BigUint,
+}
+
+fn hex(s: &str) -> BigUint {
+    BigUint::from_str_radix(s, 16).unwrap()
+}
+
+impl EcCurve {
+    // 自定义曲线, 检查参数的合法性
+    pub fn new(
+        p: BigUint,
+        a: BigUint,
+        b: BigUint,
+        gx: BigUint,
+        gy: BigUint,
+        n: BigUint,
+    ) -> Result<Self> {
+        if p < BigUint::from(5u64) || !p.is_probable_prime(20) {
+            return Err("[EcCurve] p must be an odd prime".into());
+        }
+        if a >= p || b >= p || gx >= p || gy >= p {
+            return Err("[EcCurve] parameters must be reduced modulo p".into());
+        }
+        let curve = Self {
+            p,
+            a,
+            b,
+            generator: EcPoint::new(gx, gy),
+            n,
+        };
+        // 4a^3 + 27b^2 != 0
+        let field = curve.field();
+        let discriminant = field.add(
+            &field.mul(
+                &BigUint::from(4u64),
+                &field.mul(&curve.a, &field.square(&curve.a)),
+            ),
+            &field.mul(&BigUint::from(27u64), &field.square(&curve.b)),
+        );
+        if discriminant.is_zero() {
+            return Err("[EcCurve] curve is singular".into());
+        }
+        if !curve.is_on_curve(&curve.generator) {
+            return Err("[EcCurve] generator is not on the curve".into());
+        }
+        if curve.n < BigUint::from(2u64) || !curve.mul(&curve.n, &curve.generator).is_infinity() {
+            return Err("[EcCurve] n is not the order of the generator".into());
+        }
+        Ok(curve)
+    }
+
+    fn from_hex(p: &str, a: &str, b: &str, gx: &str, gy: &str, n: &str) -> Self {
+        Self {
+            p: hex(p),
+            a: hex(a),
+            b: hex(b),
+            generator: EcPoint::new(hex(gx), hex(gy)),
+            n: hex(n),
+        }
+    }
+
+    // NIST P-256 (secp256r1 / prime256v1)
+    pub fn p256() -> Self {
+        Self::from_hex(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+            "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        )
+    }
+
+    // NIST P-384 (secp384r1)
+    pub fn p384() -> Self {
+        Self::from_hex(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffc",
+            "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+        )
+    }
+
+    // NIST P-521 (secp521r1), p = 2^521 - 1
+    pub fn p521() -> Self {
+        Self::from_hex(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc",
+            "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+        )
+    }
+
+    // SEC 2 secp256k1, a = 0
+    pub fn secp256k1() -> Self {
+        Self::from_hex(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            "0",
+            "7",
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        )
+    }
+
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn a(&self) -> &BigUint {
+        &self.a
+    }
+
+    pub fn b(&self) -> &BigUint {
+        &self.b
+    }
+
+    pub fn n(&self) -> &BigUint {
+        &self.n
+    }
+
+    pub fn generator(&self) -> &EcPoint {
+        &self.generator
+    }
+
+    // 坐标的字节长度
+    pub fn field_size(&self) -> usize {
+        self.p.bits().div_ceil(8)
+    }
+
+    // 标量的字节长度
+    pub fn scalar_size(&self) -> usize {
+        self.n.bits().div_ceil(8)
+    }
+
+    fn field(&self) -> Field<'_> {
+        Field { p: &self.p }
+    }
+
+    // y^2 = x^3 + a x + b 的右边
+    fn rhs(&self, x: &BigUint) -> BigUint {
+        let field = self.field();
+        let x3 = field.mul(&field.square(x), x);
+        field.add(&field.add(&x3, &field.mul(&self.a, x)), &self.b)
+    }
+
+    pub fn is_on_curve(&self, point: &EcPoint) -> bool {
+        match point {
+            EcPoint::Infinity => true,
+            EcPoint::Affine { x, y } => {
+                *x < self.p && *y < self.p && self.field().square(y) == self.rhs(x)
+            }
+        }
+    }
+
+    pub fn negate(&self, point: &EcPoint) -> EcPoint {
+        match point {
+            EcPoint::Infinity => EcPoint::Infinity,
+            EcPoint::Affine { x, y } => EcPoint::new(x.clone(), self.field().neg(y)),
+        }
+    }
+
+    pub fn add(&self, p1: &EcPoint, p2: &EcPoint) -> EcPoint {
+        self.to_affine(&self.jacobian_add(&p1.into(), &p2.into()))
+    }
+
+    pub fn double(&self, point: &EcPoint) -> EcPoint {
+        self.to_affine(&self.jacobian_double(&point.into()))
+    }
+
+    // 从高位到低位的倍点-点加
+    pub fn mul(&self, k: &BigUint, point: &EcPoint) -> EcPoint {
+        let base = JacobianPoint::from(point);
+        let mut result = JacobianPoint::infinity();
+        for i in (0..k.bits()).rev() {
+            result = self.jacobian_double(&result);
+            if k.bit(i) {
+                result = self.jacobian_add(&result, &base);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    pub fn mul_generator(&self, k: &BigUint) -> EcPoint {
+        self.mul(k, &self.generator)
+    }
+
+    fn to_affine(&self, point: &JacobianPoint) -> EcPoint {
+        if point.is_infinity() {
+            return EcPoint::Infinity;
+        }
+        let field = self.field();
+        let z_inv = point.z.mod_inverse(&self.p).unwrap();
+        let z_inv2 = field.square(&z_inv);
+        EcPoint::new(
+            field.mul(&point.x, &z_inv2),
+            field.mul(&point.y, &field.mul(&z_inv2, &z_inv)),
+        )
+    }
+
+    // 通用 a 的倍点公式
+    fn jacobian_double(&self, point: &JacobianPoint) -> JacobianPoint {
+        if point.is_infinity() || point.y.is_zero() {
+            return JacobianPoint::infinity();
+        }
+        let field = self.field();
+        let (x, y, z) = (&point.x, &point.y, &point.z);
+        let y2 = field.square(y);
+        // S = 4 X Y^2, M = 3 X^2 + a Z^4
+        let s = field.mul(&BigUint::from(4u64), &field.mul(x, &y2));
+        let z2 = field.square(z);
+        let m = field.add(
+            &field.mul(&BigUint::from(3u64), &field.square(x)),
+            &field.mul(&self.a, &field.square(&z2)),
+        );
+        // X' = M^2 - 2S, Y' = M (S - X') - 8 Y^4, Z' = 2 Y Z
+        let x3 = field.sub(&field.square(&m), &field.add(&s, &s));
+        let y4 = field.square(&y2);
+        let y3 = field.sub(
+            &field.mul(&m, &field.sub(&s, &x3)),
+            &field.mul(&BigUint::from(8u64), &y4),
+        );
+        let z3 = field.mul(&BigUint::from(2u64), &field.mul(y, z));
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    fn jacobian_add(&self, p1: &JacobianPoint, p2: &JacobianPoint) -> JacobianPoint {
+        if p1.is_infinity() {
+            return p2.clone();
+        }
+        if p2.is_infinity() {
+            return p1.clone();
+        }
+        let field = self.field();
+        let z1z1 = field.square(&p1.z);
+        let z2z2 = field.square(&p2.z);
+        // U1 = X1 Z2^2, U2 = X2 Z1^2, S1 = Y1 Z2^3, S2 = Y2 Z1^3
+        let u1 = field.mul(&p1.x, &z2z2);
+        let u2 = field.mul(&p2.x, &z1z1);
+        let s1 = field.mul(&p1.y, &field.mul(&p2.z, &z2z2));
+        let s2 = field.mul(&p2.y, &field.mul(&p1.z, &z1z1));
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.jacobian_double(p1)
+            } else {
+                JacobianPoint::infinity()
+            };
+        }
+        let h = field.sub(&u2, &u1);
+        let r = field.sub(&s2, &s1);
+        let h2 = field.square(&h);
+        let h3 = field.mul(&h2, &h);
+        let u1h2 = field.mul(&u1, &h2);
+        // X3 = R^2 - H^3 - 2 U1 H^2, Y3 = R (U1 H^2 - X3) - S1 H^3, Z3 = H Z1 Z2
+        let x3 = field.sub(&field.sub(&field.square(&r), &h3), &field.add(&u1h2, &u1h2));
+        let y3 = field.sub(&field.mul(&r, &field.sub(&u1h2, &x3)), &field.mul(&s1, &h3));
+        let z3 = field.mul(&h, &field.mul(&p1.z, &p2.z));
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // SEC1 编码: 04 || x || y 或 02/03 || x, 无穷远点为 00
+    pub fn encode_point(&self, point: &EcPoint, compressed: bool) -> Bytes {
+        let size = self.field_size();
+        let (x, y) = match point {
+            EcPoint::Infinity => return Bytes::new(vec![0x00]),
+            EcPoint::Affine { x, y } => (x, y),
+        };
+        let x = x.to_bytes_padded(size, Endian::Big).unwrap();
+        if compressed {
+            let prefix = if y.is_odd() { 0x03 } else { 0x02 };
+            return Bytes::new([&[prefix], x.as_ref()].concat());
+        }
+        let y = y.to_bytes_padded(size, Endian::Big).unwrap();
+        Bytes::new([&[0x04], x.as_ref(), y.as_ref()].concat())
+    }
+
+    // 支持 04 || x || y、x || y 以及 02/03 || x 压缩格式
+    pub fn decode_point(&self, encoded: &[u8]) -> Result<EcPoint> {
+        let size = self.field_size();
+        let coordinate = |bytes: &[u8]| BigUint::from_bytes(bytes, Endian::Big);
+        let point = match (encoded.len(), encoded.first()) {
+            (1, Some(0x00)) => EcPoint::Infinity,
+            (length, Some(0x04)) if length == 2 * size + 1 => EcPoint::new(
+                coordinate(&encoded[1..size + 1]),
+                coordinate(&encoded[size + 1..]),
+            ),
+            (length, _) if length == 2 * size => {
+                EcPoint::new(coordinate(&encoded[..size]), coordinate(&encoded[size..]))
+            }
+            (length, Some(&prefix)) if length == size + 1 && (prefix == 0x02 || prefix == 0x03) => {
+                let x = coordinate(&encoded[1..]);
+                if x >= self.p {
+                    return Err("[EcCurve] point is not on the curve".into());
+                }
+                let y = sqrt_mod(&self.rhs(&x), &self.p)
+                    .ok_or("[EcCurve] point is not on the curve")?;
+                let y = if y.is_odd() == (prefix == 0x03) {
+                    y
+                } else {
+                    self.field().neg(&y)
+                };
+                EcPoint::new(x, y)
+            }
+            _ => return Err("[EcCurve] invalid point encoding".into()),
+        };
+        if !self.is_on_curve(&point) {
+            return Err("[EcCurve] point is not on the curve".into());
+        }
+        Ok(point)
+    }
+
+    pub fn compress_point(&self, encoded: &[u8]) -> Result<Bytes> {
+        Ok(self.encode_point(&self.decode_point(encoded)?, true))
+    }
+
+    pub fn decompress_point(&self, encoded: &[u8]) -> Result<Bytes> {
+        Ok(self.encode_point(&self.decode_point(encoded)?, false))
+    }
+}
+
+// 模 p 的域运算, 输入均已约化
+struct Field<'a> {
+    p: &'a BigUint,
+}
+
+impl Field<'_> {
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let sum = a + b;
+        if sum >= *self.p {
+            &sum - self.p
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        if a >= b {
+            a - b
+        } else {
+            &(a + self.p) - b
+        }
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        &(a * b) % self.p
+    }
+
+    fn square(&self, a: &BigUint) -> BigUint {
+        self.mul(a, a)
+    }
+}
+
+// 模素数平方根, p = 3 mod 4 时直接求幂, 否则使用 Tonelli-Shanks
+fn sqrt_mod(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let a = a % p;
+    if a.is_zero() {
+        return Some(a);
+    }
+    let one = BigUint::one();
+    let p_minus_one = p - &one;
+    // 欧拉判别法
+    if a.mod_pow(&(&p_minus_one >> 1), p) != one {
+        return None;
+    }
+    if p.bit(1) {
+        return Some(a.mod_pow(&(&(p + &one) >> 2), p));
+    }
+
+    let s = p_minus_one.trailing_zeros().unwrap();
+    let q = &p_minus_one >> s;
+    // 找一个二次非剩余
+    let mut z = BigUint::from(2u64);
+    while z.mod_pow(&(&p_minus_one >> 1), p) != p_minus_one {
+        z = &z + &one;
+    }
+    let mut m = s;
+    let mut c = z.mod_pow(&q, p);
+    let mut t = a.mod_pow(&q, p);
+    let mut r = a.mod_pow(&(&(&q + &one) >> 1), p);
+    while !t.is_one() {
+        // 最小的 i 使 t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while !t2.is_one() {
+            t2 = &(&t2 * &t2) % p;
+            i += 1;
+        }
+        let mut b = c.clone();
+        for _ in 0..m - i - 1 {
+            b = &(&b * &b) % p;
+        }
+        r = &(&r * &b) % p;
+        c = &(&b * &b) % p;
+        t = &(&t * &c) % p;
+        m = i;
+    }
+    Some(r)
+}
@@ -0,0 +1,33 @@
+use crate::bytes::Bytes;
+use crate::ec::{parse_private_key, parse_public_key, EcCurve};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 输入对方公钥, 输出共享点的 x 坐标
+#[derive(Debug)]
+pub struct Ecdh {
+    pub curve: EcCurve,
+    pub private_key: Bytes,
+}
+
+impl Ecdh {
+    pub fn new(curve: &EcCurve, private_key: &[u8]) -> Self {
+        Self {
+            curve: curve.clone(),
+            private_key: Bytes::new(private_key),
+        }
+    }
+}
+
+impl Operation for Ecdh {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let d = parse_private_key(&self.curve, &self.private_key)?;
+        let peer_public_key = parse_public_key(&self.curve, input)?;
+        let shared = self.curve.mul(&d, &peer_public_key);
+        let x = shared
+            .x()
+            .ok_or("[Ecdh] shared secret is the point at infinity")?;
+        x.to_bytes_padded(self.curve.field_size(), Endian::Big)
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+
+use crate::bigint::{lll_reduce, BigInt, BigUint};
+use crate::bytes::Bytes;
+use crate::ec::{message_scalar, parse_public_key, EcCurve, EcPoint};
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EcdsaAttackMethod {
+    NonceReuse,
+    HiddenNumber,
+}
+
+impl fmt::Display for EcdsaAttackMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::NonceReuse => "nonce reuse",
+            Self::HiddenNumber => "hidden number problem (LLL)",
+        };
+        f.write_str(name)
+    }
+}
+
+// 恢复出的私钥, 随机数重用时一并给出 k
+#[derive(Debug)]
+pub struct EcdsaAttackReport {
+    pub method: EcdsaAttackMethod,
+    pub private_key: BigUint,
+    pub nonce: Option<BigUint>,
+}
+
+impl fmt::Display for EcdsaAttackReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "attack: {}", self.method)?;
+        writeln!(f, "d = {:#x}", self.private_key)?;
+        if let Some(k) = &self.nonce {
+            writeln!(f, "k = {:#x}", k)?;
+        }
+        Ok(())
+    }
+}
+
+// 一个签名及其消息杂凑值 z
+// known_nonce 为随机数中已知的部分, 即 k = known_nonce + u, 0 <= u < 2^nonce_bits
+#[derive(Clone, Debug)]
+pub struct EcdsaSample {
+    pub r: BigUint,
+    pub s: BigUint,
+    pub z: BigUint,
+    pub known_nonce: BigUint,
+}
+
+impl EcdsaSample {
+    pub fn new<H: Hashing>(curve: &EcCurve, message: &[u8], signature: &[u8]) -> Result<Self> {
+        let size = curve.scalar_size();
+        if signature.len() != 2 * size {
+            return Err(
+                format!("[EcdsaAttack] signature length must be {} bytes", 2 * size).into(),
+            );
+        }
+        Ok(Self {
+            r: BigUint::from_bytes(&signature[..size], Endian::Big),
+            s: BigUint::from_bytes(&signature[size..], Endian::Big),
+            z: message_scalar::<H>(curve, message)?,
+            known_nonce: BigUint::zero(),
+        })
+    }
+
+    // 大端序的已知随机数部分, 例如泄露的高位左移 nonce_bits 位
+    pub fn with_known_nonce<H: Hashing>(
+        curve: &EcCurve,
+        message: &[u8],
+        signature: &[u8],
+        known_nonce: &[u8],
+    ) -> Result<Self> {
+        Ok(Self {
+            known_nonce: BigUint::from_bytes(known_nonce, Endian::Big),
+            ..Self::new::<H>(curve, message, signature)?
+        })
+    }
+}
+
+// 依次尝试随机数重用和隐藏数问题攻击, 输入不使用, 输出攻击报告
+#[derive(Debug)]
+pub struct EcdsaAttack {
+    pub curve: EcCurve,
+    pub public_key: Bytes,
+    pub samples: Vec<EcdsaSample>,
+    // 随机数未知部分的比特长度, 为 None 时不做格攻击
+    pub nonce_bits: Option<usize>,
+}
+
+impl EcdsaAttack {
+    pub fn new(curve: &EcCurve, public_key: &[u8]) -> Self {
+        Self {
+            curve: curve.clone(),
+            public_key: Bytes::new(public_key),
+            samples: vec![],
+            nonce_bits: None,
+        }
+    }
+
+    pub fn attack(&self) -> Option<EcdsaAttackReport> {
+        nonce_reuse_attack(&self.curve, &self.public_key, &self.samples).or_else(|| {
+            let bits = self.nonce_bits?;
+            hidden_number_attack(&self.curve, &self.public_key, &self.samples, bits)
+        })
+    }
+}
+
+impl Operation for EcdsaAttack {
+    fn run(&self, _: &[u8]) -> Result<Bytes> {
+        let report = self.attack().ok_or("[EcdsaAttack] all attacks failed")?;
+        Ok(Bytes::new(report.to_string().as_bytes()))
+    }
+}
+
+fn matches_public_key(curve: &EcCurve, public_key: &EcPoint, d: &BigUint) -> bool {
+    !d.is_zero() && curve.mul_generator(d) == *public_key
+}
+
+// 两个签名的 r 相同即使用了同一个 k
+// k = (z1 - z2) / (s1 - s2), d = (s1 k - z1) / r
+// s 可能被规范化为 n - s, 因此同时尝试 s2 与 -s2
+pub fn nonce_reuse_attack(
+    curve: &EcCurve,
+    public_key: &[u8],
+    samples: &[EcdsaSample],
+) -> Option<EcdsaAttackReport> {
+    let public_key = parse_public_key(curve, public_key).ok()?;
+    let n = curve.n();
+    let sub = |a: &BigUint, b: &BigUint| &(&(&(a % n) + n) - &(b % n)) % n;
+
+    for (i, first) in samples.iter().enumerate() {
+        for second in samples[i + 1..].iter().filter(|other| other.r == first.r) {
+            let r_inv = match first.r.mod_inverse(n) {
+                Some(r_inv) => r_inv,
+                None => continue,
+            };
+            for s2 in [second.s.clone(), sub(n, &second.s)] {
+                let k = match sub(&first.s, &s2).mod_inverse(n) {
+                    Some(inv) => &(&sub(&first.z, &second.z) * &inv) % n,
+                    None => continue,
+                };
+                let d = &(&sub(&(&first.s * &k), &first.z) * &r_inv) % n;
+                if matches_public_key(curve, &public_key, &d) {
+                    return Some(EcdsaAttackReport {
+                        method: EcdsaAttackMethod::NonceReuse,
+                        private_key: d,
+                        nonce: Some(k),
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+// 随机数有偏差时归约为隐藏数问题, 用 Kannan 嵌入和 LLL 求解
+// k_i = a_i + u_i, 0 <= u_i < B = 2^nonce_bits, 令 w_i = u_i - B / 2
+// 则 w_i = t_i d + c_i (mod n), 其中 t_i = r_i / s_i, c_i = z_i / s_i - a_i - B / 2
+// 用第一个样本消去 d: w_i = A_i w_0 + C_i (mod n)
+pub fn hidden_number_attack(
+    curve: &EcCurve,
+    public_key: &[u8],
+    samples: &[EcdsaSample],
+    nonce_bits: usize,
+) -> Option<EcdsaAttackReport> {
+    let public_key = parse_public_key(curve, public_key).ok()?;
+    let n = curve.n();
+    if samples.len() < 2 || nonce_bits == 0 || nonce_bits >= n.bits() {
+        return None;
+    }
+    let half = BigUint::one() << (nonce_bits - 1);
+
+    let mut t = vec![];
+    let mut c = vec![];
+    for sample in samples {
+        let s_inv = sample.s.mod_inverse(n)?;
+        t.push(&(&sample.r * &s_inv) % n);
+        let offset = &(&sample.known_nonce + &half) % n;
+        c.push(&(&(&(&sample.z * &s_inv) % n) + &(n - &offset)) % n);
+    }
+    let t0_inv = t[0].mod_inverse(n)?;
+
+    // 前 m - 1 行为 n e_i, 随后为 (A_1, ..., A_{m-1}, 1, 0) 与 (C_1, ..., C_{m-1}, 0, B / 2)
+    let m = samples.len();
+    let mut basis = vec![vec![BigInt::zero(); m + 1]; m + 1];
+    for i in 1..m {
+        let a = &(&t[i] * &t0_inv) % n;
+        let ac0 = &(&a * &c[0]) % n;
+        basis[i - 1][i - 1] = BigInt::from(n.clone());
+        basis[m - 1][i - 1] = BigInt::from(a);
+        basis[m][i - 1] = BigInt::from(&(&c[i] + n) - &ac0);
+    }
+    basis[m - 1][m - 1] = BigInt::one();
+    basis[m][m] = BigInt::from(half.clone());
+
+    let embedding = BigInt::from(half);
+    for row in lll_reduce(&basis).ok()? {
+        // 短向量为 ±(w_1, ..., w_{m-1}, w_0, B / 2)
+        let w0 = if row[m] == embedding {
+            row[m - 1].clone()
+        } else if row[m] == -&embedding {
+            -&row[m - 1]
+        } else {
+            continue;
+        };
+        // d = (w_0 - c_0) / t_0
+        let w0 = w0.rem_euclid(n);
+        let d = &(&(&(&w0 + n) - &c[0]) * &t0_inv) % n;
+        if matches_public_key(curve, &public_key, &d) {
+            return Some(EcdsaAttackReport {
+                method: EcdsaAttackMethod::HiddenNumber,
+                private_key: d,
+                nonce: None,
+            });
+        }
+    }
+    None
+}
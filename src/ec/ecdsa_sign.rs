@@ -0,0 +1,107 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::ec::{message_scalar, parse_private_key, random_scalar, EcCurve, Rfc6979};
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// r 或 s 为 0 时重新选取 k 的次数上限, 防止 n 很小的曲线上无限循环
+const MAX_NONCE_ATTEMPTS: usize = 64;
+
+// 签名随机数 k 的来源
+#[derive(Debug, Default)]
+pub enum EcdsaNonce {
+    #[default]
+    Random,
+    // RFC 6979 确定性随机数
+    Deterministic,
+    // 指定 k 以复现签名
+    Fixed(Bytes),
+}
+
+// 输出 r || s, 各占 n 的字节长度
+#[derive(Debug)]
+pub struct EcdsaSign<H: Hashing> {
+    pub curve: EcCurve,
+    pub private_key: Bytes,
+    pub nonce: EcdsaNonce,
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> EcdsaSign<H> {
+    pub fn new(curve: &EcCurve, private_key: &[u8]) -> Self {
+        Self {
+            curve: curve.clone(),
+            private_key: Bytes::new(private_key),
+            nonce: EcdsaNonce::Random,
+            hash_function: PhantomData,
+        }
+    }
+
+    pub fn deterministic(curve: &EcCurve, private_key: &[u8]) -> Self {
+        Self {
+            nonce: EcdsaNonce::Deterministic,
+            ..Self::new(curve, private_key)
+        }
+    }
+
+    pub fn with_k(curve: &EcCurve, private_key: &[u8], k: &[u8]) -> Self {
+        Self {
+            nonce: EcdsaNonce::Fixed(Bytes::new(k)),
+            ..Self::new(curve, private_key)
+        }
+    }
+}
+
+impl<H: Hashing> Operation for EcdsaSign<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let curve = &self.curve;
+        let n = curve.n();
+        let d = parse_private_key(curve, &self.private_key)?;
+        let e = message_scalar::<H>(curve, input)?;
+        let mut deterministic = match self.nonce {
+            EcdsaNonce::Deterministic => Some(Rfc6979::<H>::new(n, &d, input)?),
+            _ => None,
+        };
+
+        for _ in 0..MAX_NONCE_ATTEMPTS {
+            let k = match (&self.nonce, &mut deterministic) {
+                (EcdsaNonce::Fixed(k), _) => {
+                    let k = BigUint::from_bytes(k, Endian::Big);
+                    if k.is_zero() || k >= *n {
+                        return Err("[EcdsaSign] k is out of range".into());
+                    }
+                    k
+                }
+                (_, Some(generator)) => generator.next_nonce()?,
+                _ => random_scalar(n)?,
+            };
+
+            // r = (k G).x mod n, s = k^-1 (e + r d) mod n
+            let r = match curve.mul_generator(&k).x() {
+                Some(x) => x % n,
+                None => BigUint::zero(),
+            };
+            let k_inv = k.mod_inverse(n).ok_or("[EcdsaSign] k is not invertible")?;
+            let s = &(&k_inv * &(&(&e + &(&r * &d)) % n)) % n;
+            if r.is_zero() || s.is_zero() {
+                if let EcdsaNonce::Fixed(_) = self.nonce {
+                    return Err("[EcdsaSign] k is not suitable for this message".into());
+                }
+                continue;
+            }
+
+            let size = curve.scalar_size();
+            return Ok(Bytes::new(
+                [
+                    r.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                    s.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                ]
+                .concat(),
+            ));
+        }
+        Err("[EcdsaSign] failed to find a valid nonce".into())
+    }
+}
@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::ec::{message_scalar, parse_public_key, EcCurve};
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct EcdsaVerify<H: Hashing> {
+    pub curve: EcCurve,
+    pub public_key: Bytes,
+    pub signature: Bytes, // r || s
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> EcdsaVerify<H> {
+    pub fn new(curve: &EcCurve, public_key: &[u8], signature: &[u8]) -> Self {
+        Self {
+            curve: curve.clone(),
+            public_key: Bytes::new(public_key),
+            signature: Bytes::new(signature),
+            hash_function: PhantomData,
+        }
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let curve = &self.curve;
+        let n = curve.n();
+        let public_key = parse_public_key(curve, &self.public_key)?;
+        let size = curve.scalar_size();
+        if self.signature.len() != 2 * size {
+            return Err(
+                format!("[EcdsaVerify] signature length must be {} bytes", 2 * size).into(),
+            );
+        }
+        let r = BigUint::from_bytes(&self.signature[..size], Endian::Big);
+        let s = BigUint::from_bytes(&self.signature[size..], Endian::Big);
+        if r.is_zero() || r >= *n || s.is_zero() || s >= *n {
+            return Ok(false);
+        }
+
+        // u1 = e w, u2 = r w, w = s^-1
+        let e = message_scalar::<H>(curve, message)?;
+        let w = match s.mod_inverse(n) {
+            Some(w) => w,
+            None => return Ok(false),
+        };
+        let u1 = &(&e * &w) % n;
+        let u2 = &(&r * &w) % n;
+        let point = curve.add(&curve.mul_generator(&u1), &curve.mul(&u2, &public_key));
+        Ok(point.x().is_some_and(|x| x % n == r))
+    }
+}
+
+impl<H: Hashing> Operation for EcdsaVerify<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[EcdsaVerify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
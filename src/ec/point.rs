@@ -0,0 +1,67 @@
+use crate::bigint::BigUint;
+
+// 仿射坐标点, 无穷远点单独表示
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EcPoint {
+    Infinity,
+    Affine { x: BigUint, y: BigUint },
+}
+
+impl EcPoint {
+    pub fn new(x: BigUint, y: BigUint) -> Self {
+        Self::Affine { x, y }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        matches!(self, Self::Infinity)
+    }
+
+    pub fn x(&self) -> Option<&BigUint> {
+        match self {
+            Self::Infinity => None,
+            Self::Affine { x, .. } => Some(x),
+        }
+    }
+
+    pub fn y(&self) -> Option<&BigUint> {
+        match self {
+            Self::Infinity => None,
+            Self::Affine { y, .. } => Some(y),
+        }
+    }
+}
+
+// Jacobian 坐标 (X, Y, Z) 表示 (X / Z^2, Y / Z^3), Z = 0 为无穷远点
+#[derive(Clone, Debug)]
+pub(crate) struct JacobianPoint {
+    pub(crate) x: BigUint,
+    pub(crate) y: BigUint,
+    pub(crate) z: BigUint,
+}
+
+impl JacobianPoint {
+    pub(crate) fn infinity() -> Self {
+        Self {
+            x: BigUint::one(),
+            y: BigUint::one(),
+            z: BigUint::zero(),
+        }
+    }
+
+    pub(crate) fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl From<&EcPoint> for JacobianPoint {
+    fn from(point: &EcPoint) -> Self {
+        match point {
+            EcPoint::Infinity => Self::infinity(),
+            EcPoint::Affine { x, y } => Self {
+                x: x.clone(),
+                y: y.clone(),
+                z: BigUint::one(),
+            },
+        }
+    }
+}
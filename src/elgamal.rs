@@ -0,0 +1,23 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::DhGroup;
+use crate::enums::Endian;
+use crate::operation::Hashing;
+use crate::types::Result;
+
+mod elgamal_decrypt;
+mod elgamal_encrypt;
+mod elgamal_sign;
+mod elgamal_verify;
+
+pub use elgamal_decrypt::ElGamalDecrypt;
+pub use elgamal_encrypt::ElGamalEncrypt;
+pub use elgamal_sign::ElGamalSign;
+pub use elgamal_verify::ElGamalVerify;
+
+// 杂凑值对 p - 1 取模
+fn message_scalar<H: Hashing>(group: &DhGroup, message: &[u8]) -> Result<BigUint> {
+    let digest: Bytes = H::default().run(message)?;
+    let p_minus_one = group.p() - &BigUint::one();
+    Ok(&BigUint::from_bytes(&digest, Endian::Big) % &p_minus_one)
+}
@@ -0,0 +1,50 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_private_key, DhGroup};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 输入 c1 || c2, 输出去掉前导 0 的明文 m = c2 / c1^x
+#[derive(Debug)]
+pub struct ElGamalDecrypt {
+    pub group: DhGroup,
+    pub private_key: Bytes,
+}
+
+impl ElGamalDecrypt {
+    pub fn new(group: &DhGroup, private_key: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            private_key: Bytes::new(private_key),
+        }
+    }
+}
+
+impl Operation for ElGamalDecrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let group = &self.group;
+        let p = group.p();
+        let x = parse_private_key(group, &self.private_key)?;
+        let size = group.size();
+        if input.len() != 2 * size {
+            return Err(format!(
+                "[ElGamalDecrypt] ciphertext length must be {} bytes",
+                2 * size
+            )
+            .into());
+        }
+        let c1 = BigUint::from_bytes(&input[..size], Endian::Big);
+        let c2 = BigUint::from_bytes(&input[size..], Endian::Big);
+        if c1.is_zero() || c1 >= *p || c2 >= *p {
+            return Err("[ElGamalDecrypt] ciphertext representative out of range".into());
+        }
+
+        let shared = c1.mod_pow(&x, p);
+        let shared_inv = shared
+            .mod_inverse(p)
+            .ok_or("[ElGamalDecrypt] c1 is not invertible")?;
+        let m = &(&c2 * &shared_inv) % p;
+        Ok(m.to_bytes(Endian::Big))
+    }
+}
@@ -0,0 +1,65 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_public_key, DhGroup};
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 教科书 ElGamal, 明文为小于 p 的正整数 (大端序)
+// 输出 c1 || c2, c1 = g^k, c2 = m y^k, 各占 p 的字节长度
+#[derive(Debug)]
+pub struct ElGamalEncrypt {
+    pub group: DhGroup,
+    pub public_key: Bytes,
+    pub k: Option<Bytes>, // 指定随机数 k 以复现密文
+}
+
+impl ElGamalEncrypt {
+    pub fn new(group: &DhGroup, public_key: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            public_key: Bytes::new(public_key),
+            k: None,
+        }
+    }
+
+    pub fn with_k(group: &DhGroup, public_key: &[u8], k: &[u8]) -> Self {
+        Self {
+            k: Some(Bytes::new(k)),
+            ..Self::new(group, public_key)
+        }
+    }
+}
+
+impl Operation for ElGamalEncrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let group = &self.group;
+        let p = group.p();
+        let y = parse_public_key(group, &self.public_key)?;
+        let m = BigUint::from_bytes(input, Endian::Big);
+        if m.is_zero() || m >= *p {
+            return Err("[ElGamalEncrypt] message representative out of range".into());
+        }
+        let order = group.order();
+        let k = match &self.k {
+            Some(k) => {
+                let k = BigUint::from_bytes(k, Endian::Big);
+                if k.is_zero() || k >= order {
+                    return Err("[ElGamalEncrypt] k is out of range".into());
+                }
+                k
+            }
+            None => &BigUint::random_below(&(&order - &BigUint::one()))? + &BigUint::one(),
+        };
+
+        let c1 = group.exp(&k);
+        let c2 = &(&m * &y.mod_pow(&k, p)) % p;
+        Ok(Bytes::new(
+            [
+                c1.to_bytes_padded(group.size(), Endian::Big)?.to_vec(),
+                c2.to_bytes_padded(group.size(), Endian::Big)?.to_vec(),
+            ]
+            .concat(),
+        ))
+    }
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_private_key, DhGroup};
+use crate::elgamal::message_scalar;
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// 随机选取 k 的次数上限, 防止弱参数下无限循环
+const MAX_NONCE_ATTEMPTS: usize = 64;
+
+// 输出 r || s, 各占 p 的字节长度
+// r = g^k mod p, s = (H(m) - x r) k^-1 mod (p - 1), k 与 p - 1 互素
+#[derive(Debug)]
+pub struct ElGamalSign<H: Hashing> {
+    pub group: DhGroup,
+    pub private_key: Bytes,
+    pub k: Option<Bytes>, // 指定随机数 k 以复现签名
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> ElGamalSign<H> {
+    pub fn new(group: &DhGroup, private_key: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            private_key: Bytes::new(private_key),
+            k: None,
+            hash_function: PhantomData,
+        }
+    }
+
+    pub fn with_k(group: &DhGroup, private_key: &[u8], k: &[u8]) -> Self {
+        Self {
+            k: Some(Bytes::new(k)),
+            ..Self::new(group, private_key)
+        }
+    }
+}
+
+impl<H: Hashing> Operation for ElGamalSign<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let group = &self.group;
+        let p_minus_one = group.p() - &BigUint::one();
+        let x = parse_private_key(group, &self.private_key)?;
+        let h = message_scalar::<H>(group, input)?;
+
+        for _ in 0..MAX_NONCE_ATTEMPTS {
+            let k = match &self.k {
+                Some(k) => BigUint::from_bytes(k, Endian::Big),
+                None => {
+                    &BigUint::random_below(&(&p_minus_one - &BigUint::one()))? + &BigUint::one()
+                }
+            };
+            let k_inv = match k.mod_inverse(&p_minus_one) {
+                Some(k_inv) if !k.is_zero() && k < p_minus_one => k_inv,
+                _ if self.k.is_some() => {
+                    return Err("[ElGamalSign] k must be coprime to p - 1".into());
+                }
+                _ => continue,
+            };
+
+            let r = group.exp(&k);
+            let xr = &(&x * &r) % &p_minus_one;
+            let s = &(&(&(&h + &p_minus_one) - &xr) * &k_inv) % &p_minus_one;
+            if s.is_zero() {
+                if self.k.is_some() {
+                    return Err("[ElGamalSign] k is not suitable for this message".into());
+                }
+                continue;
+            }
+
+            let size = group.size();
+            return Ok(Bytes::new(
+                [
+                    r.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                    s.to_bytes_padded(size, Endian::Big)?.to_vec(),
+                ]
+                .concat(),
+            ));
+        }
+        Err("[ElGamalSign] failed to find a valid nonce".into())
+    }
+}
@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::dh::{parse_public_key, DhGroup};
+use crate::elgamal::message_scalar;
+use crate::enums::Endian;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct ElGamalVerify<H: Hashing> {
+    pub group: DhGroup,
+    pub public_key: Bytes,
+    pub signature: Bytes, // r || s
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> ElGamalVerify<H> {
+    pub fn new(group: &DhGroup, public_key: &[u8], signature: &[u8]) -> Self {
+        Self {
+            group: group.clone(),
+            public_key: Bytes::new(public_key),
+            signature: Bytes::new(signature),
+            hash_function: PhantomData,
+        }
+    }
+
+    // g^H(m) = y^r r^s (mod p)
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let group = &self.group;
+        let p = group.p();
+        let y = parse_public_key(group, &self.public_key)?;
+        let size = group.size();
+        if self.signature.len() != 2 * size {
+            return Err(format!(
+                "[ElGamalVerify] signature length must be {} bytes",
+                2 * size
+            )
+            .into());
+        }
+        let r = BigUint::from_bytes(&self.signature[..size], Endian::Big);
+        let s = BigUint::from_bytes(&self.signature[size..], Endian::Big);
+        if r.is_zero() || r >= *p || s.is_zero() || s >= p - &BigUint::one() {
+            return Ok(false);
+        }
+
+        let h = message_scalar::<H>(group, message)?;
+        let right = &(&y.mod_pow(&r, p) * &r.mod_pow(&s, p)) % p;
+        Ok(group.exp(&h) == right)
+    }
+}
+
+impl<H: Hashing> Operation for ElGamalVerify<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[ElGamalVerify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
@@ -1,5 +1,6 @@
 #[derive(Clone, Copy, Debug)]
 pub enum BlockSize {
+    Bytes4,
     Bytes8,
     Bytes16,
     Bytes32,
@@ -10,6 +11,7 @@ pub enum BlockSize {
 impl From<BlockSize> for usize {
     fn from(value: BlockSize) -> Self {
         match value {
+            BlockSize::Bytes4 => 4,
             BlockSize::Bytes8 => 8,
             BlockSize::Bytes16 => 16,
             BlockSize::Bytes32 => 32,
@@ -0,0 +1,123 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod idea_decrypt;
+mod idea_encrypt;
+
+pub use idea_decrypt::IdeaDecrypt;
+pub use idea_encrypt::IdeaEncrypt;
+
+const ROUNDS: usize = 8;
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes8;
+// 每轮6个子密钥 最后的输出变换4个
+const KEY_COUNT: usize = 6 * ROUNDS + 4;
+
+// 模 2^16 + 1 乘法 0 表示 2^16
+fn mul(a: u16, b: u16) -> u16 {
+    let a = if a == 0 { 0x10000 } else { a as u64 };
+    let b = if b == 0 { 0x10000 } else { b as u64 };
+    (a * b % 0x10001) as u16
+}
+
+// 模 2^16 + 1 乘法逆元 由费马小定理 a^-1 = a^(2^16 - 1)
+fn mul_inv(a: u16) -> u16 {
+    let (mut result, mut base) = (1u16, a);
+    for _ in 0..16 {
+        result = mul(result, base);
+        base = mul(base, base);
+    }
+    result
+}
+
+#[derive(Debug)]
+pub struct Idea {
+    encrypt_keys: [u16; KEY_COUNT],
+    decrypt_keys: [u16; KEY_COUNT],
+}
+
+fn block_crypt(block: &[u8], keys: &[u16; KEY_COUNT]) -> Bytes {
+    let mut x: Vec<u16> = block
+        .chunks(2)
+        .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]))
+        .collect();
+    for k in keys.chunks(6).take(ROUNDS) {
+        x[0] = mul(x[0], k[0]);
+        x[1] = x[1].wrapping_add(k[1]);
+        x[2] = x[2].wrapping_add(k[2]);
+        x[3] = mul(x[3], k[3]);
+        // 乘加结构
+        let t0 = mul(x[0] ^ x[2], k[4]);
+        let t1 = mul((x[1] ^ x[3]).wrapping_add(t0), k[5]);
+        let t0 = t0.wrapping_add(t1);
+        x = vec![x[0] ^ t1, x[2] ^ t1, x[1] ^ t0, x[3] ^ t0];
+    }
+    // 输出变换 撤销最后一轮中间两个字的交换
+    let k = &keys[6 * ROUNDS..];
+    let y = [
+        mul(x[0], k[0]),
+        x[2].wrapping_add(k[1]),
+        x[1].wrapping_add(k[2]),
+        mul(x[3], k[3]),
+    ];
+    Bytes::new(
+        y.iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect::<Vec<u8>>(),
+    )
+}
+
+impl BlockCipher for Idea {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(block, &self.encrypt_keys)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(block, &self.decrypt_keys)
+    }
+
+    // 密钥长度为 16 字节 不足补0 超出截断
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        key.resize(16, 0);
+        let mut k = u128::from_be_bytes(key[..16].try_into().unwrap());
+
+        // 每次取8个子密钥后 密钥循环左移25位
+        let mut encrypt_keys = [0u16; KEY_COUNT];
+        for chunk in encrypt_keys.chunks_mut(8) {
+            for (i, sub_key) in chunk.iter_mut().enumerate() {
+                *sub_key = (k >> (112 - 16 * i)) as u16;
+            }
+            k = k.rotate_left(25);
+        }
+
+        // 解密子密钥 乘法与加法子密钥取逆 并逆序使用
+        // 除首尾两轮外 加法子密钥需要交换
+        let mut decrypt_keys = [0u16; KEY_COUNT];
+        for i in 0..=ROUNDS {
+            let j = ROUNDS - i;
+            let (a, b) = if i == 0 || i == ROUNDS {
+                (1, 2)
+            } else {
+                (2, 1)
+            };
+            decrypt_keys[6 * i] = mul_inv(encrypt_keys[6 * j]);
+            decrypt_keys[6 * i + 1] = encrypt_keys[6 * j + a].wrapping_neg();
+            decrypt_keys[6 * i + 2] = encrypt_keys[6 * j + b].wrapping_neg();
+            decrypt_keys[6 * i + 3] = mul_inv(encrypt_keys[6 * j + 3]);
+            if i < ROUNDS {
+                decrypt_keys[6 * i + 4] = encrypt_keys[6 * (j - 1) + 4];
+                decrypt_keys[6 * i + 5] = encrypt_keys[6 * (j - 1) + 5];
+            }
+        }
+
+        Self {
+            encrypt_keys,
+            decrypt_keys,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::bytes::Bytes;
+use crate::idea::{Idea, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct IdeaDecrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> IdeaDecrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for IdeaDecrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = self.mode.cipher_decrypt::<Idea>(input, &self.key);
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::bytes::Bytes;
+use crate::idea::{Idea, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct IdeaEncrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+}
+
+impl<M: Mode, P: Padding> IdeaEncrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for IdeaEncrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let padded_data = self.padding.pad(input);
+
+        Ok(self.mode.cipher_encrypt::<Idea>(&padded_data, &self.key))
+    }
+}
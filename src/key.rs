@@ -0,0 +1,221 @@
+use crate::asn1::{Asn1, Oid, Tag};
+use crate::bytes::Bytes;
+use crate::curve25519::Ed25519KeyPair;
+use crate::ec::{EcCurve, EcKeyPair};
+use crate::pem::Pem;
+use crate::rsa::{RsaPrivateKey, RsaPublicKey};
+use crate::sm2::Sm2KeyPair;
+use crate::types::Result;
+
+mod pbes2;
+mod pkcs1;
+mod pkcs8;
+mod sec1;
+mod spki;
+
+pub use pbes2::Pbes2Cipher;
+
+// 与 OpenSSL 默认值相同的 PBKDF2 迭代次数
+pub const DEFAULT_ITERATIONS: usize = 2048;
+
+const RSA_ENCRYPTION: &str = "1.2.840.113549.1.1.1";
+const RSASSA_PSS: &str = "1.2.840.113549.1.1.10";
+const EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
+const SM2: &str = "1.2.156.10197.1.301";
+const ED25519: &str = "1.3.101.112";
+
+// 密钥的编码格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyFormat {
+    // RSAPrivateKey / RSAPublicKey (RFC 8017)
+    Pkcs1,
+    // ECPrivateKey (RFC 5915), 含曲线参数
+    Sec1,
+    // PrivateKeyInfo (RFC 5208)
+    Pkcs8,
+    // SubjectPublicKeyInfo (RFC 5280)
+    Spki,
+}
+
+#[derive(Debug)]
+pub enum PrivateKey {
+    Rsa(RsaPrivateKey),
+    Ec(EcCurve, EcKeyPair),
+    Sm2(Sm2KeyPair),
+    Ed25519(Ed25519KeyPair),
+}
+
+impl PrivateKey {
+    // 自动识别 PKCS#1、SEC1 与未加密的 PKCS#8
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let asn1 = Asn1::from_ber(der)?;
+        let (first, second) = (asn1.child(0)?, asn1.child(1)?);
+        if first.tag == Tag::SEQUENCE {
+            return Err("[Key] private key is encrypted, a password is required".into());
+        }
+        match second.tag {
+            Tag::SEQUENCE => pkcs8::decode(&asn1),
+            Tag::INTEGER => pkcs1::decode_private_key(&asn1).map(Self::Rsa),
+            Tag::OCTET_STRING => sec1::decode_private_key(&asn1, None),
+            _ => Err("[Key] unrecognized private key format".into()),
+        }
+    }
+
+    // PBES2 加密的 PKCS#8 (EncryptedPrivateKeyInfo)
+    pub fn from_encrypted_der(der: &[u8], password: &[u8]) -> Result<Self> {
+        let plaintext = pbes2::decrypt(&Asn1::from_ber(der)?, password)?;
+        Asn1::from_ber(&plaintext)
+            .and_then(|asn1| pkcs8::decode(&asn1))
+            .map_err(|_| "[Key] incorrect password or corrupted private key".into())
+    }
+
+    // 取文本中第一个私钥块, 忽略 EC PARAMETERS 等其他块
+    pub fn from_pem(text: &str) -> Result<Self> {
+        for pem in Pem::parse_all(text)? {
+            match pem.label.as_str() {
+                "PRIVATE KEY" | "RSA PRIVATE KEY" | "EC PRIVATE KEY" => {
+                    return Self::from_der(&pem.contents)
+                }
+                "ENCRYPTED PRIVATE KEY" => {
+                    return Err("[Key] private key is encrypted, a password is required".into())
+                }
+                _ => {}
+            }
+        }
+        Err("[Key] no private key found in PEM".into())
+    }
+
+    // 未加密的私钥块同样可以读取
+    pub fn from_encrypted_pem(text: &str, password: &[u8]) -> Result<Self> {
+        match Pem::parse_all(text)?
+            .into_iter()
+            .find(|pem| pem.label == "ENCRYPTED PRIVATE KEY")
+        {
+            Some(pem) => Self::from_encrypted_der(&pem.contents, password),
+            None => Self::from_pem(text),
+        }
+    }
+
+    // PKCS#1 仅限 RSA, SEC1 仅限 EC 与 SM2
+    pub fn to_der(&self, format: KeyFormat) -> Result<Bytes> {
+        let asn1 = match (self, format) {
+            (Self::Rsa(key), KeyFormat::Pkcs1) => pkcs1::encode_private_key(key),
+            (Self::Ec(..) | Self::Sm2(_), KeyFormat::Sec1) => sec1::encode_private_key(self, true)?,
+            (_, KeyFormat::Pkcs8) => pkcs8::encode(self)?,
+            _ => {
+                return Err(format!("[Key] {:?} does not support this private key", format).into())
+            }
+        };
+        Ok(asn1.to_der())
+    }
+
+    pub fn to_pem(&self, format: KeyFormat) -> Result<String> {
+        let label = match format {
+            KeyFormat::Pkcs1 => "RSA PRIVATE KEY",
+            KeyFormat::Sec1 => "EC PRIVATE KEY",
+            _ => "PRIVATE KEY",
+        };
+        Pem::new(label, &self.to_der(format)?).encode()
+    }
+
+    // PBES2 加密的 PKCS#8, PRF 为 HMAC-SHA256
+    pub fn to_encrypted_der(
+        &self,
+        password: &[u8],
+        cipher: Pbes2Cipher,
+        iterations: usize,
+    ) -> Result<Bytes> {
+        let plaintext = self.to_der(KeyFormat::Pkcs8)?;
+        Ok(pbes2::encrypt(&plaintext, password, cipher, iterations)?.to_der())
+    }
+
+    pub fn to_encrypted_pem(
+        &self,
+        password: &[u8],
+        cipher: Pbes2Cipher,
+        iterations: usize,
+    ) -> Result<String> {
+        let der = self.to_encrypted_der(password, cipher, iterations)?;
+        Pem::new("ENCRYPTED PRIVATE KEY", &der).encode()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Rsa(key) => PublicKey::Rsa(key.public_key().clone()),
+            Self::Ec(curve, pair) => PublicKey::Ec(curve.clone(), pair.public_key.clone()),
+            Self::Sm2(pair) => PublicKey::Sm2(pair.public_key.clone()),
+            Self::Ed25519(pair) => PublicKey::Ed25519(pair.public_key.clone()),
+        }
+    }
+}
+
+// EC / SM2 公钥为 SEC1 编码的点, Ed25519 公钥为 32 字节
+#[derive(Clone, Debug)]
+pub enum PublicKey {
+    Rsa(RsaPublicKey),
+    Ec(EcCurve, Bytes),
+    Sm2(Bytes),
+    Ed25519(Bytes),
+}
+
+impl PublicKey {
+    // 自动识别 SubjectPublicKeyInfo 与 PKCS#1 RSAPublicKey
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let asn1 = Asn1::from_ber(der)?;
+        match asn1.child(0)?.tag {
+            Tag::SEQUENCE => spki::decode(&asn1),
+            Tag::INTEGER => pkcs1::decode_public_key(&asn1).map(Self::Rsa),
+            _ => Err("[Key] unrecognized public key format".into()),
+        }
+    }
+
+    pub fn from_pem(text: &str) -> Result<Self> {
+        Pem::parse_all(text)?
+            .into_iter()
+            .find(|pem| pem.label == "PUBLIC KEY" || pem.label == "RSA PUBLIC KEY")
+            .ok_or("[Key] no public key found in PEM")
+            .map(|pem| Self::from_der(&pem.contents))?
+    }
+
+    // PKCS#1 仅限 RSA
+    pub fn to_der(&self, format: KeyFormat) -> Result<Bytes> {
+        let asn1 = match (self, format) {
+            (Self::Rsa(key), KeyFormat::Pkcs1) => pkcs1::encode_public_key(key),
+            (_, KeyFormat::Spki) => spki::encode(self),
+            _ => return Err(format!("[Key] {:?} does not support this public key", format).into()),
+        };
+        Ok(asn1.to_der())
+    }
+
+    pub fn to_pem(&self, format: KeyFormat) -> Result<String> {
+        let label = match format {
+            KeyFormat::Pkcs1 => "RSA PUBLIC KEY",
+            _ => "PUBLIC KEY",
+        };
+        Pem::new(label, &self.to_der(format)?).encode()
+    }
+}
+
+fn oid(dotted: &str) -> Oid {
+    dotted.parse().unwrap()
+}
+
+// AlgorithmIdentifier ::= SEQUENCE { algorithm OBJECT IDENTIFIER, parameters ANY OPTIONAL }
+pub(crate) fn algorithm_identifier(algorithm: &str, parameters: Option<Asn1>) -> Asn1 {
+    let mut children = vec![Asn1::oid(&oid(algorithm))];
+    children.extend(parameters);
+    Asn1::sequence(children)
+}
+
+pub(crate) fn parse_algorithm(asn1: &Asn1) -> Result<(Oid, Option<&Asn1>)> {
+    let algorithm = asn1.expect(Tag::SEQUENCE)?.child(0)?.as_oid()?;
+    Ok((algorithm, asn1.children.get(1)))
+}
+
+// OID 的名称, 未知时为点分形式
+pub(crate) fn oid_name(algorithm: &Oid) -> String {
+    match algorithm.name() {
+        Some(name) => name.to_string(),
+        None => algorithm.to_string(),
+    }
+}
@@ -0,0 +1,197 @@
+use crate::aes::{AesDecrypt, AesEncrypt};
+use crate::asn1::{Asn1, Tag};
+use crate::bigint::BigUint;
+use crate::des::{TripleDesDecrypt, TripleDesEncrypt};
+use crate::key::{algorithm_identifier, oid_name, parse_algorithm};
+use crate::mode::Cbc;
+use crate::operation::Operation;
+use crate::padding::{NoPadding, Pkcs7Padding};
+use crate::pbkdf2::Pbkdf2;
+use crate::random::random_bytes;
+use crate::sha1::Sha1;
+use crate::sha2::{Sha256, Sha512};
+use crate::types::Result;
+
+const PBES2: &str = "1.2.840.113549.1.5.13";
+const PBKDF2: &str = "1.2.840.113549.1.5.12";
+const HMAC_WITH_SHA1: &str = "1.2.840.113549.2.7";
+const HMAC_WITH_SHA256: &str = "1.2.840.113549.2.9";
+const HMAC_WITH_SHA512: &str = "1.2.840.113549.2.11";
+const SALT_SIZE: usize = 16;
+
+// PBES2 的加密算法
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pbes2Cipher {
+    Aes128Cbc,
+    Aes192Cbc,
+    #[default]
+    Aes256Cbc,
+    TripleDesCbc,
+}
+
+impl Pbes2Cipher {
+    const ALL: [Self; 4] = [
+        Self::Aes128Cbc,
+        Self::Aes192Cbc,
+        Self::Aes256Cbc,
+        Self::TripleDesCbc,
+    ];
+
+    fn oid(&self) -> &'static str {
+        match self {
+            Self::Aes128Cbc => "2.16.840.1.101.3.4.1.2",
+            Self::Aes192Cbc => "2.16.840.1.101.3.4.1.22",
+            Self::Aes256Cbc => "2.16.840.1.101.3.4.1.42",
+            Self::TripleDesCbc => "1.2.840.113549.3.7",
+        }
+    }
+
+    fn key_size(&self) -> usize {
+        match self {
+            Self::Aes128Cbc => 16,
+            Self::Aes192Cbc | Self::TripleDesCbc => 24,
+            Self::Aes256Cbc => 32,
+        }
+    }
+
+    fn block_size(&self) -> usize {
+        match self {
+            Self::TripleDesCbc => 8,
+            _ => 16,
+        }
+    }
+
+    fn decrypt(&self, key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        if ciphertext.is_empty() || !ciphertext.len().is_multiple_of(self.block_size()) {
+            return Err("[Key] encrypted data length is not a multiple of the block size".into());
+        }
+        let plaintext = match self {
+            Self::TripleDesCbc => {
+                TripleDesDecrypt::<_, NoPadding>::new(key, Cbc::new(iv)).run(ciphertext)?
+            }
+            _ => AesDecrypt::<_, NoPadding>::new(key, Cbc::new(iv)).run(ciphertext)?,
+        };
+
+        // 完整校验 PKCS#7 填充, 口令错误时通常不合法
+        let pad_len = plaintext[plaintext.len() - 1] as usize;
+        if pad_len == 0
+            || pad_len > self.block_size()
+            || plaintext[plaintext.len() - pad_len..]
+                .iter()
+                .any(|&byte| byte as usize != pad_len)
+        {
+            return Err("[Pbes2] invalid padding (incorrect password?)".into());
+        }
+        Ok(plaintext[..plaintext.len() - pad_len].to_vec())
+    }
+
+    fn encrypt(&self, key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let ciphertext = match self {
+            Self::TripleDesCbc => {
+                TripleDesEncrypt::<_, Pkcs7Padding>::new(key, Cbc::new(iv)).run(plaintext)?
+            }
+            _ => AesEncrypt::<_, Pkcs7Padding>::new(key, Cbc::new(iv)).run(plaintext)?,
+        };
+        Ok(ciphertext.to_vec())
+    }
+}
+
+// EncryptedPrivateKeyInfo ::= SEQUENCE { encryptionAlgorithm, encryptedData OCTET STRING }
+// PBES2-params ::= SEQUENCE { keyDerivationFunc, encryptionScheme }
+pub(super) fn decrypt(asn1: &Asn1, password: &[u8]) -> Result<Vec<u8>> {
+    let (algorithm, parameters) = parse_algorithm(asn1.expect(Tag::SEQUENCE)?.child(0)?)?;
+    if algorithm.to_string() != PBES2 {
+        return Err(format!(
+            "[Key] unsupported encryption algorithm {}",
+            oid_name(&algorithm)
+        )
+        .into());
+    }
+    let ciphertext = asn1
+        .child(1)?
+        .expect(Tag::OCTET_STRING)?
+        .as_octet_string()?;
+    let parameters = parameters
+        .ok_or("[Key] missing PBES2 parameters")?
+        .expect(Tag::SEQUENCE)?;
+
+    let (scheme, iv) = parse_algorithm(parameters.child(1)?)?;
+    let cipher = Pbes2Cipher::ALL
+        .into_iter()
+        .find(|cipher| cipher.oid() == scheme.to_string())
+        .ok_or_else(|| format!("[Key] unsupported cipher {}", oid_name(&scheme)))?;
+    let iv = iv
+        .ok_or("[Key] missing initialization vector")?
+        .as_octet_string()?;
+    if iv.len() != cipher.block_size() {
+        return Err("[Key] invalid initialization vector length".into());
+    }
+
+    let key = derive_key(parameters.child(0)?, password, cipher.key_size())?;
+    cipher.decrypt(&key, &iv, &ciphertext)
+}
+
+// PBKDF2-params ::= SEQUENCE { salt OCTET STRING, iterationCount, keyLength OPTIONAL, prf DEFAULT hmacWithSHA1 }
+fn derive_key(kdf: &Asn1, password: &[u8], key_size: usize) -> Result<Vec<u8>> {
+    let (algorithm, parameters) = parse_algorithm(kdf)?;
+    if algorithm.to_string() != PBKDF2 {
+        return Err(format!(
+            "[Key] unsupported key derivation function {}",
+            oid_name(&algorithm)
+        )
+        .into());
+    }
+    let parameters = parameters
+        .ok_or("[Key] missing PBKDF2 parameters")?
+        .expect(Tag::SEQUENCE)?;
+    let salt = parameters
+        .child(0)?
+        .expect(Tag::OCTET_STRING)?
+        .as_octet_string()?;
+    let iterations = parameters.child(1)?.as_u64()? as usize;
+
+    let mut prf = HMAC_WITH_SHA1.to_string();
+    for child in &parameters.children[2..] {
+        if child.tag == Tag::INTEGER && child.as_u64()? as usize != key_size {
+            return Err("[Key] PBKDF2 key length does not match the cipher".into());
+        }
+        if child.tag == Tag::SEQUENCE {
+            prf = parse_algorithm(child)?.0.to_string();
+        }
+    }
+
+    let key = match prf.as_str() {
+        HMAC_WITH_SHA1 => Pbkdf2::<Sha1>::new(&salt, iterations, key_size).run(password)?,
+        HMAC_WITH_SHA256 => Pbkdf2::<Sha256>::new(&salt, iterations, key_size).run(password)?,
+        HMAC_WITH_SHA512 => Pbkdf2::<Sha512>::new(&salt, iterations, key_size).run(password)?,
+        _ => return Err(format!("[Key] unsupported PBKDF2 PRF {}", prf).into()),
+    };
+    Ok(key.to_vec())
+}
+
+// 随机盐与 IV, PRF 为 hmacWithSHA256
+pub(super) fn encrypt(
+    plaintext: &[u8],
+    password: &[u8],
+    cipher: Pbes2Cipher,
+    iterations: usize,
+) -> Result<Asn1> {
+    let salt = random_bytes(SALT_SIZE)?;
+    let iv = random_bytes(cipher.block_size())?;
+    let key = Pbkdf2::<Sha256>::new(&salt, iterations, cipher.key_size()).run(password)?;
+    let ciphertext = cipher.encrypt(&key, &iv, plaintext)?;
+
+    let kdf = algorithm_identifier(
+        PBKDF2,
+        Some(Asn1::sequence(vec![
+            Asn1::octet_string(&salt),
+            Asn1::uint(&BigUint::from(iterations as u64)),
+            algorithm_identifier(HMAC_WITH_SHA256, Some(Asn1::null())),
+        ])),
+    );
+    let scheme = algorithm_identifier(cipher.oid(), Some(Asn1::octet_string(&iv)));
+    Ok(Asn1::sequence(vec![
+        algorithm_identifier(PBES2, Some(Asn1::sequence(vec![kdf, scheme]))),
+        Asn1::octet_string(&ciphertext),
+    ]))
+}
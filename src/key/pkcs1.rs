@@ -0,0 +1,47 @@
+use crate::asn1::{Asn1, Tag};
+use crate::bigint::BigUint;
+use crate::rsa::{RsaPrivateKey, RsaPublicKey};
+use crate::types::Result;
+
+// RSAPrivateKey ::= SEQUENCE { version, n, e, d, p, q, dp, dq, qinv }
+// CRT 参数由 d、p、q 重新计算
+pub(super) fn decode_private_key(asn1: &Asn1) -> Result<RsaPrivateKey> {
+    let integer = |i| asn1.child(i)?.expect(Tag::INTEGER)?.as_biguint();
+    match asn1.expect(Tag::SEQUENCE)?.child(0)?.as_u64()? {
+        0 => {}
+        1 => return Err("[Key] multi-prime RSA keys are not supported".into()),
+        _ => return Err("[Key] invalid RSAPrivateKey version".into()),
+    }
+    let public_key = RsaPublicKey::new(integer(1)?, integer(2)?)?;
+    let (d, p, q) = (integer(3)?, integer(4)?, integer(5)?);
+    if &p * &q != *public_key.n() {
+        return Err("[Key] RSA primes do not match the modulus".into());
+    }
+    RsaPrivateKey::from_parts(public_key, d, p, q)
+}
+
+pub(super) fn encode_private_key(key: &RsaPrivateKey) -> Asn1 {
+    let values = [
+        &BigUint::zero(),
+        key.n(),
+        key.e(),
+        key.d(),
+        key.p(),
+        key.q(),
+        key.dp(),
+        key.dq(),
+        key.qinv(),
+    ];
+    Asn1::sequence(values.into_iter().map(Asn1::uint).collect())
+}
+
+// RSAPublicKey ::= SEQUENCE { n, e }
+pub(super) fn decode_public_key(asn1: &Asn1) -> Result<RsaPublicKey> {
+    let integer = |i| asn1.child(i)?.expect(Tag::INTEGER)?.as_biguint();
+    asn1.expect(Tag::SEQUENCE)?;
+    RsaPublicKey::new(integer(0)?, integer(1)?)
+}
+
+pub(super) fn encode_public_key(key: &RsaPublicKey) -> Asn1 {
+    Asn1::sequence(vec![Asn1::uint(key.n()), Asn1::uint(key.e())])
+}
@@ -0,0 +1,72 @@
+use crate::asn1::{Asn1, Tag};
+use crate::bigint::BigUint;
+use crate::curve25519::Ed25519KeyPair;
+use crate::key::sec1::{self, CurveParameters};
+use crate::key::{
+    algorithm_identifier, oid, oid_name, parse_algorithm, pkcs1, PrivateKey, EC_PUBLIC_KEY,
+    ED25519, RSASSA_PSS, RSA_ENCRYPTION, SM2,
+};
+use crate::types::Result;
+
+// PrivateKeyInfo ::= SEQUENCE { version, privateKeyAlgorithm, privateKey OCTET STRING, ... }
+// 同时接受 RFC 5958 的 version 1 (可附带公钥)
+pub(super) fn decode(asn1: &Asn1) -> Result<PrivateKey> {
+    if asn1.expect(Tag::SEQUENCE)?.child(0)?.as_u64()? > 1 {
+        return Err("[Key] invalid PrivateKeyInfo version".into());
+    }
+    let (algorithm, parameters) = parse_algorithm(asn1.child(1)?)?;
+    let private_key = asn1
+        .child(2)?
+        .expect(Tag::OCTET_STRING)?
+        .as_octet_string()?;
+
+    match algorithm.to_string().as_str() {
+        RSA_ENCRYPTION | RSASSA_PSS => {
+            pkcs1::decode_private_key(&Asn1::from_ber(&private_key)?).map(PrivateKey::Rsa)
+        }
+        EC_PUBLIC_KEY => {
+            let parameters = parameters.ok_or("[Key] missing EC curve parameters")?;
+            let parameters = sec1::decode_parameters(parameters)?;
+            sec1::decode_private_key(&Asn1::from_ber(&private_key)?, Some(parameters))
+        }
+        SM2 => sec1::decode_private_key(&Asn1::from_ber(&private_key)?, Some(CurveParameters::Sm2)),
+        // CurvePrivateKey ::= OCTET STRING
+        ED25519 => {
+            let seed = Asn1::from_ber(&private_key)?
+                .expect(Tag::OCTET_STRING)?
+                .as_octet_string()?;
+            if seed.len() != 32 {
+                return Err("[Key] Ed25519 private key must be 32 bytes".into());
+            }
+            Ed25519KeyPair::from_private_key(&seed).map(PrivateKey::Ed25519)
+        }
+        _ => Err(format!("[Key] unsupported key algorithm {}", oid_name(&algorithm)).into()),
+    }
+}
+
+// 与 OpenSSL 一致: version 0, EC 私钥不重复曲线参数
+pub(super) fn encode(key: &PrivateKey) -> Result<Asn1> {
+    let (algorithm, private_key) = match key {
+        PrivateKey::Rsa(key) => (
+            algorithm_identifier(RSA_ENCRYPTION, Some(Asn1::null())),
+            pkcs1::encode_private_key(key),
+        ),
+        PrivateKey::Ec(curve, _) => (
+            algorithm_identifier(EC_PUBLIC_KEY, Some(sec1::encode_parameters(curve))),
+            sec1::encode_private_key(key, false)?,
+        ),
+        PrivateKey::Sm2(_) => (
+            algorithm_identifier(EC_PUBLIC_KEY, Some(Asn1::oid(&oid(SM2)))),
+            sec1::encode_private_key(key, false)?,
+        ),
+        PrivateKey::Ed25519(pair) => (
+            algorithm_identifier(ED25519, None),
+            Asn1::octet_string(&pair.private_key),
+        ),
+    };
+    Ok(Asn1::sequence(vec![
+        Asn1::uint(&BigUint::zero()),
+        algorithm,
+        Asn1::octet_string(&private_key.to_der()),
+    ]))
+}
@@ -0,0 +1,160 @@
+use crate::asn1::{Asn1, Tag};
+use crate::bigint::BigUint;
+use crate::ec::{EcCurve, EcKeyPair};
+use crate::enums::Endian;
+use crate::key::{oid, oid_name, PrivateKey, SM2};
+use crate::sm2::Sm2KeyPair;
+use crate::types::Result;
+
+const PRIME256V1: &str = "1.2.840.10045.3.1.7";
+const SECP384R1: &str = "1.3.132.0.34";
+const SECP521R1: &str = "1.3.132.0.35";
+const SECP256K1: &str = "1.3.132.0.10";
+const PRIME_FIELD: &str = "1.2.840.10045.1.1";
+
+// ECParameters 对应的曲线, SM2 使用专门的实现
+pub(super) enum CurveParameters {
+    Ec(EcCurve),
+    Sm2,
+}
+
+fn named_curves() -> [(&'static str, EcCurve); 4] {
+    [
+        (PRIME256V1, EcCurve::p256()),
+        (SECP384R1, EcCurve::p384()),
+        (SECP521R1, EcCurve::p521()),
+        (SECP256K1, EcCurve::secp256k1()),
+    ]
+}
+
+// 命名曲线 OID 或素域上的显式参数
+pub(super) fn decode_parameters(asn1: &Asn1) -> Result<CurveParameters> {
+    if asn1.tag == Tag::SEQUENCE {
+        return decode_explicit_parameters(asn1).map(CurveParameters::Ec);
+    }
+    let curve_oid = asn1.expect(Tag::OBJECT_IDENTIFIER)?.as_oid()?;
+    let dotted = curve_oid.to_string();
+    if dotted == SM2 {
+        return Ok(CurveParameters::Sm2);
+    }
+    named_curves()
+        .into_iter()
+        .find(|(name, _)| *name == dotted)
+        .map(|(_, curve)| CurveParameters::Ec(curve))
+        .ok_or_else(|| format!("[Key] unsupported curve {}", oid_name(&curve_oid)).into())
+}
+
+// SpecifiedECDomain ::= SEQUENCE { version, fieldID, curve, base, order, cofactor OPTIONAL }
+fn decode_explicit_parameters(asn1: &Asn1) -> Result<EcCurve> {
+    let version = asn1.child(0)?.as_u64()?;
+    if !(1..=3).contains(&version) {
+        return Err("[Key] invalid ECParameters version".into());
+    }
+    let field = asn1.child(1)?.expect(Tag::SEQUENCE)?;
+    if field.child(0)?.as_oid()?.to_string() != PRIME_FIELD {
+        return Err("[Key] only prime field curves are supported".into());
+    }
+    let p = field.child(1)?.as_biguint()?;
+    let coefficients = asn1.child(2)?.expect(Tag::SEQUENCE)?;
+    let coefficient = |i| -> Result<BigUint> {
+        let bytes = coefficients.child(i)?.as_octet_string()?;
+        Ok(BigUint::from_bytes(&bytes, Endian::Big))
+    };
+    let (a, b) = (coefficient(0)?, coefficient(1)?);
+
+    // 基点须为未压缩格式 04 || x || y
+    let base = asn1.child(3)?.as_octet_string()?;
+    let size = p.bits().div_ceil(8);
+    if base.len() != 2 * size + 1 || base[0] != 0x04 {
+        return Err("[Key] base point must be uncompressed".into());
+    }
+    let gx = BigUint::from_bytes(&base[1..size + 1], Endian::Big);
+    let gy = BigUint::from_bytes(&base[size + 1..], Endian::Big);
+    let n = asn1.child(4)?.as_biguint()?;
+    EcCurve::new(p, a, b, gx, gy, n)
+}
+
+// 已知曲线使用 OID, 其他曲线使用显式参数 (不含余因子)
+pub(super) fn encode_parameters(curve: &EcCurve) -> Asn1 {
+    if let Some((name, _)) = named_curves().into_iter().find(|(_, c)| c == curve) {
+        return Asn1::oid(&oid(name));
+    }
+    let size = curve.field_size();
+    let padded = |value: &BigUint| value.to_bytes_padded(size, Endian::Big).unwrap();
+    Asn1::sequence(vec![
+        Asn1::uint(&BigUint::one()),
+        Asn1::sequence(vec![Asn1::oid(&oid(PRIME_FIELD)), Asn1::uint(curve.p())]),
+        Asn1::sequence(vec![
+            Asn1::octet_string(&padded(curve.a())),
+            Asn1::octet_string(&padded(curve.b())),
+        ]),
+        Asn1::octet_string(&curve.encode_point(curve.generator(), false)),
+        Asn1::uint(curve.n()),
+    ])
+}
+
+// ECPrivateKey ::= SEQUENCE { version 1, privateKey, [0] parameters OPTIONAL, [1] publicKey OPTIONAL }
+// PKCS#8 中曲线参数位于 AlgorithmIdentifier, 由 parameters 传入
+pub(super) fn decode_private_key(
+    asn1: &Asn1,
+    parameters: Option<CurveParameters>,
+) -> Result<PrivateKey> {
+    if asn1.expect(Tag::SEQUENCE)?.child(0)?.as_u64()? != 1 {
+        return Err("[Key] invalid ECPrivateKey version".into());
+    }
+    let private_key = asn1
+        .child(1)?
+        .expect(Tag::OCTET_STRING)?
+        .as_octet_string()?;
+    let mut parameters = parameters;
+    let mut public_key = None;
+    for child in &asn1.children[2..] {
+        if child.tag.matches(&Tag::context(0, true)) && parameters.is_none() {
+            parameters = Some(decode_parameters(child.child(0)?)?);
+        } else if child.tag.matches(&Tag::context(1, true)) {
+            public_key = Some(child.child(0)?.as_bit_string()?.0);
+        }
+    }
+
+    let (key, matches) = match parameters.ok_or("[Key] missing EC curve parameters")? {
+        CurveParameters::Ec(curve) => {
+            let pair = EcKeyPair::from_private_key(&curve, &private_key)?;
+            let expected = match &public_key {
+                Some(point) => Some(curve.decompress_point(point)?),
+                None => None,
+            };
+            let matches = expected.is_none_or(|point| *point == *pair.public_key);
+            (PrivateKey::Ec(curve, pair), matches)
+        }
+        CurveParameters::Sm2 => {
+            let pair = Sm2KeyPair::from_private_key(&private_key)?;
+            // 只比较未压缩格式的公钥
+            let matches = public_key
+                .as_ref()
+                .is_none_or(|point| point.len() != 65 || **point == *pair.public_key);
+            (PrivateKey::Sm2(pair), matches)
+        }
+    };
+    if !matches {
+        return Err("[Key] public key does not match the private key".into());
+    }
+    Ok(key)
+}
+
+pub(super) fn encode_private_key(key: &PrivateKey, with_parameters: bool) -> Result<Asn1> {
+    let (private_key, public_key, parameters) = match key {
+        PrivateKey::Ec(curve, pair) => (
+            &pair.private_key,
+            &pair.public_key,
+            encode_parameters(curve),
+        ),
+        PrivateKey::Sm2(pair) => (&pair.private_key, &pair.public_key, Asn1::oid(&oid(SM2))),
+        _ => return Err("[Key] SEC1 only supports EC keys".into()),
+    };
+    let mut children = vec![Asn1::uint(&BigUint::one()), Asn1::octet_string(private_key)];
+    if with_parameters {
+        children.push(Asn1::explicit(0, parameters));
+    }
+    children.push(Asn1::explicit(1, Asn1::bit_string(public_key)));
+    Ok(Asn1::sequence(children))
+}
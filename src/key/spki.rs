@@ -0,0 +1,65 @@
+use crate::asn1::{Asn1, Tag};
+use crate::bytes::Bytes;
+use crate::curve25519;
+use crate::key::sec1::{self, CurveParameters};
+use crate::key::{
+    algorithm_identifier, oid, oid_name, parse_algorithm, pkcs1, PublicKey, EC_PUBLIC_KEY, ED25519,
+    RSASSA_PSS, RSA_ENCRYPTION, SM2,
+};
+use crate::sm2;
+use crate::types::Result;
+
+// SubjectPublicKeyInfo ::= SEQUENCE { algorithm AlgorithmIdentifier, subjectPublicKey BIT STRING }
+pub(super) fn decode(asn1: &Asn1) -> Result<PublicKey> {
+    let (algorithm, parameters) = parse_algorithm(asn1.expect(Tag::SEQUENCE)?.child(0)?)?;
+    let (public_key, unused) = asn1.child(1)?.expect(Tag::BIT_STRING)?.as_bit_string()?;
+    if unused != 0 {
+        return Err("[Key] public key must be a whole number of bytes".into());
+    }
+
+    match algorithm.to_string().as_str() {
+        RSA_ENCRYPTION | RSASSA_PSS => {
+            pkcs1::decode_public_key(&Asn1::from_ber(&public_key)?).map(PublicKey::Rsa)
+        }
+        EC_PUBLIC_KEY => {
+            let parameters = parameters.ok_or("[Key] missing EC curve parameters")?;
+            match sec1::decode_parameters(parameters)? {
+                CurveParameters::Ec(curve) => {
+                    curve.decode_point(&public_key)?;
+                    Ok(PublicKey::Ec(curve, public_key))
+                }
+                CurveParameters::Sm2 => decode_sm2(public_key),
+            }
+        }
+        SM2 => decode_sm2(public_key),
+        ED25519 => {
+            curve25519::parse_public_key(&public_key)?;
+            Ok(PublicKey::Ed25519(public_key))
+        }
+        _ => Err(format!("[Key] unsupported key algorithm {}", oid_name(&algorithm)).into()),
+    }
+}
+
+fn decode_sm2(public_key: Bytes) -> Result<PublicKey> {
+    sm2::parse_public_key(&public_key)?;
+    Ok(PublicKey::Sm2(public_key))
+}
+
+pub(super) fn encode(key: &PublicKey) -> Asn1 {
+    let (algorithm, public_key) = match key {
+        PublicKey::Rsa(key) => (
+            algorithm_identifier(RSA_ENCRYPTION, Some(Asn1::null())),
+            pkcs1::encode_public_key(key).to_der(),
+        ),
+        PublicKey::Ec(curve, point) => (
+            algorithm_identifier(EC_PUBLIC_KEY, Some(sec1::encode_parameters(curve))),
+            point.clone(),
+        ),
+        PublicKey::Sm2(point) => (
+            algorithm_identifier(EC_PUBLIC_KEY, Some(Asn1::oid(&oid(SM2)))),
+            point.clone(),
+        ),
+        PublicKey::Ed25519(point) => (algorithm_identifier(ED25519, None), point.clone()),
+    };
+    Asn1::sequence(vec![algorithm, Asn1::bit_string(&public_key)])
+}
@@ -0,0 +1,66 @@
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+use crate::types::Result;
+
+mod unwrap;
+mod wrap;
+
+pub use unwrap::KeyUnwrap;
+pub use wrap::KeyWrap;
+
+// RFC 3394 默认初始值
+const DEFAULT_IV: [u8; 8] = [0xa6; 8];
+// RFC 5649 替代初始值的前4字节 后4字节为明文长度
+const ALTERNATIVE_IV_PREFIX: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+// 只支持128位分组 (64位半分组)
+fn check<C: BlockCipher>(cipher: &C) -> Result<()> {
+    match cipher.block_size() {
+        BlockSize::Bytes16 => Ok(()),
+        _ => Err("[KeyWrap] only 128-bit block ciphers are supported".into()),
+    }
+}
+
+// 包装过程 共 6 * n 步
+fn wrap_blocks<C: BlockCipher>(cipher: &C, iv: &[u8], plain: &[u8]) -> Vec<u8> {
+    let mut a = u64::from_be_bytes(iv.try_into().unwrap());
+    let mut r: Vec<[u8; 8]> = plain
+        .chunks(8)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect();
+    let n = r.len() as u64;
+
+    for j in 0..6 {
+        for (i, block) in r.iter_mut().enumerate() {
+            let b = cipher.encrypt_block(&[a.to_be_bytes(), *block].concat());
+            let t = n * j + i as u64 + 1;
+            a = u64::from_be_bytes(b[..8].try_into().unwrap()) ^ t;
+            block.copy_from_slice(&b[8..]);
+        }
+    }
+
+    let mut output = a.to_be_bytes().to_vec();
+    output.extend(r.iter().flatten());
+    output
+}
+
+// 解包过程 返回初始值和明文
+fn unwrap_blocks<C: BlockCipher>(cipher: &C, input: &[u8]) -> ([u8; 8], Vec<u8>) {
+    let mut a = u64::from_be_bytes(input[..8].try_into().unwrap());
+    let mut r: Vec<[u8; 8]> = input[8..]
+        .chunks(8)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect();
+    let n = r.len() as u64;
+
+    for j in (0..6).rev() {
+        for (i, block) in r.iter_mut().enumerate().rev() {
+            let t = n * j + i as u64 + 1;
+            let b = cipher.decrypt_block(&[(a ^ t).to_be_bytes(), *block].concat());
+            a = u64::from_be_bytes(b[..8].try_into().unwrap());
+            block.copy_from_slice(&b[8..]);
+        }
+    }
+
+    (a.to_be_bytes(), r.concat())
+}
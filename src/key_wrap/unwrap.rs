@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::key_wrap::{check, unwrap_blocks, ALTERNATIVE_IV_PREFIX, DEFAULT_IV};
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 密钥解包 RFC 3394 (AES-KW) / RFC 5649 (AES-KWP)
+// 完整性校验失败时返回错误
+#[derive(Debug)]
+pub struct KeyUnwrap<C: BlockCipher> {
+    pub key: Bytes,
+    pub with_padding: bool,
+    cipher: PhantomData<C>,
+}
+
+impl<C: BlockCipher> KeyUnwrap<C> {
+    pub fn new(key: &[u8], with_padding: bool) -> Self {
+        Self {
+            key: Bytes::new(key),
+            with_padding,
+            cipher: PhantomData,
+        }
+    }
+}
+
+impl<C: BlockCipher> Operation for KeyUnwrap<C> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let cipher = C::build(&self.key);
+        check(&cipher)?;
+
+        if input.len() < 16 || !input.len().is_multiple_of(8) {
+            return Err(
+                "[KeyUnwrap] input length must be a multiple of 8 bytes and at least 16 bytes"
+                    .into(),
+            );
+        }
+
+        if !self.with_padding {
+            if input.len() < 24 {
+                return Err("[KeyUnwrap] input length must be at least 24 bytes".into());
+            }
+            let (iv, plain) = unwrap_blocks(&cipher, input);
+            if iv != DEFAULT_IV {
+                return Err("[KeyUnwrap] integrity check failed".into());
+            }
+            return Ok(Bytes::new(plain));
+        }
+
+        let (iv, padded) = if input.len() == 16 {
+            let block = cipher.decrypt_block(input);
+            (block[..8].try_into().unwrap(), block[8..].to_vec())
+        } else {
+            unwrap_blocks(&cipher, input)
+        };
+
+        // 校验替代初始值 明文长度 以及填充是否全为0
+        let length = u32::from_be_bytes(iv[4..].try_into().unwrap()) as usize;
+        if iv[..4] != ALTERNATIVE_IV_PREFIX
+            || length > padded.len()
+            || length + 8 <= padded.len()
+            || padded[length..].iter().any(|&byte| byte != 0)
+        {
+            return Err("[KeyUnwrap] integrity check failed".into());
+        }
+        Ok(Bytes::new(&padded[..length]))
+    }
+}
@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::key_wrap::{check, wrap_blocks, ALTERNATIVE_IV_PREFIX, DEFAULT_IV};
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 密钥包装 RFC 3394 (AES-KW) / RFC 5649 (AES-KWP)
+// 分组密码由类型参数指定 如 KeyWrap::<Aes> KeyWrap::<Sm4>
+#[derive(Debug)]
+pub struct KeyWrap<C: BlockCipher> {
+    pub key: Bytes,
+    pub with_padding: bool, // 是否使用 RFC 5649 带填充的版本
+    cipher: PhantomData<C>,
+}
+
+impl<C: BlockCipher> KeyWrap<C> {
+    pub fn new(key: &[u8], with_padding: bool) -> Self {
+        Self {
+            key: Bytes::new(key),
+            with_padding,
+            cipher: PhantomData,
+        }
+    }
+}
+
+impl<C: BlockCipher> Operation for KeyWrap<C> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let cipher = C::build(&self.key);
+        check(&cipher)?;
+
+        if !self.with_padding {
+            if input.len() < 16 || !input.len().is_multiple_of(8) {
+                return Err(
+                    "[KeyWrap] input length must be a multiple of 8 bytes and at least 16 bytes"
+                        .into(),
+                );
+            }
+            return Ok(Bytes::new(wrap_blocks(&cipher, &DEFAULT_IV, input)));
+        }
+
+        if input.is_empty() || input.len() > u32::MAX as usize {
+            return Err("[KeyWrap] input length must be between 1 and 2^32 - 1 bytes".into());
+        }
+        // 替代初始值 = A65959A6 || 明文长度
+        let mut iv = ALTERNATIVE_IV_PREFIX.to_vec();
+        iv.extend_from_slice(&(input.len() as u32).to_be_bytes());
+        // 补零到8字节的整数倍
+        let mut padded = input.to_vec();
+        padded.resize(input.len().div_ceil(8) * 8, 0);
+
+        // 只有一个半分组时 直接加密一个分组
+        if padded.len() == 8 {
+            return Ok(cipher.encrypt_block(&[iv, padded].concat()));
+        }
+        Ok(Bytes::new(wrap_blocks(&cipher, &iv, &padded)))
+    }
+}
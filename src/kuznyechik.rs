@@ -0,0 +1,159 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod kuznyechik_decrypt;
+mod kuznyechik_encrypt;
+
+pub use kuznyechik_decrypt::KuznyechikDecrypt;
+pub use kuznyechik_encrypt::KuznyechikEncrypt;
+
+// GOST R 34.12-2015 (Kuznyechik)
+const ROUNDS: usize = 10;
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes16;
+
+// 非线性置换 与 Streebog 使用的相同
+pub(crate) const PI: [u8; 256] = [
+    0xfc, 0xee, 0xdd, 0x11, 0xcf, 0x6e, 0x31, 0x16, 0xfb, 0xc4, 0xfa, 0xda, 0x23, 0xc5, 0x04, 0x4d,
+    0xe9, 0x77, 0xf0, 0xdb, 0x93, 0x2e, 0x99, 0xba, 0x17, 0x36, 0xf1, 0xbb, 0x14, 0xcd, 0x5f, 0xc1,
+    0xf9, 0x18, 0x65, 0x5a, 0xe2, 0x5c, 0xef, 0x21, 0x81, 0x1c, 0x3c, 0x42, 0x8b, 0x01, 0x8e, 0x4f,
+    0x05, 0x84, 0x02, 0xae, 0xe3, 0x6a, 0x8f, 0xa0, 0x06, 0x0b, 0xed, 0x98, 0x7f, 0xd4, 0xd3, 0x1f,
+    0xeb, 0x34, 0x2c, 0x51, 0xea, 0xc8, 0x48, 0xab, 0xf2, 0x2a, 0x68, 0xa2, 0xfd, 0x3a, 0xce, 0xcc,
+    0xb5, 0x70, 0x0e, 0x56, 0x08, 0x0c, 0x76, 0x12, 0xbf, 0x72, 0x13, 0x47, 0x9c, 0xb7, 0x5d, 0x87,
+    0x15, 0xa1, 0x96, 0x29, 0x10, 0x7b, 0x9a, 0xc7, 0xf3, 0x91, 0x78, 0x6f, 0x9d, 0x9e, 0xb2, 0xb1,
+    0x32, 0x75, 0x19, 0x3d, 0xff, 0x35, 0x8a, 0x7e, 0x6d, 0x54, 0xc6, 0x80, 0xc3, 0xbd, 0x0d, 0x57,
+    0xdf, 0xf5, 0x24, 0xa9, 0x3e, 0xa8, 0x43, 0xc9, 0xd7, 0x79, 0xd6, 0xf6, 0x7c, 0x22, 0xb9, 0x03,
+    0xe0, 0x0f, 0xec, 0xde, 0x7a, 0x94, 0xb0, 0xbc, 0xdc, 0xe8, 0x28, 0x50, 0x4e, 0x33, 0x0a, 0x4a,
+    0xa7, 0x97, 0x60, 0x73, 0x1e, 0x00, 0x62, 0x44, 0x1a, 0xb8, 0x38, 0x82, 0x64, 0x9f, 0x26, 0x41,
+    0xad, 0x45, 0x46, 0x92, 0x27, 0x5e, 0x55, 0x2f, 0x8c, 0xa3, 0xa5, 0x7d, 0x69, 0xd5, 0x95, 0x3b,
+    0x07, 0x58, 0xb3, 0x40, 0x86, 0xac, 0x1d, 0xf7, 0x30, 0x37, 0x6b, 0xe4, 0x88, 0xd9, 0xe7, 0x89,
+    0xe1, 0x1b, 0x83, 0x49, 0x4c, 0x3f, 0xf8, 0xfe, 0x8d, 0x53, 0xaa, 0x90, 0xca, 0xd8, 0x85, 0x61,
+    0x20, 0x71, 0x67, 0xa4, 0x2d, 0x2b, 0x09, 0x5b, 0xcb, 0x9b, 0x25, 0xd0, 0xbe, 0xe5, 0x6c, 0x52,
+    0x59, 0xa6, 0x74, 0xd2, 0xe6, 0xf4, 0xb4, 0xc0, 0xd1, 0x66, 0xaf, 0xc2, 0x39, 0x4b, 0x63, 0xb6,
+];
+
+// 线性变换系数 GF(2^8) 模 x^8 + x^7 + x^6 + x + 1
+const L_VECTOR: [u8; 16] = [
+    148, 32, 133, 16, 194, 192, 1, 251, 1, 192, 194, 16, 133, 32, 148, 1,
+];
+const POLY: u16 = 0x1c3;
+
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut result = 0u8;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= (POLY & 0xff) as u8;
+        }
+        b >>= 1;
+    }
+    result
+}
+
+fn l(block: &[u8; 16]) -> u8 {
+    block
+        .iter()
+        .zip(L_VECTOR)
+        .fold(0, |acc, (&a, c)| acc ^ gf_mul(a, c))
+}
+
+// L = R^16, R 将 l 的结果移入最高字节
+fn linear(block: &mut [u8; 16]) {
+    for _ in 0..16 {
+        let x = l(block);
+        block.rotate_right(1);
+        block[0] = x;
+    }
+}
+
+fn linear_inv(block: &mut [u8; 16]) {
+    for _ in 0..16 {
+        block.rotate_left(1);
+        block[15] = l(block);
+    }
+}
+
+fn xor(a: &mut [u8; 16], b: &[u8; 16]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x ^= y;
+    }
+}
+
+// LSX[k](a) = L(S(a ^ k))
+fn lsx(block: &mut [u8; 16], key: &[u8; 16]) {
+    xor(block, key);
+    for byte in block.iter_mut() {
+        *byte = PI[*byte as usize];
+    }
+    linear(block);
+}
+
+#[derive(Debug)]
+pub struct Kuznyechik {
+    round_keys: [[u8; 16]; ROUNDS],
+    pi_inv: [u8; 256],
+}
+
+impl BlockCipher for Kuznyechik {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut a: [u8; 16] = block.try_into().unwrap();
+        for key in &self.round_keys[..ROUNDS - 1] {
+            lsx(&mut a, key);
+        }
+        xor(&mut a, &self.round_keys[ROUNDS - 1]);
+        Bytes::new(a.as_ref())
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut a: [u8; 16] = block.try_into().unwrap();
+        xor(&mut a, &self.round_keys[ROUNDS - 1]);
+        for key in self.round_keys[..ROUNDS - 1].iter().rev() {
+            linear_inv(&mut a);
+            for byte in a.iter_mut() {
+                *byte = self.pi_inv[*byte as usize];
+            }
+            xor(&mut a, key);
+        }
+        Bytes::new(a.as_ref())
+    }
+
+    // 密钥长度为 32 字节 不足补0 超出截断
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        key.resize(32, 0);
+
+        let mut round_keys = [[0u8; 16]; ROUNDS];
+        round_keys[0].copy_from_slice(&key[..16]);
+        round_keys[1].copy_from_slice(&key[16..32]);
+        // 每8轮 Feistel 结构产生一对轮密钥 常数 C_i = L(i)
+        for pair in 1..ROUNDS / 2 {
+            let (mut a1, mut a0) = (round_keys[2 * pair - 2], round_keys[2 * pair - 1]);
+            for i in 1..=8 {
+                let mut c = [0u8; 16];
+                c[15] = (8 * (pair - 1) + i) as u8;
+                linear(&mut c);
+                let mut t = a1;
+                lsx(&mut t, &c);
+                xor(&mut t, &a0);
+                (a1, a0) = (t, a1);
+            }
+            round_keys[2 * pair] = a1;
+            round_keys[2 * pair + 1] = a0;
+        }
+
+        let mut pi_inv = [0u8; 256];
+        for (i, &x) in PI.iter().enumerate() {
+            pi_inv[x as usize] = i as u8;
+        }
+
+        Self { round_keys, pi_inv }
+    }
+}
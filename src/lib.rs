@@ -1,24 +1,59 @@
 pub mod aes;
+pub mod aria;
+pub mod asn1;
 pub mod base64;
+pub mod bigint;
 pub mod bits;
+pub mod blowfish;
 pub mod bytes;
+pub mod camellia;
+pub mod cast;
+pub mod chacha20;
+pub mod chacha20_poly1305;
+pub mod cipher;
+pub mod curve25519;
 pub mod des;
+pub mod dh;
+pub mod dsa;
+pub mod ec;
+pub mod elgamal;
 pub mod enums;
 pub mod hex;
 pub mod hmac;
+pub mod idea;
+pub mod key;
+pub mod key_wrap;
+pub mod kuznyechik;
+pub mod magma;
 pub mod md5;
 pub mod mode;
 pub mod operation;
 pub mod padding;
+pub mod pbkdf2;
+pub mod pem;
+pub mod poly1305;
+pub mod random;
+pub mod rc2;
 pub mod rc4;
+pub mod rc5;
+pub mod rc6;
 pub mod recipe;
 pub mod rot13;
+pub mod rsa;
+pub mod salsa20;
+pub mod seed;
+pub mod serpent;
 pub mod sha1;
 pub mod sha2;
+pub mod sm2;
 pub mod sm3;
 pub mod sm4;
+pub mod streebog;
 pub mod tea;
+pub mod twofish;
 pub mod types;
+pub mod x509;
+pub mod zuc;
 
 // pub fn add(left: u64, right: u64) -> u64 {
 //     left + right
@@ -0,0 +1,244 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{BlockSize, Endian};
+
+mod magma_decrypt;
+mod magma_encrypt;
+
+pub use magma_decrypt::MagmaDecrypt;
+pub use magma_encrypt::MagmaEncrypt;
+
+// GOST 28147-89 / GOST R 34.12-2015 (Magma)
+const ROUNDS: usize = 32;
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes8;
+
+// 第 i 行作用于第 i 个4位组 (从最低位开始)
+// id-tc26-gost-28147-param-Z, GOST R 34.12-2015 规定的 S 盒 (1.2.643.7.1.2.5.1.1)
+const TC26_Z: [[u8; 16]; 8] = [
+    [12, 4, 6, 2, 10, 5, 11, 9, 14, 8, 13, 7, 0, 3, 15, 1],
+    [6, 8, 2, 3, 9, 10, 5, 12, 1, 14, 4, 7, 11, 13, 0, 15],
+    [11, 3, 5, 8, 2, 15, 10, 13, 14, 1, 7, 4, 12, 9, 6, 0],
+    [12, 8, 2, 1, 13, 4, 15, 6, 7, 0, 10, 5, 3, 14, 9, 11],
+    [7, 15, 5, 10, 8, 1, 6, 13, 0, 9, 3, 14, 11, 4, 2, 12],
+    [5, 13, 15, 6, 9, 2, 12, 10, 11, 7, 8, 1, 4, 3, 14, 0],
+    [8, 14, 2, 5, 6, 9, 1, 12, 15, 4, 11, 0, 13, 10, 3, 7],
+    [1, 7, 14, 13, 0, 5, 8, 3, 4, 15, 10, 6, 9, 12, 11, 2],
+];
+
+// id-Gost28147-89-CryptoPro-A-ParamSet (1.2.643.2.2.31.1)
+const CRYPTO_PRO_A: [[u8; 16]; 8] = [
+    [9, 6, 3, 2, 8, 11, 1, 7, 10, 4, 14, 15, 12, 0, 13, 5],
+    [3, 7, 14, 9, 8, 10, 15, 0, 5, 2, 6, 12, 11, 4, 13, 1],
+    [14, 4, 6, 2, 11, 3, 13, 8, 12, 15, 5, 10, 0, 7, 1, 9],
+    [14, 7, 10, 12, 13, 1, 3, 9, 0, 2, 11, 4, 15, 8, 5, 6],
+    [11, 5, 1, 9, 8, 13, 15, 0, 14, 4, 2, 3, 12, 7, 10, 6],
+    [3, 10, 13, 12, 1, 2, 0, 11, 7, 5, 9, 4, 8, 15, 14, 6],
+    [1, 13, 2, 9, 7, 10, 6, 0, 8, 12, 4, 5, 15, 3, 11, 14],
+    [11, 10, 15, 5, 0, 12, 14, 8, 6, 2, 3, 9, 1, 7, 13, 4],
+];
+
+// id-Gost28147-89-CryptoPro-B-ParamSet (1.2.643.2.2.31.2)
+const CRYPTO_PRO_B: [[u8; 16]; 8] = [
+    [8, 4, 11, 1, 3, 5, 0, 9, 2, 14, 10, 12, 13, 6, 7, 15],
+    [0, 1, 2, 10, 4, 13, 5, 12, 9, 7, 3, 15, 11, 8, 6, 14],
+    [14, 12, 0, 10, 9, 2, 13, 11, 7, 5, 8, 15, 3, 6, 1, 4],
+    [7, 5, 0, 13, 11, 6, 1, 2, 3, 10, 12, 15, 4, 14, 9, 8],
+    [2, 7, 12, 15, 9, 5, 10, 11, 1, 4, 0, 13, 6, 8, 14, 3],
+    [8, 3, 2, 6, 4, 13, 14, 11, 12, 1, 7, 15, 10, 0, 9, 5],
+    [5, 2, 10, 11, 9, 1, 12, 3, 7, 4, 13, 0, 6, 15, 8, 14],
+    [0, 4, 11, 14, 8, 3, 7, 1, 10, 2, 9, 6, 15, 13, 5, 12],
+];
+
+// id-Gost28147-89-CryptoPro-C-ParamSet (1.2.643.2.2.31.3)
+const CRYPTO_PRO_C: [[u8; 16]; 8] = [
+    [1, 11, 12, 2, 9, 13, 0, 15, 4, 5, 8, 14, 10, 7, 6, 3],
+    [0, 1, 7, 13, 11, 4, 5, 2, 8, 14, 15, 12, 9, 10, 6, 3],
+    [8, 2, 5, 0, 4, 9, 15, 10, 3, 7, 12, 13, 6, 14, 1, 11],
+    [3, 6, 0, 1, 5, 13, 10, 8, 11, 2, 9, 7, 14, 15, 12, 4],
+    [8, 13, 11, 0, 4, 5, 1, 2, 9, 3, 12, 14, 6, 15, 10, 7],
+    [12, 9, 11, 1, 8, 14, 2, 4, 7, 3, 6, 5, 10, 0, 15, 13],
+    [10, 9, 6, 8, 13, 14, 2, 0, 15, 3, 5, 11, 4, 1, 12, 7],
+    [7, 4, 0, 5, 10, 2, 15, 14, 12, 6, 1, 11, 13, 9, 3, 8],
+];
+
+// id-Gost28147-89-CryptoPro-D-ParamSet (1.2.643.2.2.31.4)
+const CRYPTO_PRO_D: [[u8; 16]; 8] = [
+    [15, 12, 2, 10, 6, 4, 5, 0, 7, 9, 14, 13, 1, 11, 8, 3],
+    [11, 6, 3, 4, 12, 15, 14, 2, 7, 13, 8, 0, 5, 10, 9, 1],
+    [1, 12, 11, 0, 15, 14, 6, 5, 10, 13, 4, 8, 9, 3, 7, 2],
+    [1, 5, 14, 12, 10, 7, 0, 13, 6, 2, 11, 4, 9, 3, 15, 8],
+    [0, 12, 8, 9, 13, 2, 10, 11, 7, 3, 6, 5, 4, 14, 15, 1],
+    [8, 0, 15, 3, 2, 5, 14, 11, 1, 10, 4, 7, 12, 9, 13, 6],
+    [3, 0, 6, 15, 1, 14, 9, 2, 13, 8, 12, 4, 11, 10, 5, 7],
+    [1, 10, 6, 8, 15, 11, 0, 4, 12, 3, 5, 9, 7, 13, 2, 14],
+];
+
+// id-Gost28147-89-TestParamSet (1.2.643.2.2.31.0)
+const TEST_89: [[u8; 16]; 8] = [
+    [4, 2, 15, 5, 9, 1, 0, 8, 14, 3, 11, 12, 13, 7, 10, 6],
+    [12, 9, 15, 14, 8, 1, 3, 10, 2, 7, 4, 13, 6, 0, 11, 5],
+    [13, 8, 14, 12, 7, 3, 9, 10, 1, 5, 2, 4, 6, 15, 0, 11],
+    [14, 9, 11, 2, 5, 15, 7, 1, 0, 13, 12, 6, 10, 4, 3, 8],
+    [3, 14, 5, 9, 6, 8, 0, 13, 10, 11, 7, 12, 2, 1, 15, 4],
+    [8, 15, 6, 11, 1, 9, 12, 5, 13, 3, 7, 10, 0, 14, 2, 4],
+    [9, 11, 12, 0, 3, 6, 7, 5, 4, 8, 14, 15, 1, 10, 2, 13],
+    [12, 6, 5, 2, 11, 0, 9, 13, 3, 14, 7, 10, 15, 4, 1, 8],
+];
+
+// id-GostR3411-94-CryptoProParamSet (1.2.643.2.2.30.1)
+const CRYPTO_PRO_3411: [[u8; 16]; 8] = [
+    [10, 4, 5, 6, 8, 1, 3, 7, 13, 12, 14, 0, 9, 2, 11, 15],
+    [5, 15, 4, 0, 2, 13, 11, 9, 1, 7, 6, 3, 12, 14, 10, 8],
+    [7, 15, 12, 14, 9, 4, 1, 0, 3, 11, 5, 2, 6, 10, 8, 13],
+    [4, 10, 7, 12, 0, 15, 2, 8, 14, 1, 6, 5, 13, 11, 9, 3],
+    [7, 6, 4, 11, 9, 12, 2, 10, 1, 8, 0, 14, 15, 13, 3, 5],
+    [7, 6, 2, 4, 13, 9, 15, 0, 10, 1, 5, 11, 8, 14, 12, 3],
+    [13, 14, 4, 1, 7, 0, 5, 10, 3, 12, 8, 15, 6, 2, 9, 11],
+    [1, 3, 10, 9, 5, 11, 4, 15, 8, 6, 7, 14, 13, 0, 2, 12],
+];
+
+// id-GostR3411-94-TestParamSet, 早期实现中最常见的 S 盒 (1.2.643.2.2.30.0)
+const TEST_3411: [[u8; 16]; 8] = [
+    [4, 10, 9, 2, 13, 8, 0, 14, 6, 11, 1, 12, 7, 15, 5, 3],
+    [14, 11, 4, 12, 6, 13, 15, 10, 2, 3, 8, 1, 0, 7, 5, 9],
+    [5, 8, 1, 13, 10, 3, 4, 2, 14, 15, 12, 7, 6, 0, 9, 11],
+    [7, 13, 10, 1, 0, 8, 9, 15, 14, 4, 6, 12, 11, 2, 5, 3],
+    [6, 12, 7, 1, 5, 15, 13, 8, 4, 10, 9, 14, 0, 3, 11, 2],
+    [4, 11, 10, 0, 7, 2, 1, 13, 3, 6, 8, 5, 9, 12, 15, 14],
+    [13, 11, 4, 1, 3, 15, 5, 9, 0, 10, 14, 7, 6, 8, 2, 12],
+    [1, 15, 13, 0, 5, 7, 10, 4, 9, 2, 3, 14, 6, 11, 8, 12],
+];
+
+// 可选的 S 盒
+#[derive(Clone, Copy, Debug)]
+pub enum MagmaSBox {
+    Tc26Z,
+    CryptoProA,
+    CryptoProB,
+    CryptoProC,
+    CryptoProD,
+    Test89,
+    CryptoPro3411,
+    Test3411,
+    Custom([[u8; 16]; 8]),
+}
+
+impl MagmaSBox {
+    pub fn table(&self) -> [[u8; 16]; 8] {
+        match self {
+            MagmaSBox::Tc26Z => TC26_Z,
+            MagmaSBox::CryptoProA => CRYPTO_PRO_A,
+            MagmaSBox::CryptoProB => CRYPTO_PRO_B,
+            MagmaSBox::CryptoProC => CRYPTO_PRO_C,
+            MagmaSBox::CryptoProD => CRYPTO_PRO_D,
+            MagmaSBox::Test89 => TEST_89,
+            MagmaSBox::CryptoPro3411 => CRYPTO_PRO_3411,
+            MagmaSBox::Test3411 => TEST_3411,
+            MagmaSBox::Custom(table) => *table,
+        }
+    }
+}
+
+// S 盒与字节序
+// GOST R 34.12-2015 使用大端序, GOST 28147-89 的常见实现 (OpenSSL gost 引擎, libgcrypt 等) 使用小端序
+#[derive(Clone, Copy, Debug)]
+pub struct MagmaParams {
+    pub s_box: MagmaSBox,
+    pub endian: Endian,
+}
+
+impl MagmaParams {
+    pub fn new(s_box: MagmaSBox, endian: Endian) -> Self {
+        Self { s_box, endian }
+    }
+}
+
+impl Default for MagmaParams {
+    fn default() -> Self {
+        Self::new(MagmaSBox::Tc26Z, Endian::Big)
+    }
+}
+
+#[derive(Debug)]
+pub struct Magma {
+    sub_keys: [u32; 8],
+    s_box: [[u8; 16]; 8],
+    endian: Endian,
+}
+
+impl Magma {
+    pub fn with_params(key: &[u8], params: MagmaParams) -> Self {
+        // 密钥长度为 32 字节 不足补0 超出截断
+        let mut key = key.to_vec();
+        key.resize(32, 0);
+        let mut sub_keys = [0u32; 8];
+        for (sub_key, chunk) in sub_keys.iter_mut().zip(key.chunks(4)) {
+            let word = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            *sub_key = match params.endian {
+                Endian::Big => u32::from_be_bytes(word),
+                Endian::Little => u32::from_le_bytes(word),
+            };
+        }
+
+        Self {
+            sub_keys,
+            s_box: params.s_box.table(),
+            endian: params.endian,
+        }
+    }
+
+    fn g(&self, a: u32, key: u32) -> u32 {
+        let x = a.wrapping_add(key);
+        let substituted = (0..8).fold(0u32, |acc, i| {
+            acc | (self.s_box[i][(x >> (4 * i)) as usize & 0xf] as u32) << (4 * i)
+        });
+        substituted.rotate_left(11)
+    }
+
+    // n1 为先与轮密钥相加的一半
+    // 大端序时为后4字节 小端序时为前4字节
+    fn block_crypt(&self, block: &[u8], key_order: impl Iterator<Item = usize>) -> Bytes {
+        let (first, second) = (
+            [block[0], block[1], block[2], block[3]],
+            [block[4], block[5], block[6], block[7]],
+        );
+        let (mut n1, mut n2) = match self.endian {
+            Endian::Big => (u32::from_be_bytes(second), u32::from_be_bytes(first)),
+            Endian::Little => (u32::from_le_bytes(first), u32::from_le_bytes(second)),
+        };
+        for i in key_order {
+            (n1, n2) = (n2 ^ self.g(n1, self.sub_keys[i]), n1);
+        }
+        // 最后一轮不交换
+        let (n1, n2) = (n2, n1);
+        Bytes::new(match self.endian {
+            Endian::Big => [n2.to_be_bytes(), n1.to_be_bytes()].concat(),
+            Endian::Little => [n1.to_le_bytes(), n2.to_le_bytes()].concat(),
+        })
+    }
+}
+
+impl BlockCipher for Magma {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    // 轮密钥顺序为 K1..K8 重复三次 最后为 K8..K1
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        self.block_crypt(
+            block,
+            (0..ROUNDS).map(|i| if i < 24 { i % 8 } else { 7 - i % 8 }),
+        )
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        self.block_crypt(
+            block,
+            (0..ROUNDS).map(|i| if i < 8 { i } else { 7 - i % 8 }),
+        )
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_params(key, MagmaParams::default())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::bytes::Bytes;
+use crate::magma::{Magma, MagmaParams, BLOCK_SIZE};
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct MagmaEncrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+    pub params: Option<MagmaParams>, // 自定义 S 盒与字节序
+}
+
+impl<M: Mode, P: Padding> MagmaEncrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            params: None,
+        }
+    }
+
+    pub fn with_params(key: &[u8], mode: M, params: MagmaParams) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            params: Some(params),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for MagmaEncrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let padded_data = self.padding.pad(input);
+
+        Ok(match self.params {
+            Some(params) => self
+                .mode
+                .block_cipher_encrypt(&padded_data, &Magma::with_params(&self.key, params)),
+            None => self.mode.cipher_encrypt::<Magma>(&padded_data, &self.key),
+        })
+    }
+}
@@ -107,7 +107,12 @@ impl Hashing for Md5 {
         BLOCK_SIZE
     }
 
-    //fn output_size(&self) -> usize {
-    //    16
-    //}
+    fn output_size(&self) -> usize {
+        16
+    }
+
+    // 1.2.840.113549.2.5
+    fn oid(&self) -> &'static [u8] {
+        &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x05]
+    }
 }
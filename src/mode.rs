@@ -2,21 +2,41 @@ use std::fmt::Debug;
 
 use crate::bits::Bits;
 use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
 use crate::enums::{Bit, BlockSize};
 
 mod cbc;
+mod ccm;
 mod cfb;
+mod cmac;
 mod ctr;
+mod cts;
+mod eax;
 mod ecb;
 mod gcm;
+mod gcm_siv;
+mod ige;
+mod ocb;
 mod ofb;
+mod pcbc;
+mod siv;
+mod xts;
 
 pub use cbc::Cbc;
+pub use ccm::Ccm;
 pub use cfb::Cfb;
 pub use ctr::Ctr;
+pub use cts::{Cts, CtsVariant};
+pub use eax::Eax;
 pub use ecb::Ecb;
 pub use gcm::Gcm;
+pub use gcm_siv::GcmSiv;
+pub use ige::Ige;
+pub use ocb::Ocb;
 pub use ofb::Ofb;
+pub use pcbc::Pcbc;
+pub use siv::Siv;
+pub use xts::Xts;
 
 pub trait Mode: Clone + Debug {
     fn bits_decrypt(
@@ -46,4 +66,29 @@ pub trait Mode: Clone + Debug {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[u8]) -> Bytes,
     ) -> Bytes;
+
+    // 由模式根据密钥构造分组密码后进行加密
+    // 需要拆分或派生密钥的模式(SIV, XTS等)需要重写此方法
+    fn cipher_encrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        self.block_cipher_encrypt(input, &C::build(key))
+    }
+
+    fn cipher_decrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        self.block_cipher_decrypt(input, &C::build(key))
+    }
+
+    // 使用已构造的分组密码进行加密 (如自定义S盒的分组密码)
+    fn block_cipher_encrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_encrypt(input, cipher.block_size(), |block| {
+            cipher.encrypt_block(block)
+        })
+    }
+
+    // 默认解密过程只用到分组加密函数
+    // 需要分组解密函数的模式(ECB, CBC等)需要重写此方法
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_decrypt(input, cipher.block_size(), |block| {
+            cipher.encrypt_block(block)
+        })
+    }
 }
@@ -1,5 +1,6 @@
 use crate::bits::Bits;
 use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
 use crate::enums::{Bit, BlockSize};
 use crate::mode::Mode;
 
@@ -109,4 +110,10 @@ impl Mode for Cbc {
         }
         Bytes::new(output)
     }
+
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_decrypt(input, cipher.block_size(), |block| {
+            cipher.decrypt_block(block)
+        })
+    }
 }
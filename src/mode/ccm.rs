@@ -0,0 +1,189 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::Mode;
+
+// 带CBC-MAC的计数器模式
+// 加解密过程均不支持并行(CBC-MAC)
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct Ccm {
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+    pub tag_length: usize,
+}
+
+impl Ccm {
+    // nonce 长度 7..=13 字节
+    // tag 长度 4, 6, 8, 10, 12, 14, 16 字节
+    pub fn new(nonce: &[u8], additional_data: Option<&[u8]>, tag_length: usize) -> Self {
+        Self {
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+            tag_length,
+        }
+    }
+
+    // 长度字段所占字节数 L = 15 - nonce长度
+    fn length_size(&self) -> usize {
+        let nonce_len = self.nonce.len();
+        if !(7..=13).contains(&nonce_len) {
+            panic!("CCM nonce length must be between 7 and 13 bytes");
+        }
+        15 - nonce_len
+    }
+
+    fn check_tag_length(&self) {
+        if !(4..=16).contains(&self.tag_length) || !self.tag_length.is_multiple_of(2) {
+            panic!("CCM tag length must be one of 4, 6, 8, 10, 12, 14, 16");
+        }
+    }
+
+    // 计数器块 A_i = flags || nonce || i
+    fn counter_block(&self, index: usize) -> Bytes {
+        let l = self.length_size();
+        let mut block = Vec::with_capacity(16);
+        block.push((l - 1) as u8);
+        block.extend_from_slice(&self.nonce);
+        block.extend_from_slice(&(index as u128).to_be_bytes()[16 - l..]);
+        Bytes::new(block)
+    }
+
+    // CBC-MAC 计算未加密的认证标签
+    fn cbc_mac(&self, payload: &[u8], block_encrypt: &impl Fn(&[u8]) -> Bytes) -> Bytes {
+        let l = self.length_size();
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+
+        // B_0 = flags || nonce || Q
+        let mut flags = (((self.tag_length - 2) / 2) << 3) as u8 | (l - 1) as u8;
+        if !aad.is_empty() {
+            flags |= 0x40;
+        }
+        let payload_len = payload.len() as u128;
+        if l < 16 && payload_len >> (8 * l) != 0 {
+            panic!("CCM payload is too long for the given nonce length");
+        }
+        let mut auth_data = Vec::new();
+        auth_data.push(flags);
+        auth_data.extend_from_slice(&self.nonce);
+        auth_data.extend_from_slice(&payload_len.to_be_bytes()[16 - l..]);
+
+        // 附加认证数据 长度编码 + 数据 + 填充
+        if !aad.is_empty() {
+            let aad_len = aad.len() as u64;
+            if aad_len < 0xff00 {
+                auth_data.extend_from_slice(&(aad_len as u16).to_be_bytes());
+            } else if aad_len <= u32::MAX as u64 {
+                auth_data.extend_from_slice(&[0xff, 0xfe]);
+                auth_data.extend_from_slice(&(aad_len as u32).to_be_bytes());
+            } else {
+                auth_data.extend_from_slice(&[0xff, 0xff]);
+                auth_data.extend_from_slice(&aad_len.to_be_bytes());
+            }
+            auth_data.extend_from_slice(aad);
+            auth_data.resize(auth_data.len().div_ceil(16) * 16, 0);
+        }
+
+        // 明文 + 填充
+        auth_data.extend_from_slice(payload);
+        auth_data.resize(auth_data.len().div_ceil(16) * 16, 0);
+
+        let mut vector = Bytes::new(vec![0u8; 16]);
+        for chunk in auth_data.chunks(16) {
+            vector = block_encrypt(&Bytes::new(chunk).xor(&vector));
+        }
+        Bytes::new(&vector[..self.tag_length])
+    }
+
+    // 计数器模式加解密 计数器从1开始
+    fn ctr_crypt(&self, input: &[u8], block_encrypt: &impl Fn(&[u8]) -> Bytes) -> Vec<u8> {
+        let mut output = Vec::with_capacity(input.len());
+        for (i, chunk) in input.chunks(16).enumerate() {
+            let block = Bytes::new(chunk);
+            let block_key = block_encrypt(&self.counter_block(i + 1));
+            output.extend_from_slice(&block.xor(&block_key));
+        }
+        output
+    }
+}
+
+impl Mode for Ccm {
+    fn bits_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_decrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        match block_size {
+            BlockSize::Bytes16 => {}
+            _ => panic!("CCM mode only supports 128-bit block size"),
+        }
+        self.check_tag_length();
+        if input.len() < self.tag_length {
+            panic!("CCM decrypt input must include authentication tag");
+        }
+
+        let ciphertext = &input[..input.len() - self.tag_length];
+        let received_tag = &input[input.len() - self.tag_length..];
+
+        let output = self.ctr_crypt(ciphertext, &block_encrypt);
+
+        // 对解密得到的明文计算认证标签
+        let mac = self.cbc_mac(&output, &block_encrypt);
+        let s0 = block_encrypt(&self.counter_block(0));
+        let computed_tag = mac.xor(&s0);
+
+        // 验证标签
+        if computed_tag.as_ref() != received_tag {
+            panic!("CCM authentication tag verification failed");
+        }
+
+        Bytes::new(output)
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        match block_size {
+            BlockSize::Bytes16 => {}
+            _ => panic!("CCM mode only supports 128-bit block size"),
+        }
+        self.check_tag_length();
+
+        // 先对明文计算认证标签 再加密
+        let mac = self.cbc_mac(input, &block_encrypt);
+        let s0 = block_encrypt(&self.counter_block(0));
+        let tag = mac.xor(&s0);
+
+        let mut output = self.ctr_crypt(input, &block_encrypt);
+
+        // 返回值包含密文和标签
+        output.extend_from_slice(&tag);
+        Bytes::new(output)
+    }
+}
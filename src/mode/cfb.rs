@@ -9,11 +9,62 @@ use crate::mode::Mode;
 #[derive(Clone, Debug)]
 pub struct Cfb {
     pub iv: Bytes,
+    pub segment_size: Option<usize>, // 分段长度(位) 为空时等于分组长度
 }
 
 impl Cfb {
     pub fn new(iv: &[u8]) -> Self {
-        Self { iv: Bytes::new(iv) }
+        Self {
+            iv: Bytes::new(iv),
+            segment_size: None,
+        }
+    }
+
+    // 分段长度以位为单位 如 CFB-1, CFB-8, CFB-64
+    pub fn with_segment_size(iv: &[u8], segment_size: usize) -> Self {
+        Self {
+            iv: Bytes::new(iv),
+            segment_size: Some(segment_size),
+        }
+    }
+
+    // 分段长度小于分组长度时返回分段长度
+    fn segment(&self, block_size: BlockSize) -> Option<usize> {
+        let block_bits = usize::from(block_size) * 8;
+        match self.segment_size {
+            Some(size) if size == 0 || size > block_bits => {
+                panic!("CFB segment size must be between 1 and {} bits", block_bits)
+            }
+            Some(size) if size < block_bits => Some(size),
+            _ => None,
+        }
+    }
+
+    // 按位处理的移位寄存器
+    // 每次只取加密结果的前 segment 位 并将密文分段移入寄存器
+    fn segment_crypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        segment: usize,
+        decrypt: bool,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size.into(), 0);
+        let mut register = Bytes::new(iv).to_bits().to_vec();
+
+        let input: Bits = input.into();
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(segment) {
+            let block = Bits::new(chunk);
+            let result = block.xor(&block_encrypt(&register));
+            let feedback = if decrypt { &block } else { &result };
+            register.drain(..feedback.len());
+            register.extend_from_slice(feedback);
+            output.extend_from_slice(&result);
+        }
+        Bits::new(output).to_bytes()
     }
 }
 
@@ -24,6 +75,9 @@ impl Mode for Cfb {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[Bit]) -> Bits,
     ) -> Bytes {
+        if let Some(segment) = self.segment(block_size) {
+            return self.segment_crypt(input, block_size, segment, true, block_encrypt);
+        }
         let block_size: usize = block_size.into();
         // inintialization vector
         let mut iv = self.iv.to_vec();
@@ -50,6 +104,9 @@ impl Mode for Cfb {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[Bit]) -> Bits,
     ) -> Bytes {
+        if let Some(segment) = self.segment(block_size) {
+            return self.segment_crypt(input, block_size, segment, false, block_encrypt);
+        }
         let block_size: usize = block_size.into();
 
         // inintialization vector
@@ -76,6 +133,11 @@ impl Mode for Cfb {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[u8]) -> Bytes,
     ) -> Bytes {
+        if let Some(segment) = self.segment(block_size) {
+            return self.segment_crypt(input, block_size, segment, true, |vector| {
+                block_encrypt(&Bytes::from(vector)).to_bits()
+            });
+        }
         let block_size: usize = block_size.into();
         let mut iv = self.iv.to_vec();
         iv.resize(block_size, 0);
@@ -97,6 +159,11 @@ impl Mode for Cfb {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[u8]) -> Bytes,
     ) -> Bytes {
+        if let Some(segment) = self.segment(block_size) {
+            return self.segment_crypt(input, block_size, segment, false, |vector| {
+                block_encrypt(&Bytes::from(vector)).to_bits()
+            });
+        }
         let block_size: usize = block_size.into();
         let mut iv = self.iv.to_vec();
         iv.resize(block_size, 0);
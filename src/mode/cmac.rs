@@ -0,0 +1,45 @@
+use crate::bytes::Bytes;
+
+// 以 u128 表示的128位分组进行加密
+pub(super) fn encrypt_u128(block_encrypt: &impl Fn(&[u8]) -> Bytes, block: u128) -> u128 {
+    let output = block_encrypt(&block.to_be_bytes());
+    u128::from_be_bytes(output[..16].try_into().unwrap())
+}
+
+// GF(2^128) 上乘以 x
+pub(super) fn dbl(block: u128) -> u128 {
+    if block >> 127 == 1 {
+        (block << 1) ^ 0x87
+    } else {
+        block << 1
+    }
+}
+
+// CMAC (OMAC1)
+pub(super) fn cmac(message: &[u8], block_encrypt: &impl Fn(&[u8]) -> Bytes) -> u128 {
+    // 子密钥
+    let l = encrypt_u128(block_encrypt, 0);
+    let k1 = dbl(l);
+    let k2 = dbl(k1);
+
+    let block_count = message.len().div_ceil(16).max(1);
+    let mut vector = 0u128;
+    for i in 0..block_count {
+        let chunk = &message[i * 16..message.len().min(i * 16 + 16)];
+        let mut block = [0u8; 16];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let mut block = u128::from_be_bytes(block);
+
+        if i == block_count - 1 {
+            // 最后一组 完整时异或k1 否则填充10*后异或k2
+            if chunk.len() == 16 {
+                block ^= k1;
+            } else {
+                block ^= 0x80u128 << (8 * (15 - chunk.len()));
+                block ^= k2;
+            }
+        }
+        vector = encrypt_u128(block_encrypt, vector ^ block);
+    }
+    vector
+}
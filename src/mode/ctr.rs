@@ -1,7 +1,8 @@
 use crate::bits::Bits;
 use crate::bytes::Bytes;
-use crate::enums::{Bit, BlockSize};
+use crate::enums::{Bit, BlockSize, Endian};
 use crate::mode::Mode;
+use crate::types::Result;
 
 // 计数器模式
 // 加解密过程均支持并行
@@ -9,38 +10,93 @@ use crate::mode::Mode;
 #[derive(Clone, Debug)]
 pub struct Ctr {
     pub iv: Bytes,
+    pub counter_width: usize, // 计数器位数 位于向量末尾
+    pub endian: Endian,       // 计数器字节序
+    pub offset: usize,        // 起始位置(字节) 用于从中间开始加解密
 }
 
-// 计数器自增 采用CyberChef中的实现(只对后32bit进行自增)
 impl Ctr {
+    // 默认采用CyberChef中的实现(只对后32bit进行大端自增)
     pub fn new(iv: &[u8]) -> Self {
-        Self { iv: Bytes::new(iv) }
+        Self::with_counter(iv, 32, Endian::Big)
     }
 
-    fn bits_crypt(
-        &self,
-        input: &[u8],
-        block_size: BlockSize,
-        block_crypt: impl Fn(&[Bit]) -> Bits,
-    ) -> Bytes {
-        let block_size: usize = block_size.into();
-        // inintialization vector
-        let mut iv = self.iv.to_vec();
-        iv.resize(block_size, 0);
-        let mut vector = Bytes::new(iv).to_bits();
+    // 计数器位数为8的整数倍 如 32, 64, 128 (OpenSSL 为 128 位大端)
+    pub fn with_counter(iv: &[u8], counter_width: usize, endian: Endian) -> Self {
+        Self {
+            iv: Bytes::new(iv),
+            counter_width,
+            endian,
+            offset: 0,
+        }
+    }
 
-        let length = input.len();
-        let mut output = Vec::with_capacity(length);
+    // 向量由 nonce || 计数器 组成 计数器占 counter_width 位
+    pub fn from_nonce(
+        nonce: &[u8],
+        counter: u128,
+        counter_width: usize,
+        endian: Endian,
+    ) -> Result<Self> {
+        if counter_width == 0 || !counter_width.is_multiple_of(8) || counter_width > 128 {
+            return Err("[Ctr] counter width must be a multiple of 8 up to 128 bits".into());
+        }
+        if counter_width < 128 && counter >> counter_width != 0 {
+            return Err("[Ctr] counter does not fit in the counter width".into());
+        }
+        let counter_bytes = match endian {
+            Endian::Big => counter.to_be_bytes()[16 - counter_width / 8..].to_vec(),
+            Endian::Little => counter.to_le_bytes()[..counter_width / 8].to_vec(),
+        };
+        Ok(Self::with_counter(
+            &[nonce, &counter_bytes].concat(),
+            counter_width,
+            endian,
+        ))
+    }
 
-        for chunk in input.chunks(block_size) {
-            let block: Bits = chunk.into();
-            let block_key = block_crypt(&vector);
-            output.extend_from_slice(&block.xor(&block_key).to_bytes());
-            // 向量不断自增
-            vector.inc32();
+    // 从第 offset 个字节开始加解密
+    pub fn with_offset(self, offset: usize) -> Self {
+        Self { offset, ..self }
+    }
+
+    // 第 index 个分组对应的向量
+    fn counter_block(&self, iv: &[u8], index: u128) -> Bytes {
+        let width = self.counter_width / 8;
+        if self.counter_width == 0
+            || !self.counter_width.is_multiple_of(8)
+            || width > iv.len()
+            || width > 16
+        {
+            panic!("CTR counter width must be a multiple of 8 bits and fit in the block");
         }
+        let start = iv.len() - width;
+        let mut counter_bytes = [0u8; 16];
+        let counter = match self.endian {
+            Endian::Big => {
+                counter_bytes[16 - width..].copy_from_slice(&iv[start..]);
+                u128::from_be_bytes(counter_bytes)
+            }
+            Endian::Little => {
+                counter_bytes[..width].copy_from_slice(&iv[start..]);
+                u128::from_le_bytes(counter_bytes)
+            }
+        };
 
-        Bytes::new(output)
+        // 只在计数器位数内自增 溢出时回绕
+        let mask = if width == 16 {
+            u128::MAX
+        } else {
+            (1u128 << self.counter_width) - 1
+        };
+        let counter = counter.wrapping_add(index) & mask;
+
+        let mut block = iv[..start].to_vec();
+        match self.endian {
+            Endian::Big => block.extend_from_slice(&counter.to_be_bytes()[16 - width..]),
+            Endian::Little => block.extend_from_slice(&counter.to_le_bytes()[..width]),
+        }
+        Bytes::new(block)
     }
 
     fn bytes_crypt(
@@ -52,15 +108,26 @@ impl Ctr {
         let block_size: usize = block_size.into();
         let mut iv = self.iv.to_vec();
         iv.resize(block_size, 0);
-        let mut vector = Bytes::new(iv);
+
+        // 起始分组 及 分组内需要跳过的字节数
+        let mut index = (self.offset / block_size) as u128;
+        let mut skip = self.offset % block_size;
 
         let mut output = Vec::with_capacity(input.len());
-        for chunk in input.chunks(block_size) {
-            let block = Bytes::new(chunk);
-            let block_key = block_crypt(&vector);
-            output.extend_from_slice(&block.xor(&block_key));
+        let mut position = 0;
+        while position < input.len() {
+            let block_key = block_crypt(&self.counter_block(&iv, index));
+            let length = (block_size - skip).min(input.len() - position);
+            output.extend(
+                input[position..position + length]
+                    .iter()
+                    .zip(&block_key[skip..])
+                    .map(|(byte, key)| byte ^ key),
+            );
+            position += length;
+            skip = 0;
             // 向量不断自增
-            vector.inc32();
+            index += 1;
         }
         Bytes::new(output)
     }
@@ -73,7 +140,9 @@ impl Mode for Ctr {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[Bit]) -> Bits,
     ) -> Bytes {
-        self.bits_crypt(input, block_size, block_encrypt)
+        self.bytes_crypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
     }
 
     fn bits_encrypt(
@@ -82,7 +151,9 @@ impl Mode for Ctr {
         block_size: BlockSize,
         block_encrypt: impl Fn(&[Bit]) -> Bits,
     ) -> Bytes {
-        self.bits_crypt(input, block_size, block_encrypt)
+        self.bytes_crypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
     }
 
     // 加解密过程相同
@@ -0,0 +1,172 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::Mode;
+
+// 密文窃取的三种排列方式 (NIST SP 800-38A 附录)
+// CS1: 倒数第二块截断后保持原有顺序
+// CS2: 最后一块不完整时交换最后两块 否则与 CS1 相同
+// CS3: 总是交换最后两块 (Kerberos 5)
+#[derive(Clone, Copy, Debug)]
+pub enum CtsVariant {
+    Cs1,
+    Cs2,
+    Cs3,
+}
+
+// 密文窃取的密码分组链接模式
+// 密文长度与明文长度相同 输入长度至少为一个分组
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct Cts {
+    pub iv: Bytes,
+    pub variant: CtsVariant,
+}
+
+impl Cts {
+    pub fn new(iv: &[u8], variant: CtsVariant) -> Self {
+        Self {
+            iv: Bytes::new(iv),
+            variant,
+        }
+    }
+
+    // 是否交换最后两块
+    fn swap(&self, partial: bool) -> bool {
+        match self.variant {
+            CtsVariant::Cs1 => false,
+            CtsVariant::Cs2 => partial,
+            CtsVariant::Cs3 => true,
+        }
+    }
+
+    fn check(input: &[u8], block_size: usize) {
+        if input.len() < block_size {
+            panic!("CTS input must be at least one block");
+        }
+    }
+}
+
+impl Mode for Cts {
+    fn bits_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_decrypt(input, block_size, |block| {
+            block_decrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        Self::check(input, block_size);
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size, 0);
+        let mut vector = Bytes::new(iv);
+
+        let length = input.len();
+        if length == block_size {
+            return block_decrypt(input).xor(&vector);
+        }
+
+        // 最后一块的长度
+        let last_length = match length % block_size {
+            0 => block_size,
+            remainder => remainder,
+        };
+        let head_length = length - block_size - last_length;
+
+        // 前面的分组按 CBC 解密
+        let mut output = Vec::with_capacity(length);
+        for chunk in input[..head_length].chunks(block_size) {
+            output.extend_from_slice(&block_decrypt(chunk).xor(&vector));
+            vector = Bytes::new(chunk);
+        }
+
+        // 还原为 CS1 顺序 截断的倒数第二块 || 最后一块
+        let tail = &input[head_length..];
+        let (stolen, last) = if self.swap(last_length < block_size) {
+            let (last, stolen) = tail.split_at(block_size);
+            (stolen, last)
+        } else {
+            tail.split_at(last_length)
+        };
+
+        // 解密最后一块 得到被窃取的部分
+        let decrypted_last = block_decrypt(last);
+        let mut second_last = stolen.to_vec();
+        second_last.extend_from_slice(&decrypted_last[last_length..]);
+        let plain_last = Bytes::new(&decrypted_last[..last_length]).xor(&Bytes::new(stolen));
+
+        output.extend_from_slice(&block_decrypt(&second_last).xor(&vector));
+        output.extend_from_slice(&plain_last);
+        Bytes::new(output)
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        Self::check(input, block_size);
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size, 0);
+        let mut vector = Bytes::new(iv);
+
+        // 补零后按 CBC 加密
+        let mut blocks = Vec::with_capacity(input.len().div_ceil(block_size));
+        for chunk in input.chunks(block_size) {
+            let mut block = chunk.to_vec();
+            block.resize(block_size, 0);
+            vector = block_encrypt(&Bytes::new(block).xor(&vector));
+            blocks.push(vector.clone());
+        }
+        if blocks.len() == 1 {
+            return vector;
+        }
+
+        let last_length = match input.len() % block_size {
+            0 => block_size,
+            remainder => remainder,
+        };
+        let last = blocks.pop().unwrap();
+        let second_last = blocks.pop().unwrap();
+
+        let mut output: Vec<u8> = blocks.iter().flat_map(|block| block.to_vec()).collect();
+        if self.swap(last_length < block_size) {
+            output.extend_from_slice(&last);
+            output.extend_from_slice(&second_last[..last_length]);
+        } else {
+            output.extend_from_slice(&second_last[..last_length]);
+            output.extend_from_slice(&last);
+        }
+        Bytes::new(output)
+    }
+
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_decrypt(input, cipher.block_size(), |block| {
+            cipher.decrypt_block(block)
+        })
+    }
+}
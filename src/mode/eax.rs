@@ -0,0 +1,140 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::cmac::cmac;
+use crate::mode::Mode;
+
+// EAX模式 计数器模式 + OMAC认证
+// 加解密过程均支持并行(CTR部分)
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct Eax {
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+    pub tag_length: usize,
+}
+
+impl Eax {
+    pub fn new(nonce: &[u8], additional_data: Option<&[u8]>, tag_length: usize) -> Self {
+        Self {
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+            tag_length,
+        }
+    }
+
+    fn check(&self, block_size: BlockSize) {
+        match block_size {
+            BlockSize::Bytes16 => {}
+            _ => panic!("EAX mode only supports 128-bit block size"),
+        }
+        if !(1..=16).contains(&self.tag_length) {
+            panic!("EAX tag length must be between 1 and 16 bytes");
+        }
+    }
+
+    // OMAC^t(M) = OMAC([t]_n || M)
+    fn omac(&self, t: u8, message: &[u8], block_encrypt: &impl Fn(&[u8]) -> Bytes) -> u128 {
+        let mut data = vec![0u8; 16];
+        data[15] = t;
+        data.extend_from_slice(message);
+        cmac(&data, block_encrypt)
+    }
+
+    // 计数器模式 对整个128位计数器进行自增
+    fn ctr_crypt(
+        &self,
+        input: &[u8],
+        counter: u128,
+        block_encrypt: &impl Fn(&[u8]) -> Bytes,
+    ) -> Vec<u8> {
+        let mut vector = Bytes::new(counter.to_be_bytes().as_ref());
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(16) {
+            let block = Bytes::new(chunk);
+            let block_key = block_encrypt(&vector);
+            output.extend_from_slice(&block.xor(&block_key));
+            vector.inc();
+        }
+        output
+    }
+
+    fn tag(
+        &self,
+        nonce_mac: u128,
+        ciphertext: &[u8],
+        block_encrypt: &impl Fn(&[u8]) -> Bytes,
+    ) -> Vec<u8> {
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+        let header_mac = self.omac(1, aad, block_encrypt);
+        let ciphertext_mac = self.omac(2, ciphertext, block_encrypt);
+        let tag = nonce_mac ^ header_mac ^ ciphertext_mac;
+        tag.to_be_bytes()[..self.tag_length].to_vec()
+    }
+}
+
+impl Mode for Eax {
+    fn bits_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_decrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        self.check(block_size);
+        if input.len() < self.tag_length {
+            panic!("EAX decrypt input must include authentication tag");
+        }
+
+        let ciphertext = &input[..input.len() - self.tag_length];
+        let received_tag = &input[input.len() - self.tag_length..];
+
+        let nonce_mac = self.omac(0, &self.nonce, &block_encrypt);
+        let computed_tag = self.tag(nonce_mac, ciphertext, &block_encrypt);
+
+        // 验证标签
+        if computed_tag != received_tag {
+            panic!("EAX authentication tag verification failed");
+        }
+
+        Bytes::new(self.ctr_crypt(ciphertext, nonce_mac, &block_encrypt))
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        self.check(block_size);
+
+        let nonce_mac = self.omac(0, &self.nonce, &block_encrypt);
+        let mut output = self.ctr_crypt(input, nonce_mac, &block_encrypt);
+        let tag = self.tag(nonce_mac, &output, &block_encrypt);
+
+        // 返回值包含密文和标签
+        output.extend_from_slice(&tag);
+        Bytes::new(output)
+    }
+}
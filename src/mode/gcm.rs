@@ -12,7 +12,7 @@ pub struct Gcm {
     pub additional_data: Option<Bytes>,
 }
 
-fn ghash_u128(key: u128, messages: &[u128]) -> u128 {
+pub(super) fn ghash_u128(key: u128, messages: &[u128]) -> u128 {
     let mut y = 0u128;
     for message in messages {
         let yi = gmul_u128(y ^ message, key);
@@ -0,0 +1,181 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::cmac::encrypt_u128;
+use crate::mode::gcm::ghash_u128;
+use crate::mode::Mode;
+
+// GCM-SIV 抗nonce重用的认证加密 (RFC 8452)
+// 由主密钥和nonce派生出认证密钥与加密密钥
+// nonce 长度为 12 字节
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct GcmSiv {
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+}
+
+// POLYVAL 可以通过 GHASH 计算
+// POLYVAL(H, X) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)), ByteReverse(X)))
+fn polyval(key: &[u8], data: &[u8]) -> [u8; 16] {
+    let mut h = u128::from_le_bytes(key.try_into().unwrap());
+    // mulX_GHASH
+    let lsb = h & 1;
+    h >>= 1;
+    if lsb != 0 {
+        h ^= 0xe1u128 << 120;
+    }
+
+    let blocks: Vec<u128> = data
+        .chunks(16)
+        .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    ghash_u128(h, &blocks).to_le_bytes()
+}
+
+impl GcmSiv {
+    pub fn new(nonce: &[u8], additional_data: Option<&[u8]>) -> Self {
+        Self {
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+        }
+    }
+
+    // 派生 认证密钥 和 加密密钥
+    fn derive_keys<C: BlockCipher>(&self, key: &[u8]) -> (Vec<u8>, C) {
+        if self.nonce.len() != 12 {
+            panic!("GCM-SIV nonce length must be 12 bytes");
+        }
+        let master = C::build(key);
+        match master.block_size() {
+            BlockSize::Bytes16 => {}
+            _ => panic!("GCM-SIV mode only supports 128-bit block size"),
+        }
+
+        // 每次加密取结果的前8字节
+        let key_blocks = if key.len() > 16 { 6 } else { 4 };
+        let mut derived = Vec::with_capacity(key_blocks * 8);
+        for i in 0..key_blocks as u32 {
+            let mut block = i.to_le_bytes().to_vec();
+            block.extend_from_slice(&self.nonce);
+            derived.extend_from_slice(&master.encrypt_block(&block)[..8]);
+        }
+
+        let encryption_key = C::build(&derived[16..]);
+        derived.truncate(16);
+        (derived, encryption_key)
+    }
+
+    fn tag(
+        &self,
+        auth_key: &[u8],
+        plaintext: &[u8],
+        block_encrypt: &impl Fn(&[u8]) -> Bytes,
+    ) -> u128 {
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+
+        let mut auth_data = aad.to_vec();
+        auth_data.resize(aad.len().div_ceil(16) * 16, 0);
+        auth_data.extend_from_slice(plaintext);
+        auth_data.resize(auth_data.len().div_ceil(16) * 16, 0);
+        // 长度块 小端序 单位为比特
+        auth_data.extend_from_slice(&((aad.len() * 8) as u64).to_le_bytes());
+        auth_data.extend_from_slice(&((plaintext.len() * 8) as u64).to_le_bytes());
+
+        let mut s = polyval(auth_key, &auth_data);
+        for (byte, nonce_byte) in s.iter_mut().zip(self.nonce.iter()) {
+            *byte ^= nonce_byte;
+        }
+        s[15] &= 0x7f;
+
+        encrypt_u128(block_encrypt, u128::from_be_bytes(s))
+    }
+
+    // 计数器模式 计数器为前32位 小端序自增
+    fn ctr_crypt(input: &[u8], tag: u128, block_encrypt: &impl Fn(&[u8]) -> Bytes) -> Vec<u8> {
+        let mut counter = tag.to_be_bytes();
+        counter[15] |= 0x80;
+
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(16) {
+            let block = Bytes::new(chunk);
+            let block_key = block_encrypt(&counter);
+            output.extend_from_slice(&block.xor(&block_key));
+
+            let count = u32::from_le_bytes(counter[..4].try_into().unwrap()).wrapping_add(1);
+            counter[..4].copy_from_slice(&count.to_le_bytes());
+        }
+        output
+    }
+}
+
+impl Mode for GcmSiv {
+    fn bits_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("GCM-SIV mode derives keys from the master key, use cipher_decrypt instead")
+    }
+
+    fn bits_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("GCM-SIV mode derives keys from the master key, use cipher_encrypt instead")
+    }
+
+    fn bytes_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("GCM-SIV mode derives keys from the master key, use cipher_decrypt instead")
+    }
+
+    fn bytes_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("GCM-SIV mode derives keys from the master key, use cipher_encrypt instead")
+    }
+
+    fn cipher_encrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        let (auth_key, cipher) = self.derive_keys::<C>(key);
+        let block_encrypt = |block: &[u8]| cipher.encrypt_block(block);
+
+        let tag = self.tag(&auth_key, input, &block_encrypt);
+        let mut output = Self::ctr_crypt(input, tag, &block_encrypt);
+
+        // 返回值包含密文和标签
+        output.extend_from_slice(&tag.to_be_bytes());
+        Bytes::new(output)
+    }
+
+    fn cipher_decrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        if input.len() < 16 {
+            panic!("GCM-SIV decrypt input must include authentication tag");
+        }
+        let (auth_key, cipher) = self.derive_keys::<C>(key);
+        let block_encrypt = |block: &[u8]| cipher.encrypt_block(block);
+
+        let ciphertext = &input[..input.len() - 16];
+        let received_tag = u128::from_be_bytes(input[input.len() - 16..].try_into().unwrap());
+
+        let output = Self::ctr_crypt(ciphertext, received_tag, &block_encrypt);
+
+        // 验证标签
+        if self.tag(&auth_key, &output, &block_encrypt) != received_tag {
+            panic!("GCM-SIV authentication tag verification failed");
+        }
+
+        Bytes::new(output)
+    }
+}
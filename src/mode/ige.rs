@@ -0,0 +1,100 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::Mode;
+
+// 无限混淆扩展模式 (Telegram MTProto)
+// C_i = E(P_i ^ C_{i-1}) ^ P_{i-1}
+// 加解密过程均不支持并行
+// 不支持无填充 (输入长度需为分组长度的整数倍)
+#[derive(Clone, Debug)]
+pub struct Ige {
+    pub iv: Bytes,
+}
+
+impl Ige {
+    // iv 长度为两个分组 前半部分为 C_0 后半部分为 P_0 (与 OpenSSL 一致)
+    pub fn new(iv: &[u8]) -> Self {
+        Self { iv: Bytes::new(iv) }
+    }
+
+    fn vectors(&self, input: &[u8], block_size: usize) -> (Bytes, Bytes) {
+        if !input.len().is_multiple_of(block_size) {
+            panic!("IGE input length must be a multiple of block size");
+        }
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size * 2, 0);
+        (Bytes::new(&iv[..block_size]), Bytes::new(&iv[block_size..]))
+    }
+}
+
+impl Mode for Ige {
+    fn bits_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_decrypt(input, block_size, |block| {
+            block_decrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        let (mut prev_cipher, mut prev_plain) = self.vectors(input, block_size);
+
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(block_size) {
+            let block = Bytes::new(chunk);
+            let plain = block_decrypt(&block.xor(&prev_plain)).xor(&prev_cipher);
+            prev_cipher = block;
+            output.extend_from_slice(&plain);
+            prev_plain = plain;
+        }
+        Bytes::new(output)
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        let (mut prev_cipher, mut prev_plain) = self.vectors(input, block_size);
+
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(block_size) {
+            let block = Bytes::new(chunk);
+            let cipher = block_encrypt(&block.xor(&prev_cipher)).xor(&prev_plain);
+            prev_plain = block;
+            output.extend_from_slice(&cipher);
+            prev_cipher = cipher;
+        }
+        Bytes::new(output)
+    }
+
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_decrypt(input, cipher.block_size(), |block| {
+            cipher.decrypt_block(block)
+        })
+    }
+}
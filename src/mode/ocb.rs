@@ -0,0 +1,239 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::cmac::{dbl, encrypt_u128};
+use crate::mode::Mode;
+
+// OCB3 (RFC 7253)
+// 加解密过程均支持并行
+// 支持无填充
+// 解密过程同时需要分组加密和解密函数
+#[derive(Clone, Debug)]
+pub struct Ocb {
+    pub nonce: Bytes,
+    pub additional_data: Option<Bytes>,
+    pub tag_length: usize,
+}
+
+// 密钥相关的偏移量 L_*, L_$, L_0, L_1, ...
+struct Offsets {
+    l_star: u128,
+    l_dollar: u128,
+    l: Vec<u128>,
+}
+
+impl Offsets {
+    fn new(block_encrypt: &impl Fn(&[u8]) -> Bytes, block_count: usize) -> Self {
+        let l_star = encrypt_u128(block_encrypt, 0);
+        let l_dollar = dbl(l_star);
+        let mut l = vec![dbl(l_dollar)];
+        // ntz(i) 最大为 log2(block_count)
+        while (1usize << l.len()) <= block_count {
+            l.push(dbl(l[l.len() - 1]));
+        }
+        Self {
+            l_star,
+            l_dollar,
+            l,
+        }
+    }
+
+    fn ntz(&self, i: usize) -> u128 {
+        self.l[i.trailing_zeros() as usize]
+    }
+}
+
+impl Ocb {
+    pub fn new(nonce: &[u8], additional_data: Option<&[u8]>, tag_length: usize) -> Self {
+        Self {
+            nonce: Bytes::new(nonce),
+            additional_data: additional_data.map(Bytes::new),
+            tag_length,
+        }
+    }
+
+    fn check(&self, block_size: BlockSize) {
+        match block_size {
+            BlockSize::Bytes16 => {}
+            _ => panic!("OCB mode only supports 128-bit block size"),
+        }
+        if !(1..=16).contains(&self.tag_length) {
+            panic!("OCB tag length must be between 1 and 16 bytes");
+        }
+        if self.nonce.is_empty() || self.nonce.len() > 15 {
+            panic!("OCB nonce length must be between 1 and 15 bytes");
+        }
+    }
+
+    // 根据nonce计算初始偏移量 Offset_0
+    fn initial_offset(&self, block_encrypt: &impl Fn(&[u8]) -> Bytes) -> u128 {
+        // Nonce = num2str(TAGLEN mod 128, 7) || zeros || 1 || N
+        let mut nonce = [0u8; 16];
+        nonce[16 - self.nonce.len()..].copy_from_slice(&self.nonce);
+        nonce[15 - self.nonce.len()] |= 0x01;
+        nonce[0] |= (((self.tag_length * 8) % 128) << 1) as u8;
+        let nonce = u128::from_be_bytes(nonce);
+
+        let bottom = (nonce & 0x3f) as u32;
+        let ktop = encrypt_u128(block_encrypt, nonce & !0x3f);
+        // Stretch = Ktop || (Ktop[1..64] xor Ktop[9..72])
+        let stretch = ((ktop >> 64) ^ (ktop >> 56)) as u64;
+        if bottom == 0 {
+            ktop
+        } else {
+            (ktop << bottom) | (stretch >> (64 - bottom)) as u128
+        }
+    }
+
+    // HASH(K, A)
+    fn hash(&self, offsets: &Offsets, block_encrypt: &impl Fn(&[u8]) -> Bytes) -> u128 {
+        let aad = self.additional_data.as_deref().unwrap_or_default();
+        let mut sum = 0u128;
+        let mut offset = 0u128;
+        for (i, chunk) in aad.chunks(16).enumerate() {
+            if chunk.len() == 16 {
+                offset ^= offsets.ntz(i + 1);
+                let block = u128::from_be_bytes(chunk.try_into().unwrap());
+                sum ^= encrypt_u128(block_encrypt, block ^ offset);
+            } else {
+                offset ^= offsets.l_star;
+                sum ^= encrypt_u128(block_encrypt, pad_block(chunk) ^ offset);
+            }
+        }
+        sum
+    }
+
+    fn crypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: &impl Fn(&[u8]) -> Bytes,
+        block_crypt: &impl Fn(&[u8]) -> Bytes,
+        encrypting: bool,
+    ) -> (Vec<u8>, Vec<u8>) {
+        self.check(block_size);
+        let aad_len = self.additional_data.as_ref().map_or(0, |aad| aad.len());
+        let offsets = Offsets::new(block_encrypt, input.len().max(aad_len) / 16);
+
+        let mut offset = self.initial_offset(block_encrypt);
+        let mut checksum = 0u128;
+        let mut output = Vec::with_capacity(input.len() + self.tag_length);
+
+        for (i, chunk) in input.chunks(16).enumerate() {
+            if chunk.len() == 16 {
+                offset ^= offsets.ntz(i + 1);
+                let block = u128::from_be_bytes(chunk.try_into().unwrap());
+                let crypted = encrypt_u128(block_crypt, block ^ offset) ^ offset;
+                checksum ^= if encrypting { block } else { crypted };
+                output.extend_from_slice(&crypted.to_be_bytes());
+            } else {
+                // 最后不完整的一组
+                offset ^= offsets.l_star;
+                let pad = Bytes::new(encrypt_u128(block_encrypt, offset).to_be_bytes().as_ref());
+                let crypted = Bytes::new(chunk).xor(&pad);
+                checksum ^= if encrypting {
+                    pad_block(chunk)
+                } else {
+                    pad_block(&crypted)
+                };
+                output.extend_from_slice(&crypted);
+            }
+        }
+
+        let tag = encrypt_u128(block_encrypt, checksum ^ offset ^ offsets.l_dollar)
+            ^ self.hash(&offsets, block_encrypt);
+        (output, tag.to_be_bytes()[..self.tag_length].to_vec())
+    }
+
+    fn decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+        block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        if input.len() < self.tag_length {
+            panic!("OCB decrypt input must include authentication tag");
+        }
+
+        let ciphertext = &input[..input.len() - self.tag_length];
+        let received_tag = &input[input.len() - self.tag_length..];
+
+        let (output, computed_tag) = self.crypt(
+            ciphertext,
+            block_size,
+            &block_encrypt,
+            &block_decrypt,
+            false,
+        );
+
+        // 验证标签
+        if computed_tag != received_tag {
+            panic!("OCB authentication tag verification failed");
+        }
+
+        Bytes::new(output)
+    }
+}
+
+// 不完整的分组 填充 10*
+fn pad_block(chunk: &[u8]) -> u128 {
+    let mut block = [0u8; 16];
+    block[..chunk.len()].copy_from_slice(chunk);
+    block[chunk.len()] = 0x80;
+    u128::from_be_bytes(block)
+}
+
+impl Mode for Ocb {
+    fn bits_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_decrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("OCB decryption needs both block functions, use cipher_decrypt instead")
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("OCB decryption needs both block functions, use cipher_decrypt instead")
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let (mut output, tag) = self.crypt(input, block_size, &block_encrypt, &block_encrypt, true);
+
+        // 返回值包含密文和标签
+        output.extend_from_slice(&tag);
+        Bytes::new(output)
+    }
+
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.decrypt(
+            input,
+            cipher.block_size(),
+            |block| cipher.encrypt_block(block),
+            |block| cipher.decrypt_block(block),
+        )
+    }
+}
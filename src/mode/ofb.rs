@@ -9,11 +9,62 @@ use crate::mode::Mode;
 #[derive(Clone, Debug)]
 pub struct Ofb {
     pub iv: Bytes,
+    pub feedback_size: Option<usize>, // 反馈长度(位) 为空时等于分组长度
 }
 
 impl Ofb {
     pub fn new(iv: &[u8]) -> Self {
-        Self { iv: Bytes::new(iv) }
+        Self {
+            iv: Bytes::new(iv),
+            feedback_size: None,
+        }
+    }
+
+    // 反馈长度以位为单位 (FIPS 81 中的 k 位 OFB)
+    pub fn with_feedback_size(iv: &[u8], feedback_size: usize) -> Self {
+        Self {
+            iv: Bytes::new(iv),
+            feedback_size: Some(feedback_size),
+        }
+    }
+
+    // 反馈长度小于分组长度时返回反馈长度
+    fn feedback(&self, block_size: BlockSize) -> Option<usize> {
+        let block_bits = usize::from(block_size) * 8;
+        match self.feedback_size {
+            Some(size) if size == 0 || size > block_bits => {
+                panic!(
+                    "OFB feedback size must be between 1 and {} bits",
+                    block_bits
+                )
+            }
+            Some(size) if size < block_bits => Some(size),
+            _ => None,
+        }
+    }
+
+    // 按位处理的移位寄存器
+    // 每次只取加密结果的前 feedback 位 作为密钥流并移入寄存器
+    fn feedback_crypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        feedback: usize,
+        block_crypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size.into(), 0);
+        let mut register = Bytes::new(iv).to_bits().to_vec();
+
+        let input: Bits = input.into();
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(feedback) {
+            let key_stream = block_crypt(&register);
+            register.drain(..feedback);
+            register.extend_from_slice(&key_stream[..feedback]);
+            output.extend_from_slice(&Bits::new(chunk).xor(&key_stream));
+        }
+        Bits::new(output).to_bytes()
     }
 
     fn bits_crypt(
@@ -22,6 +73,9 @@ impl Ofb {
         block_size: BlockSize,
         block_crypt: impl Fn(&[Bit]) -> Bits,
     ) -> Bytes {
+        if let Some(feedback) = self.feedback(block_size) {
+            return self.feedback_crypt(input, block_size, feedback, block_crypt);
+        }
         let block_size: usize = block_size.into();
         // inintialization vector
         let mut iv = self.iv.to_vec();
@@ -47,6 +101,11 @@ impl Ofb {
         block_size: BlockSize,
         block_crypt: impl Fn(&[u8]) -> Bytes,
     ) -> Bytes {
+        if let Some(feedback) = self.feedback(block_size) {
+            return self.feedback_crypt(input, block_size, feedback, |vector| {
+                block_crypt(&Bytes::from(vector)).to_bits()
+            });
+        }
         let block_size: usize = block_size.into();
         let mut iv = self.iv.to_vec();
         iv.resize(block_size, 0);
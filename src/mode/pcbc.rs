@@ -0,0 +1,99 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::Mode;
+
+// 填充密码分组链接模式
+// 明文与密文的异或结果作为下一个向量
+// 加解密过程均不支持并行
+// 不支持无填充 (输入长度需为分组长度的整数倍)
+#[derive(Clone, Debug)]
+pub struct Pcbc {
+    pub iv: Bytes,
+}
+
+impl Pcbc {
+    pub fn new(iv: &[u8]) -> Self {
+        Self { iv: Bytes::new(iv) }
+    }
+
+    fn check(input: &[u8], block_size: usize) {
+        if !input.len().is_multiple_of(block_size) {
+            panic!("PCBC input length must be a multiple of block size");
+        }
+    }
+}
+
+impl Mode for Pcbc {
+    fn bits_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_decrypt(input, block_size, |block| {
+            block_decrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bits_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        self.bytes_encrypt(input, block_size, |block| {
+            block_encrypt(&Bytes::new(block).to_bits()).to_bytes()
+        })
+    }
+
+    fn bytes_decrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        Self::check(input, block_size);
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size, 0);
+        let mut vector = Bytes::new(iv);
+
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(block_size) {
+            let plain = block_decrypt(chunk).xor(&vector);
+            vector = plain.xor(&Bytes::new(chunk));
+            output.extend_from_slice(&plain);
+        }
+        Bytes::new(output)
+    }
+
+    fn bytes_encrypt(
+        &self,
+        input: &[u8],
+        block_size: BlockSize,
+        block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        let block_size: usize = block_size.into();
+        Self::check(input, block_size);
+        let mut iv = self.iv.to_vec();
+        iv.resize(block_size, 0);
+        let mut vector = Bytes::new(iv);
+
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(block_size) {
+            let block = Bytes::new(chunk);
+            let cipher = block_encrypt(&block.xor(&vector));
+            vector = block.xor(&cipher);
+            output.extend_from_slice(&cipher);
+        }
+        Bytes::new(output)
+    }
+
+    fn block_cipher_decrypt(&self, input: &[u8], cipher: &impl BlockCipher) -> Bytes {
+        self.bytes_decrypt(input, cipher.block_size(), |block| {
+            cipher.decrypt_block(block)
+        })
+    }
+}
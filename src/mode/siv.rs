@@ -0,0 +1,161 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::cmac::{cmac, dbl};
+use crate::mode::Mode;
+
+// SIV 合成初始向量模式 (RFC 5297)
+// 确定性认证加密 支持多个附加认证数据
+// 密钥由两部分组成 前半部分用于S2V 后半部分用于CTR
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct Siv {
+    pub additional_data: Vec<Bytes>,
+}
+
+impl Siv {
+    // nonce 作为最后一个附加认证数据参与计算
+    pub fn new(nonce: Option<&[u8]>, additional_data: &[&[u8]]) -> Self {
+        let mut components: Vec<Bytes> = additional_data.iter().map(|ad| Bytes::new(*ad)).collect();
+        if let Some(nonce) = nonce {
+            components.push(Bytes::new(nonce));
+        }
+        Self {
+            additional_data: components,
+        }
+    }
+
+    // S2V 将多个字符串转换为一个向量
+    fn s2v(&self, plaintext: &[u8], block_encrypt: &impl Fn(&[u8]) -> Bytes) -> u128 {
+        let mut d = cmac(&[0u8; 16], block_encrypt);
+        for ad in self.additional_data.iter() {
+            d = dbl(d) ^ cmac(ad, block_encrypt);
+        }
+
+        let t = if plaintext.len() >= 16 {
+            // xorend
+            let mut t = plaintext.to_vec();
+            let start = t.len() - 16;
+            for (byte, d_byte) in t[start..].iter_mut().zip(d.to_be_bytes()) {
+                *byte ^= d_byte;
+            }
+            t
+        } else {
+            let mut padded = [0u8; 16];
+            padded[..plaintext.len()].copy_from_slice(plaintext);
+            padded[plaintext.len()] = 0x80;
+            (dbl(d) ^ u128::from_be_bytes(padded))
+                .to_be_bytes()
+                .to_vec()
+        };
+        cmac(&t, block_encrypt)
+    }
+
+    // 计数器模式 对整个128位计数器进行自增
+    fn ctr_crypt(input: &[u8], vector: u128, block_encrypt: &impl Fn(&[u8]) -> Bytes) -> Vec<u8> {
+        // 清除第31位和第63位 (从右往左)
+        let counter = vector & !(1u128 << 63 | 1u128 << 31);
+        let mut vector = Bytes::new(counter.to_be_bytes().as_ref());
+        let mut output = Vec::with_capacity(input.len());
+        for chunk in input.chunks(16) {
+            let block = Bytes::new(chunk);
+            let block_key = block_encrypt(&vector);
+            output.extend_from_slice(&block.xor(&block_key));
+            vector.inc();
+        }
+        output
+    }
+
+    fn split_key(key: &[u8]) -> (&[u8], &[u8]) {
+        if key.is_empty() || !key.len().is_multiple_of(2) {
+            panic!("SIV key must consist of two keys of equal length");
+        }
+        key.split_at(key.len() / 2)
+    }
+
+    fn check(block_size: BlockSize) {
+        match block_size {
+            BlockSize::Bytes16 => {}
+            _ => panic!("SIV mode only supports 128-bit block size"),
+        }
+    }
+}
+
+impl Mode for Siv {
+    fn bits_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("SIV mode needs two keys, use cipher_decrypt instead")
+    }
+
+    fn bits_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("SIV mode needs two keys, use cipher_encrypt instead")
+    }
+
+    fn bytes_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("SIV mode needs two keys, use cipher_decrypt instead")
+    }
+
+    fn bytes_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("SIV mode needs two keys, use cipher_encrypt instead")
+    }
+
+    fn cipher_encrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        let (mac_key, ctr_key) = Self::split_key(key);
+        let mac_cipher = C::build(mac_key);
+        let ctr_cipher = C::build(ctr_key);
+        Self::check(mac_cipher.block_size());
+
+        let vector = self.s2v(input, &|block: &[u8]| mac_cipher.encrypt_block(block));
+        let ciphertext = Self::ctr_crypt(input, vector, &|block: &[u8]| {
+            ctr_cipher.encrypt_block(block)
+        });
+
+        // 返回值为 V || C
+        let mut output = vector.to_be_bytes().to_vec();
+        output.extend_from_slice(&ciphertext);
+        Bytes::new(output)
+    }
+
+    fn cipher_decrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        let (mac_key, ctr_key) = Self::split_key(key);
+        let mac_cipher = C::build(mac_key);
+        let ctr_cipher = C::build(ctr_key);
+        Self::check(mac_cipher.block_size());
+        if input.len() < 16 {
+            panic!("SIV decrypt input must include synthetic IV");
+        }
+
+        let vector = u128::from_be_bytes(input[..16].try_into().unwrap());
+        let output = Self::ctr_crypt(&input[16..], vector, &|block: &[u8]| {
+            ctr_cipher.encrypt_block(block)
+        });
+
+        // 验证合成初始向量
+        let computed = self.s2v(&output, &|block: &[u8]| mac_cipher.encrypt_block(block));
+        if computed != vector {
+            panic!("SIV authentication failed");
+        }
+
+        Bytes::new(output)
+    }
+}
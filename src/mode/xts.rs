@@ -0,0 +1,188 @@
+use crate::bits::Bits;
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{Bit, BlockSize};
+use crate::mode::Mode;
+
+// 带密文窃取的 XEX 可调分组密码模式 (IEEE 1619)
+// 密钥由两部分组成 前半部分用于加密数据 后半部分用于加密调整值
+// 密文长度与明文长度相同 输入长度至少为一个分组
+// 支持无填充
+#[derive(Clone, Debug)]
+pub struct Xts {
+    pub tweak: Bytes,
+}
+
+impl Xts {
+    pub fn new(tweak: &[u8]) -> Self {
+        Self {
+            tweak: Bytes::new(tweak),
+        }
+    }
+
+    // 扇区号按小端序作为调整值
+    pub fn from_sector(sector: u128) -> Self {
+        Self {
+            tweak: Bytes::new(sector.to_le_bytes().as_ref()),
+        }
+    }
+
+    fn split_key(key: &[u8]) -> (&[u8], &[u8]) {
+        if key.is_empty() || !key.len().is_multiple_of(2) {
+            panic!("XTS key must consist of two keys of equal length");
+        }
+        key.split_at(key.len() / 2)
+    }
+
+    // 调整值乘以 α (小端序)
+    fn mul_alpha(tweak: &mut [u8]) {
+        // 不可约多项式 x^128 + x^7 + x^2 + x + 1 或 x^64 + x^4 + x^3 + x + 1
+        let reduction = match tweak.len() {
+            16 => 0x87,
+            8 => 0x1b,
+            _ => panic!("XTS mode only supports 64-bit or 128-bit block size"),
+        };
+        let mut carry = 0u8;
+        for byte in tweak.iter_mut() {
+            let next_carry = *byte >> 7;
+            *byte = (*byte << 1) | carry;
+            carry = next_carry;
+        }
+        if carry == 1 {
+            tweak[0] ^= reduction;
+        }
+    }
+
+    // 每个分组对应的调整值
+    fn tweaks(
+        &self,
+        count: usize,
+        block_size: usize,
+        tweak_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Vec<Bytes> {
+        let mut tweak = self.tweak.to_vec();
+        tweak.resize(block_size, 0);
+        let mut tweak = tweak_encrypt(&tweak).to_vec();
+
+        let mut tweaks = Vec::with_capacity(count);
+        for _ in 0..count {
+            tweaks.push(Bytes::new(tweak.clone()));
+            Self::mul_alpha(&mut tweak);
+        }
+        tweaks
+    }
+
+    fn xts_crypt(
+        &self,
+        input: &[u8],
+        block_size: usize,
+        decrypt: bool,
+        block_crypt: impl Fn(&[u8]) -> Bytes,
+        tweak_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        if input.len() < block_size {
+            panic!("XTS input must be at least one block");
+        }
+        let crypt =
+            |block: &[u8], tweak: &Bytes| block_crypt(&Bytes::new(block).xor(tweak)).xor(tweak);
+
+        let full_blocks = input.len() / block_size;
+        let last_length = input.len() % block_size;
+        let tweaks = self.tweaks(full_blocks + 1, block_size, tweak_encrypt);
+
+        // 最后一块不完整时 倒数第二块需要参与密文窃取
+        let normal_blocks = if last_length == 0 {
+            full_blocks
+        } else {
+            full_blocks - 1
+        };
+        let mut output = Vec::with_capacity(input.len());
+        for (chunk, tweak) in input[..normal_blocks * block_size]
+            .chunks(block_size)
+            .zip(tweaks.iter())
+        {
+            output.extend_from_slice(&crypt(chunk, tweak));
+        }
+
+        if last_length != 0 {
+            let second_last = &input[normal_blocks * block_size..full_blocks * block_size];
+            let last = &input[full_blocks * block_size..];
+            // 解密时倒数第二块使用后一个调整值
+            let (first_tweak, second_tweak) = if decrypt {
+                (&tweaks[full_blocks], &tweaks[normal_blocks])
+            } else {
+                (&tweaks[normal_blocks], &tweaks[full_blocks])
+            };
+            let stolen = crypt(second_last, first_tweak);
+            let mut block = last.to_vec();
+            block.extend_from_slice(&stolen[last_length..]);
+            output.extend_from_slice(&crypt(&block, second_tweak));
+            output.extend_from_slice(&stolen[..last_length]);
+        }
+        Bytes::new(output)
+    }
+}
+
+impl Mode for Xts {
+    fn bits_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_decrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("XTS mode needs two keys, use cipher_decrypt instead")
+    }
+
+    fn bits_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[Bit]) -> Bits,
+    ) -> Bytes {
+        panic!("XTS mode needs two keys, use cipher_encrypt instead")
+    }
+
+    fn bytes_decrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_decrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("XTS mode needs two keys, use cipher_decrypt instead")
+    }
+
+    fn bytes_encrypt(
+        &self,
+        _input: &[u8],
+        _block_size: BlockSize,
+        _block_encrypt: impl Fn(&[u8]) -> Bytes,
+    ) -> Bytes {
+        panic!("XTS mode needs two keys, use cipher_encrypt instead")
+    }
+
+    fn cipher_encrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        let (data_key, tweak_key) = Self::split_key(key);
+        let data_cipher = C::build(data_key);
+        let tweak_cipher = C::build(tweak_key);
+        self.xts_crypt(
+            input,
+            data_cipher.block_size().into(),
+            false,
+            |block| data_cipher.encrypt_block(block),
+            |block| tweak_cipher.encrypt_block(block),
+        )
+    }
+
+    fn cipher_decrypt<C: BlockCipher>(&self, input: &[u8], key: &[u8]) -> Bytes {
+        let (data_key, tweak_key) = Self::split_key(key);
+        let data_cipher = C::build(data_key);
+        let tweak_cipher = C::build(tweak_key);
+        self.xts_crypt(
+            input,
+            data_cipher.block_size().into(),
+            true,
+            |block| data_cipher.decrypt_block(block),
+            |block| tweak_cipher.encrypt_block(block),
+        )
+    }
+}
@@ -5,5 +5,14 @@ use crate::operation::Operation;
 //pub trait Hashing: Operation + Default + Debug {
 pub trait Hashing: Operation + Default {
     fn block_size(&self) -> BlockSize;
-    //fn output_size(&self) -> usize;
+
+    // 默认通过计算空消息的摘要得到, 实现可覆盖
+    fn output_size(&self) -> usize {
+        self.run(&[]).map(|digest| digest.len()).unwrap_or(0)
+    }
+
+    // 算法标识符 OID 的 DER 编码 (不含标签和长度), 默认为空表示没有 OID
+    fn oid(&self) -> &'static [u8] {
+        &[]
+    }
 }
@@ -25,7 +25,7 @@ impl Padding for BitPadding {
             padded_data.push(0);
         }
         let length_bytes = match self.block_size {
-            BlockSize::Bytes8 => {
+            BlockSize::Bytes4 | BlockSize::Bytes8 => {
                 let original_len: u8 = length as u8 * 8;
                 vec![original_len]
             }
@@ -70,7 +70,7 @@ impl Padding for BitPadding {
         let mut unpadded_data = data.to_vec();
         let length = data.len();
         let original_length: usize = match self.block_size {
-            BlockSize::Bytes8 => {
+            BlockSize::Bytes4 | BlockSize::Bytes8 => {
                 let original_bits_len = unpadded_data[length - 1];
                 (original_bits_len / 8) as usize
             }
@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use crate::bytes::Bytes;
+use crate::hmac::Hmac;
+use crate::operation::{Hashing, Operation};
+use crate::types::Result;
+
+// PBKDF2 (RFC 8018), 输入口令, 输出 key_length 字节的密钥
+#[derive(Debug)]
+pub struct Pbkdf2<H: Hashing> {
+    pub salt: Bytes,
+    pub iterations: usize,
+    pub key_length: usize,
+    hash_function: PhantomData<H>,
+}
+
+impl<H: Hashing> Pbkdf2<H> {
+    pub fn new(salt: &[u8], iterations: usize, key_length: usize) -> Self {
+        Self {
+            salt: Bytes::new(salt),
+            iterations,
+            key_length,
+            hash_function: PhantomData,
+        }
+    }
+}
+
+impl<H: Hashing> Operation for Pbkdf2<H> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.iterations == 0 {
+            return Err("[Pbkdf2] iteration count must be positive".into());
+        }
+        let prf = Hmac::<H>::new(input);
+        let mut output = Vec::with_capacity(self.key_length);
+        let mut index = 1u32;
+        // T_i = U_1 ^ U_2 ^ ... ^ U_c, U_1 = PRF(P, S || INT(i)), U_j = PRF(P, U_{j-1})
+        while output.len() < self.key_length {
+            let mut u = prf.run(&[&self.salt[..], &index.to_be_bytes()].concat())?;
+            let mut block = u.clone();
+            for _ in 1..self.iterations {
+                u = prf.run(&u)?;
+                block = block.xor(&u);
+            }
+            output.extend_from_slice(&block);
+            index = index
+                .checked_add(1)
+                .ok_or("[Pbkdf2] derived key is too long")?;
+        }
+        output.truncate(self.key_length);
+        Ok(Bytes::new(output))
+    }
+}
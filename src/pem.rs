@@ -0,0 +1,86 @@
+use crate::base64::{alphabet, FromBase64, ToBase64};
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::types::Result;
+
+mod from_pem;
+mod to_pem;
+
+pub use from_pem::FromPem;
+pub use to_pem::ToPem;
+
+// PEM 块 (RFC 7468): -----BEGIN label----- 与 -----END label----- 之间的 Base64 文本
+#[derive(Clone, Debug)]
+pub struct Pem {
+    pub label: String,
+    pub contents: Bytes,
+}
+
+impl Pem {
+    pub fn new(label: &str, contents: &[u8]) -> Self {
+        Self {
+            label: label.to_string(),
+            contents: Bytes::new(contents),
+        }
+    }
+
+    // 文本中的第一个 PEM 块
+    pub fn parse(text: &str) -> Result<Self> {
+        Self::parse_all(text)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| "[Pem] no PEM block found".into())
+    }
+
+    // 文本中的所有 PEM 块, 忽略块之外的内容
+    pub fn parse_all(text: &str) -> Result<Vec<Self>> {
+        let mut blocks = vec![];
+        let mut lines = text.lines().map(str::trim);
+        while let Some(line) = lines.next() {
+            let label = match line
+                .strip_prefix("-----BEGIN ")
+                .and_then(|rest| rest.strip_suffix("-----"))
+            {
+                Some(label) => label,
+                None => continue,
+            };
+            let end = format!("-----END {}-----", label);
+            let mut body = String::new();
+            loop {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| format!("[Pem] missing end line for {}", label))?;
+                if line == end {
+                    break;
+                }
+                // RFC 1421 的加密头部
+                if line.starts_with("Proc-Type:") && line.contains("ENCRYPTED") {
+                    return Err("[Pem] legacy encrypted PEM is not supported".into());
+                }
+                if !line.contains(':') {
+                    body.push_str(line);
+                }
+            }
+            let contents = FromBase64::new(alphabet::STANDARD, true)
+                .run(body.as_bytes())
+                .map_err(|e| format!("[Pem] invalid base64 in {}: {}", label, e))?;
+            blocks.push(Self {
+                label: label.to_string(),
+                contents,
+            });
+        }
+        Ok(blocks)
+    }
+
+    // 每行 64 个字符
+    pub fn encode(&self) -> Result<String> {
+        let base64 = ToBase64::new(alphabet::STANDARD).run(&self.contents)?;
+        let mut text = format!("-----BEGIN {}-----\n", self.label);
+        for line in base64.chunks(64) {
+            text.push_str(std::str::from_utf8(line)?);
+            text.push('\n');
+        }
+        text.push_str(&format!("-----END {}-----\n", self.label));
+        Ok(text)
+    }
+}
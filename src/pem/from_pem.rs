@@ -0,0 +1,29 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::pem::Pem;
+use crate::types::Result;
+
+// 输入 PEM 文本, 输出第一个标签匹配的块的 DER 内容, label 为空时不限标签
+#[derive(Debug, Default)]
+pub struct FromPem {
+    pub label: String,
+}
+
+impl FromPem {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+        }
+    }
+}
+
+impl Operation for FromPem {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let text = std::str::from_utf8(input)?;
+        Pem::parse_all(text)?
+            .into_iter()
+            .find(|pem| self.label.is_empty() || pem.label == self.label)
+            .map(|pem| pem.contents)
+            .ok_or_else(|| format!("[FromPem] no PEM block labelled {:?}", self.label).into())
+    }
+}
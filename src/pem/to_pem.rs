@@ -0,0 +1,25 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::pem::Pem;
+use crate::types::Result;
+
+// 输入 DER 数据, 输出 PEM 文本, 如 label 为 "PUBLIC KEY"
+#[derive(Debug, Default)]
+pub struct ToPem {
+    pub label: String,
+}
+
+impl ToPem {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+        }
+    }
+}
+
+impl Operation for ToPem {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let text = Pem::new(&self.label, input).encode()?;
+        Ok(Bytes::new(text.as_bytes()))
+    }
+}
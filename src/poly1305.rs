@@ -0,0 +1,118 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// 一次性认证器 密钥为32字节 (r || s)
+#[derive(Debug)]
+pub struct Poly1305 {
+    pub key: Bytes,
+}
+
+impl Poly1305 {
+    pub fn new(key: &[u8]) -> Self {
+        Self {
+            key: Bytes::new(key),
+        }
+    }
+}
+
+// 26位一组 模 2^130 - 5 运算
+pub(crate) fn poly1305_mac(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let le32 = |bytes: &[u8]| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+    // r 需要 clamp
+    let r0 = le32(&key[0..4]) & 0x3ffffff;
+    let r1 = (le32(&key[3..7]) >> 2) & 0x3ffff03;
+    let r2 = (le32(&key[6..10]) >> 4) & 0x3ffc0ff;
+    let r3 = (le32(&key[9..13]) >> 6) & 0x3f03fff;
+    let r4 = (le32(&key[12..16]) >> 8) & 0x00fffff;
+    let (s1, s2, s3, s4) = (r1 * 5, r2 * 5, r3 * 5, r4 * 5);
+
+    let mut h = [0u32; 5];
+    for chunk in message.chunks(16) {
+        // 每块末尾添加 0x01
+        let mut block = [0u8; 17];
+        block[..chunk.len()].copy_from_slice(chunk);
+        block[chunk.len()] = 1;
+
+        h[0] += le32(&block[0..4]) & 0x3ffffff;
+        h[1] += (le32(&block[3..7]) >> 2) & 0x3ffffff;
+        h[2] += (le32(&block[6..10]) >> 4) & 0x3ffffff;
+        h[3] += (le32(&block[9..13]) >> 6) & 0x3ffffff;
+        h[4] += (le32(&block[12..16]) >> 8) | ((block[16] as u32) << 24);
+
+        // h = h * r
+        let m = |a: u32, b: u32| a as u64 * b as u64;
+        let d0 = m(h[0], r0) + m(h[1], s4) + m(h[2], s3) + m(h[3], s2) + m(h[4], s1);
+        let mut d1 = m(h[0], r1) + m(h[1], r0) + m(h[2], s4) + m(h[3], s3) + m(h[4], s2);
+        let mut d2 = m(h[0], r2) + m(h[1], r1) + m(h[2], r0) + m(h[3], s4) + m(h[4], s3);
+        let mut d3 = m(h[0], r3) + m(h[1], r2) + m(h[2], r1) + m(h[3], r0) + m(h[4], s4);
+        let mut d4 = m(h[0], r4) + m(h[1], r3) + m(h[2], r2) + m(h[3], r1) + m(h[4], r0);
+
+        // 部分约减
+        let mut c = d0 >> 26;
+        h[0] = d0 as u32 & 0x3ffffff;
+        d1 += c;
+        c = d1 >> 26;
+        h[1] = d1 as u32 & 0x3ffffff;
+        d2 += c;
+        c = d2 >> 26;
+        h[2] = d2 as u32 & 0x3ffffff;
+        d3 += c;
+        c = d3 >> 26;
+        h[3] = d3 as u32 & 0x3ffffff;
+        d4 += c;
+        c = d4 >> 26;
+        h[4] = d4 as u32 & 0x3ffffff;
+        h[0] += c as u32 * 5;
+        h[1] += h[0] >> 26;
+        h[0] &= 0x3ffffff;
+    }
+
+    // 完全约减
+    let mut c = h[1] >> 26;
+    h[1] &= 0x3ffffff;
+    for limb in h.iter_mut().skip(2) {
+        *limb += c;
+        c = *limb >> 26;
+        *limb &= 0x3ffffff;
+    }
+    h[0] += c * 5;
+    c = h[0] >> 26;
+    h[0] &= 0x3ffffff;
+    h[1] += c;
+
+    // g = h + 5 - 2^130 若不小于0 则取 g
+    let mut g = [0u32; 5];
+    g[0] = h[0].wrapping_add(5);
+    c = g[0] >> 26;
+    g[0] &= 0x3ffffff;
+    for (g, h) in g.iter_mut().zip(h).skip(1) {
+        *g = h.wrapping_add(c);
+        c = *g >> 26;
+        *g &= 0x3ffffff;
+    }
+    g[4] = g[4].wrapping_sub(1 << 26);
+    let mask = (g[4] >> 31).wrapping_sub(1);
+    for (h, g) in h.iter_mut().zip(g) {
+        *h = (*h & !mask) | (g & mask);
+    }
+
+    // 转为128位 再加上 s
+    let h = (h[0] as u128)
+        | (h[1] as u128) << 26
+        | (h[2] as u128) << 52
+        | (h[3] as u128) << 78
+        | (h[4] as u128) << 104;
+    let s = u128::from_le_bytes(key[16..32].try_into().unwrap());
+    h.wrapping_add(s).to_le_bytes().to_vec()
+}
+
+impl Operation for Poly1305 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() != 32 {
+            return Err("[Poly1305] key length must be 32 bytes".into());
+        }
+        Ok(Bytes::new(poly1305_mac(&self.key, input)))
+    }
+}
@@ -0,0 +1,13 @@
+use std::fs::File;
+use std::io::Read;
+
+use crate::types::Result;
+
+// 从操作系统获取密码学安全的随机数
+pub fn random_bytes(len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0u8; len];
+    File::open("/dev/urandom")
+        .and_then(|mut file| file.read_exact(&mut bytes))
+        .map_err(|e| format!("[Random] failed to read /dev/urandom: {}", e))?;
+    Ok(bytes)
+}
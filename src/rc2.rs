@@ -0,0 +1,153 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod rc2_decrypt;
+mod rc2_encrypt;
+
+pub use rc2_decrypt::Rc2Decrypt;
+pub use rc2_encrypt::Rc2Encrypt;
+
+// RFC 2268
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes8;
+
+// 圆周率的小数部分生成的置换表
+const PI_TABLE: [u8; 256] = [
+    0xd9, 0x78, 0xf9, 0xc4, 0x19, 0xdd, 0xb5, 0xed, 0x28, 0xe9, 0xfd, 0x79, 0x4a, 0xa0, 0xd8, 0x9d,
+    0xc6, 0x7e, 0x37, 0x83, 0x2b, 0x76, 0x53, 0x8e, 0x62, 0x4c, 0x64, 0x88, 0x44, 0x8b, 0xfb, 0xa2,
+    0x17, 0x9a, 0x59, 0xf5, 0x87, 0xb3, 0x4f, 0x13, 0x61, 0x45, 0x6d, 0x8d, 0x09, 0x81, 0x7d, 0x32,
+    0xbd, 0x8f, 0x40, 0xeb, 0x86, 0xb7, 0x7b, 0x0b, 0xf0, 0x95, 0x21, 0x22, 0x5c, 0x6b, 0x4e, 0x82,
+    0x54, 0xd6, 0x65, 0x93, 0xce, 0x60, 0xb2, 0x1c, 0x73, 0x56, 0xc0, 0x14, 0xa7, 0x8c, 0xf1, 0xdc,
+    0x12, 0x75, 0xca, 0x1f, 0x3b, 0xbe, 0xe4, 0xd1, 0x42, 0x3d, 0xd4, 0x30, 0xa3, 0x3c, 0xb6, 0x26,
+    0x6f, 0xbf, 0x0e, 0xda, 0x46, 0x69, 0x07, 0x57, 0x27, 0xf2, 0x1d, 0x9b, 0xbc, 0x94, 0x43, 0x03,
+    0xf8, 0x11, 0xc7, 0xf6, 0x90, 0xef, 0x3e, 0xe7, 0x06, 0xc3, 0xd5, 0x2f, 0xc8, 0x66, 0x1e, 0xd7,
+    0x08, 0xe8, 0xea, 0xde, 0x80, 0x52, 0xee, 0xf7, 0x84, 0xaa, 0x72, 0xac, 0x35, 0x4d, 0x6a, 0x2a,
+    0x96, 0x1a, 0xd2, 0x71, 0x5a, 0x15, 0x49, 0x74, 0x4b, 0x9f, 0xd0, 0x5e, 0x04, 0x18, 0xa4, 0xec,
+    0xc2, 0xe0, 0x41, 0x6e, 0x0f, 0x51, 0xcb, 0xcc, 0x24, 0x91, 0xaf, 0x50, 0xa1, 0xf4, 0x70, 0x39,
+    0x99, 0x7c, 0x3a, 0x85, 0x23, 0xb8, 0xb4, 0x7a, 0xfc, 0x02, 0x36, 0x5b, 0x25, 0x55, 0x97, 0x31,
+    0x2d, 0x5d, 0xfa, 0x98, 0xe3, 0x8a, 0x92, 0xae, 0x05, 0xdf, 0x29, 0x10, 0x67, 0x6c, 0xba, 0xc9,
+    0xd3, 0x00, 0xe6, 0xcf, 0xe1, 0x9e, 0xa8, 0x2c, 0x63, 0x16, 0x01, 0x3f, 0x58, 0xe2, 0x89, 0xa9,
+    0x0d, 0x38, 0x34, 0x1b, 0xab, 0x33, 0xff, 0xb0, 0xbb, 0x48, 0x0c, 0x5f, 0xb9, 0xb1, 0xcd, 0x2e,
+    0xc5, 0xf3, 0xdb, 0x47, 0xe5, 0xa5, 0x9c, 0x77, 0x0a, 0xa6, 0x20, 0x68, 0xfe, 0x7f, 0xc1, 0xad,
+];
+
+// 混合轮中每个字的循环移位量
+const SHIFTS: [u32; 4] = [1, 2, 3, 5];
+
+#[derive(Debug)]
+pub struct Rc2 {
+    keys: [u16; 64],
+}
+
+impl Rc2 {
+    // 有效密钥长度(位) 为 1..=1024, 常见实现默认与密钥长度相同
+    pub fn with_effective_bits(key: &[u8], effective_bits: usize) -> Self {
+        // 密钥长度为 1..=128 字节
+        let key = &key[..key.len().min(128)];
+        let t = key.len().max(1);
+        let effective_bits = effective_bits.clamp(1, 1024);
+
+        let mut l = [0u8; 128];
+        l[..key.len()].copy_from_slice(key);
+        for i in t..128 {
+            l[i] = PI_TABLE[l[i - 1].wrapping_add(l[i - t]) as usize];
+        }
+        let t8 = effective_bits.div_ceil(8);
+        let tm = 0xffu8 >> (8 * t8 - effective_bits);
+        l[128 - t8] = PI_TABLE[(l[128 - t8] & tm) as usize];
+        for i in (0..128 - t8).rev() {
+            l[i] = PI_TABLE[(l[i + 1] ^ l[i + t8]) as usize];
+        }
+
+        let mut keys = [0u16; 64];
+        for (k, chunk) in keys.iter_mut().zip(l.chunks(2)) {
+            *k = u16::from_le_bytes([chunk[0], chunk[1]]);
+        }
+        Self { keys }
+    }
+
+    fn mix(&self, r: &mut [u16; 4], j: &mut usize) {
+        for i in 0..4 {
+            let value = r[i]
+                .wrapping_add(self.keys[*j])
+                .wrapping_add(r[(i + 3) % 4] & r[(i + 2) % 4])
+                .wrapping_add(!r[(i + 3) % 4] & r[(i + 1) % 4]);
+            r[i] = value.rotate_left(SHIFTS[i]);
+            *j += 1;
+        }
+    }
+
+    fn mash(&self, r: &mut [u16; 4]) {
+        for i in 0..4 {
+            r[i] = r[i].wrapping_add(self.keys[(r[(i + 3) % 4] & 63) as usize]);
+        }
+    }
+
+    fn r_mix(&self, r: &mut [u16; 4], j: &mut usize) {
+        for i in (0..4).rev() {
+            *j -= 1;
+            r[i] = r[i]
+                .rotate_right(SHIFTS[i])
+                .wrapping_sub(self.keys[*j])
+                .wrapping_sub(r[(i + 3) % 4] & r[(i + 2) % 4])
+                .wrapping_sub(!r[(i + 3) % 4] & r[(i + 1) % 4]);
+        }
+    }
+
+    fn r_mash(&self, r: &mut [u16; 4]) {
+        for i in (0..4).rev() {
+            r[i] = r[i].wrapping_sub(self.keys[(r[(i + 3) % 4] & 63) as usize]);
+        }
+    }
+}
+
+fn to_words(block: &[u8]) -> [u16; 4] {
+    let mut r = [0u16; 4];
+    for (word, chunk) in r.iter_mut().zip(block.chunks(2)) {
+        *word = u16::from_le_bytes([chunk[0], chunk[1]]);
+    }
+    r
+}
+
+fn from_words(r: [u16; 4]) -> Bytes {
+    Bytes::new(
+        r.iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<u8>>(),
+    )
+}
+
+impl BlockCipher for Rc2 {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    // 5轮混合, 1轮糅合, 6轮混合, 1轮糅合, 5轮混合
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut r = to_words(block);
+        let mut j = 0;
+        for round in 0..16 {
+            if round == 5 || round == 11 {
+                self.mash(&mut r);
+            }
+            self.mix(&mut r, &mut j);
+        }
+        from_words(r)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut r = to_words(block);
+        let mut j = 64;
+        for round in 0..16 {
+            if round == 5 || round == 11 {
+                self.r_mash(&mut r);
+            }
+            self.r_mix(&mut r, &mut j);
+        }
+        from_words(r)
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_effective_bits(key, key.len() * 8)
+    }
+}
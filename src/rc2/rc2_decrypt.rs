@@ -0,0 +1,47 @@
+use crate::bytes::Bytes;
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::rc2::{Rc2, BLOCK_SIZE};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct Rc2Decrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+    pub effective_bits: Option<usize>, // 有效密钥长度(位)
+}
+
+impl<M: Mode, P: Padding> Rc2Decrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            effective_bits: None,
+        }
+    }
+
+    pub fn with_effective_bits(key: &[u8], mode: M, effective_bits: usize) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            effective_bits: Some(effective_bits),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for Rc2Decrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = match self.effective_bits {
+            Some(effective_bits) => self
+                .mode
+                .block_cipher_decrypt(input, &Rc2::with_effective_bits(&self.key, effective_bits)),
+            None => self.mode.cipher_decrypt::<Rc2>(input, &self.key),
+        };
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
@@ -0,0 +1,201 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+use crate::types::Result;
+
+mod rc5_decrypt;
+mod rc5_encrypt;
+
+pub use rc5_decrypt::Rc5Decrypt;
+pub use rc5_encrypt::Rc5Encrypt;
+
+// RC5-32/12/b
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes8;
+
+// 字长与轮数可配置 分组长度为两个字
+#[derive(Clone, Copy, Debug)]
+pub struct Rc5Params {
+    pub word_size: usize, // 16, 32 或 64 位
+    pub rounds: usize,
+}
+
+impl Rc5Params {
+    pub fn new(word_size: usize, rounds: usize) -> Self {
+        Self { word_size, rounds }
+    }
+
+    pub fn block_size(&self) -> Result<BlockSize> {
+        match self.word_size {
+            16 => Ok(BlockSize::Bytes4),
+            32 => Ok(BlockSize::Bytes8),
+            64 => Ok(BlockSize::Bytes16),
+            _ => Err("[Rc5] word size must be 16, 32 or 64".into()),
+        }
+    }
+}
+
+impl Default for Rc5Params {
+    fn default() -> Self {
+        Self::new(32, 12)
+    }
+}
+
+// 以 u64 存放 w 位的字, 运算后截断到 w 位
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Word {
+    pub bits: u32,
+    mask: u64,
+}
+
+impl Word {
+    // 字长需为 16, 32 或 64
+    pub fn new(bits: usize) -> Self {
+        let bits = bits as u32;
+        Self {
+            bits,
+            mask: u64::MAX >> (64 - bits),
+        }
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bits as usize / 8
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        a.wrapping_add(b) & self.mask
+    }
+
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        a.wrapping_sub(b) & self.mask
+    }
+
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        a.wrapping_mul(b) & self.mask
+    }
+
+    // 循环移位量取低 lg(w) 位
+    pub fn rotl(&self, x: u64, s: u64) -> u64 {
+        let s = (s % self.bits as u64) as u32;
+        if s == 0 {
+            return x;
+        }
+        ((x << s) | (x >> (self.bits - s))) & self.mask
+    }
+
+    pub fn rotr(&self, x: u64, s: u64) -> u64 {
+        let s = s % self.bits as u64;
+        self.rotl(x, (self.bits as u64 - s) % self.bits as u64)
+    }
+
+    // 魔数 P = Odd((e - 2) * 2^w), Q = Odd((φ - 1) * 2^w)
+    fn magic(&self) -> (u64, u64) {
+        match self.bits {
+            16 => (0xb7e1, 0x9e37),
+            32 => (0xb7e15163, 0x9e3779b9),
+            _ => (0xb7e151628aed2a6b, 0x9e3779b97f4a7c15),
+        }
+    }
+
+    // 小端序读入字
+    pub fn load(&self, bytes: &[u8]) -> Vec<u64> {
+        bytes
+            .chunks(self.bytes())
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0u64, |word, &byte| (word << 8) | byte as u64)
+            })
+            .collect()
+    }
+
+    pub fn store(&self, words: &[u64]) -> Bytes {
+        Bytes::new(
+            words
+                .iter()
+                .flat_map(|word| word.to_le_bytes()[..self.bytes()].to_vec())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    // RC5 与 RC6 共用的密钥扩展 生成 count 个子密钥
+    pub fn expand_key(&self, key: &[u8], count: usize) -> Vec<u64> {
+        let c = key.len().div_ceil(self.bytes()).max(1);
+        let mut l = self.load(key);
+        l.resize(c, 0);
+
+        let (p, q) = self.magic();
+        let mut s = vec![p; count];
+        for i in 1..count {
+            s[i] = self.add(s[i - 1], q);
+        }
+
+        let (mut a, mut b) = (0u64, 0u64);
+        let (mut i, mut j) = (0, 0);
+        for _ in 0..3 * count.max(c) {
+            a = self.rotl(self.add(s[i], self.add(a, b)), 3);
+            s[i] = a;
+            b = self.rotl(self.add(l[j], self.add(a, b)), self.add(a, b));
+            l[j] = b;
+            i = (i + 1) % count;
+            j = (j + 1) % c;
+        }
+        s
+    }
+}
+
+#[derive(Debug)]
+pub struct Rc5 {
+    word: Word,
+    block_size: BlockSize,
+    rounds: usize,
+    keys: Vec<u64>,
+}
+
+impl Rc5 {
+    pub fn with_params(key: &[u8], params: Rc5Params) -> Result<Self> {
+        let block_size = params.block_size()?;
+        let word = Word::new(params.word_size);
+        Ok(Self {
+            word,
+            block_size,
+            rounds: params.rounds,
+            keys: word.expand_key(key, 2 * params.rounds + 2),
+        })
+    }
+}
+
+impl BlockCipher for Rc5 {
+    fn block_size(&self) -> BlockSize {
+        self.block_size
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let w = &self.word;
+        let s = &self.keys;
+        let v = w.load(block);
+        let mut a = w.add(v[0], s[0]);
+        let mut b = w.add(v[1], s[1]);
+        for i in 1..=self.rounds {
+            a = w.add(w.rotl(a ^ b, b), s[2 * i]);
+            b = w.add(w.rotl(b ^ a, a), s[2 * i + 1]);
+        }
+        w.store(&[a, b])
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let w = &self.word;
+        let s = &self.keys;
+        let v = w.load(block);
+        let (mut a, mut b) = (v[0], v[1]);
+        for i in (1..=self.rounds).rev() {
+            b = w.rotr(w.sub(b, s[2 * i + 1]), a) ^ a;
+            a = w.rotr(w.sub(a, s[2 * i]), b) ^ b;
+        }
+        w.store(&[w.sub(a, s[0]), w.sub(b, s[1])])
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_params(key, Rc5Params::default()).unwrap()
+    }
+}
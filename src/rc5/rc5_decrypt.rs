@@ -0,0 +1,48 @@
+use crate::bytes::Bytes;
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::rc5::{Rc5, Rc5Params, BLOCK_SIZE};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct Rc5Decrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+    pub params: Option<Rc5Params>, // 自定义字长与轮数
+}
+
+impl<M: Mode, P: Padding> Rc5Decrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            params: None,
+        }
+    }
+
+    pub fn with_params(key: &[u8], mode: M, params: Rc5Params) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            // 字长不合法时在 run 中返回错误
+            padding: P::build(params.block_size().unwrap_or(BLOCK_SIZE)),
+            params: Some(params),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for Rc5Decrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let result = match self.params {
+            Some(params) => self
+                .mode
+                .block_cipher_decrypt(input, &Rc5::with_params(&self.key, params)?),
+            None => self.mode.cipher_decrypt::<Rc5>(input, &self.key),
+        };
+
+        Ok(Bytes::new(self.padding.unpad(&result)))
+    }
+}
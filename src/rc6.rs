@@ -0,0 +1,116 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+use crate::rc5::Word;
+use crate::types::Result;
+
+mod rc6_decrypt;
+mod rc6_encrypt;
+
+pub use rc6_decrypt::Rc6Decrypt;
+pub use rc6_encrypt::Rc6Encrypt;
+
+// RC6-32/20/b (AES 候选算法)
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes16;
+
+// 字长与轮数可配置 分组长度为四个字
+#[derive(Clone, Copy, Debug)]
+pub struct Rc6Params {
+    pub word_size: usize, // 16, 32 或 64 位
+    pub rounds: usize,
+}
+
+impl Rc6Params {
+    pub fn new(word_size: usize, rounds: usize) -> Self {
+        Self { word_size, rounds }
+    }
+
+    pub fn block_size(&self) -> Result<BlockSize> {
+        match self.word_size {
+            16 => Ok(BlockSize::Bytes8),
+            32 => Ok(BlockSize::Bytes16),
+            64 => Ok(BlockSize::Bytes32),
+            _ => Err("[Rc6] word size must be 16, 32 or 64".into()),
+        }
+    }
+}
+
+impl Default for Rc6Params {
+    fn default() -> Self {
+        Self::new(32, 20)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rc6 {
+    word: Word,
+    block_size: BlockSize,
+    rounds: usize,
+    keys: Vec<u64>,
+}
+
+impl Rc6 {
+    pub fn with_params(key: &[u8], params: Rc6Params) -> Result<Self> {
+        let block_size = params.block_size()?;
+        let word = Word::new(params.word_size);
+        Ok(Self {
+            word,
+            block_size,
+            rounds: params.rounds,
+            keys: word.expand_key(key, 2 * params.rounds + 4),
+        })
+    }
+
+    // x * (2x + 1) 后循环左移 lg(w) 位
+    fn quadratic(&self, x: u64) -> u64 {
+        let w = &self.word;
+        w.rotl(w.mul(x, w.add(x, x) | 1), w.bits.trailing_zeros() as u64)
+    }
+}
+
+impl BlockCipher for Rc6 {
+    fn block_size(&self) -> BlockSize {
+        self.block_size
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let w = &self.word;
+        let s = &self.keys;
+        let v = w.load(block);
+        let (mut a, mut b, mut c, mut d) = (v[0], w.add(v[1], s[0]), v[2], w.add(v[3], s[1]));
+        for i in 1..=self.rounds {
+            let t = self.quadratic(b);
+            let u = self.quadratic(d);
+            a = w.add(w.rotl(a ^ t, u), s[2 * i]);
+            c = w.add(w.rotl(c ^ u, t), s[2 * i + 1]);
+            (a, b, c, d) = (b, c, d, a);
+        }
+        let r = self.rounds;
+        w.store(&[w.add(a, s[2 * r + 2]), b, w.add(c, s[2 * r + 3]), d])
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let w = &self.word;
+        let s = &self.keys;
+        let r = self.rounds;
+        let v = w.load(block);
+        let (mut a, mut b, mut c, mut d) = (
+            w.sub(v[0], s[2 * r + 2]),
+            v[1],
+            w.sub(v[2], s[2 * r + 3]),
+            v[3],
+        );
+        for i in (1..=r).rev() {
+            (a, b, c, d) = (d, a, b, c);
+            let u = self.quadratic(d);
+            let t = self.quadratic(b);
+            c = w.rotr(w.sub(c, s[2 * i + 1]), t) ^ u;
+            a = w.rotr(w.sub(a, s[2 * i]), u) ^ t;
+        }
+        w.store(&[a, w.sub(b, s[0]), c, w.sub(d, s[1])])
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_params(key, Rc6Params::default()).unwrap()
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Debug;
+
+use crate::bigint::BigUint;
+use crate::enums::Endian;
+use crate::operation::Hashing;
+use crate::types::Result;
+
+mod oaep;
+mod pkcs1v15;
+mod pss;
+mod raw;
+mod rsa_attack;
+mod rsa_decrypt;
+mod rsa_encrypt;
+mod rsa_sign;
+mod rsa_verify;
+
+pub use oaep::Oaep;
+pub use pkcs1v15::{Pkcs1v15Encryption, Pkcs1v15Signature};
+pub use pss::Pss;
+pub use raw::RsaRaw;
+pub use rsa_attack::{
+    common_modulus_attack, fermat_attack, hastad_attack, pollard_p_minus_one_attack,
+    pollard_rho_attack, private_exponent_attack, small_exponent_attack, wiener_attack, RsaAttack,
+    RsaAttackMethod, RsaAttackReport,
+};
+pub use rsa_decrypt::RsaDecrypt;
+pub use rsa_encrypt::RsaEncrypt;
+pub use rsa_sign::RsaSign;
+pub use rsa_verify::RsaVerify;
+
+pub const DEFAULT_EXPONENT: u64 = 65537;
+
+// 加密填充方案, k 为模数的字节长度
+pub trait RsaEncryptionScheme: Debug {
+    fn pad(&self, message: &[u8], k: usize) -> Result<Vec<u8>>;
+    fn unpad(&self, encoded: &[u8], k: usize) -> Result<Vec<u8>>;
+}
+
+// 签名编码方案, 编码结果为 k 字节, mod_bits 为模数的比特长度
+pub trait RsaSignatureScheme: Debug {
+    fn encode(&self, message: &[u8], mod_bits: usize) -> Result<Vec<u8>>;
+    fn verify(&self, message: &[u8], encoded: &[u8], mod_bits: usize) -> Result<bool>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    n: BigUint,
+    e: BigUint,
+}
+
+impl RsaPublicKey {
+    pub fn new(n: BigUint, e: BigUint) -> Result<Self> {
+        if n.bits() < 8 || n.is_even() {
+            return Err("[Rsa] modulus must be an odd integer of at least 8 bits".into());
+        }
+        if e < BigUint::from(2u64) || e >= n {
+            return Err("[Rsa] public exponent out of range".into());
+        }
+        Ok(Self { n, e })
+    }
+
+    // 大端序字节
+    pub fn from_bytes(n: &[u8], e: &[u8]) -> Result<Self> {
+        Self::new(
+            BigUint::from_bytes(n, Endian::Big),
+            BigUint::from_bytes(e, Endian::Big),
+        )
+    }
+
+    pub fn n(&self) -> &BigUint {
+        &self.n
+    }
+
+    pub fn e(&self) -> &BigUint {
+        &self.e
+    }
+
+    // 模数的字节长度
+    pub fn size(&self) -> usize {
+        self.n.bits().div_ceil(8)
+    }
+
+    // RSAEP / RSAVP1: m^e mod n
+    pub fn encrypt_raw(&self, m: &BigUint) -> Result<BigUint> {
+        if *m >= self.n {
+            return Err("[Rsa] message representative out of range".into());
+        }
+        Ok(m.mod_pow(&self.e, &self.n))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    public_key: RsaPublicKey,
+    d: BigUint,
+    p: BigUint,
+    q: BigUint,
+    // 中国剩余定理参数
+    dp: BigUint,
+    dq: BigUint,
+    qinv: BigUint,
+}
+
+impl RsaPrivateKey {
+    // 由 (n, e, d) 分解出 p 和 q
+    pub fn new(n: BigUint, e: BigUint, d: BigUint) -> Result<Self> {
+        let public_key = RsaPublicKey::new(n, e)?;
+        let (p, q) = recover_primes(&public_key.n, &public_key.e, &d)?;
+        Self::from_parts(public_key, d, p, q)
+    }
+
+    // 大端序字节
+    pub fn from_bytes(n: &[u8], e: &[u8], d: &[u8]) -> Result<Self> {
+        Self::new(
+            BigUint::from_bytes(n, Endian::Big),
+            BigUint::from_bytes(e, Endian::Big),
+            BigUint::from_bytes(d, Endian::Big),
+        )
+    }
+
+    // d = e^-1 mod lcm(p - 1, q - 1)
+    pub fn from_primes(p: BigUint, q: BigUint, e: BigUint) -> Result<Self> {
+        if p == q {
+            return Err("[Rsa] primes must be distinct".into());
+        }
+        let one = BigUint::one();
+        let lambda = (&p - &one).lcm(&(&q - &one));
+        let d = e
+            .mod_inverse(&lambda)
+            .ok_or("[Rsa] public exponent is not invertible")?;
+        let public_key = RsaPublicKey::new(&p * &q, e)?;
+        Self::from_parts(public_key, d, p, q)
+    }
+
+    pub fn generate(bits: usize) -> Result<Self> {
+        Self::generate_with_exponent(bits, BigUint::from(DEFAULT_EXPONENT))
+    }
+
+    // 两个素数最高两位均为 1, 模数恰好 bits 比特
+    pub fn generate_with_exponent(bits: usize, e: BigUint) -> Result<Self> {
+        if bits < 16 {
+            return Err("[Rsa] modulus must be at least 16 bits".into());
+        }
+        if e.is_even() || e < BigUint::from(3u64) {
+            return Err("[Rsa] public exponent must be odd and at least 3".into());
+        }
+        if e.bits() >= bits {
+            return Err("[Rsa] public exponent too large for modulus size".into());
+        }
+        let one = BigUint::one();
+        let random_prime = |bits| loop {
+            let prime = BigUint::random_prime(bits)?;
+            if (&prime - &one).gcd(&e).is_one() {
+                return Ok::<_, crate::types::Error>(prime);
+            }
+        };
+        loop {
+            let p = random_prime(bits - bits / 2)?;
+            let q = random_prime(bits / 2)?;
+            if p == q || (&p * &q).bits() != bits {
+                continue;
+            }
+            return Self::from_primes(p, q, e);
+        }
+    }
+
+    pub(crate) fn from_parts(
+        public_key: RsaPublicKey,
+        d: BigUint,
+        p: BigUint,
+        q: BigUint,
+    ) -> Result<Self> {
+        let one = BigUint::one();
+        let (p, q) = if p > q { (p, q) } else { (q, p) };
+        let dp = &d % &(&p - &one);
+        let dq = &d % &(&q - &one);
+        let qinv = q.mod_inverse(&p).ok_or("[Rsa] primes must be coprime")?;
+        Ok(Self {
+            public_key,
+            d,
+            p,
+            q,
+            dp,
+            dq,
+            qinv,
+        })
+    }
+
+    pub fn public_key(&self) -> &RsaPublicKey {
+        &self.public_key
+    }
+
+    pub fn n(&self) -> &BigUint {
+        &self.public_key.n
+    }
+
+    pub fn e(&self) -> &BigUint {
+        &self.public_key.e
+    }
+
+    pub fn d(&self) -> &BigUint {
+        &self.d
+    }
+
+    // p > q
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn q(&self) -> &BigUint {
+        &self.q
+    }
+
+    pub fn dp(&self) -> &BigUint {
+        &self.dp
+    }
+
+    pub fn dq(&self) -> &BigUint {
+        &self.dq
+    }
+
+    pub fn qinv(&self) -> &BigUint {
+        &self.qinv
+    }
+
+    pub fn size(&self) -> usize {
+        self.public_key.size()
+    }
+
+    // RSADP / RSASP1: c^d mod n, 使用中国剩余定理
+    pub fn decrypt_raw(&self, c: &BigUint) -> Result<BigUint> {
+        if *c >= self.public_key.n {
+            return Err("[Rsa] ciphertext representative out of range".into());
+        }
+        let m1 = c.mod_pow(&self.dp, &self.p);
+        let m2 = c.mod_pow(&self.dq, &self.q);
+        // h = qinv * (m1 - m2) mod p
+        let difference = &(&m1 + &self.p) - &(&m2 % &self.p);
+        let h = &(&self.qinv * &difference) % &self.p;
+        Ok(&m2 + &(&h * &self.q))
+    }
+}
+
+// 已知私钥指数时分解模数 (NIST SP 800-56B 附录 C)
+pub(crate) fn recover_primes(n: &BigUint, e: &BigUint, d: &BigUint) -> Result<(BigUint, BigUint)> {
+    let one = BigUint::one();
+    // k = d * e - 1 是 λ(n) 的倍数
+    let k = (d * e).checked_sub(&one).unwrap_or_default();
+    if k.is_zero() || k.is_odd() {
+        return Err("[Rsa] invalid private exponent".into());
+    }
+    let t = k.trailing_zeros().unwrap();
+    let r = &k >> t;
+    let n_minus_one = n - &one;
+
+    for g in (2u64..).take(100) {
+        let mut y = BigUint::from(g).mod_pow(&r, n);
+        if y.is_one() || y == n_minus_one {
+            continue;
+        }
+        for _ in 0..t {
+            let x = &(&y * &y) % n;
+            // 找到 1 的非平凡平方根
+            if x.is_one() {
+                let p = (&y - &one).gcd(n);
+                let q = n / &p;
+                return Ok((p, q));
+            }
+            if x == n_minus_one {
+                break;
+            }
+            y = x;
+        }
+    }
+    Err("[Rsa] failed to factor modulus from private exponent".into())
+}
+
+// PKCS#1 的掩码生成函数 MGF1
+pub(crate) fn mgf1<H: Hashing>(seed: &[u8], length: usize) -> Result<Vec<u8>> {
+    let hash_function = H::default();
+    let mut mask = Vec::with_capacity(length + hash_function.output_size());
+    let mut counter = 0u32;
+    while mask.len() < length {
+        let mut block = seed.to_vec();
+        block.extend_from_slice(&counter.to_be_bytes());
+        mask.extend_from_slice(&hash_function.run(&block)?);
+        counter += 1;
+    }
+    mask.truncate(length);
+    Ok(mask)
+}
@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::bytes::Bytes;
+use crate::operation::Hashing;
+use crate::random::random_bytes;
+use crate::rsa::{mgf1, RsaEncryptionScheme};
+use crate::types::Result;
+
+// RSAES-OAEP, H 为标签杂凑函数, M 为 MGF1 使用的杂凑函数 (默认与 H 相同)
+#[derive(Debug, Default)]
+pub struct Oaep<H: Hashing, M: Hashing = H> {
+    label: Bytes,
+    hash_function: H,
+    mgf_hash: PhantomData<M>,
+}
+
+impl<H: Hashing, M: Hashing> Oaep<H, M> {
+    pub fn new() -> Self {
+        Self::with_label(&[])
+    }
+
+    pub fn with_label(label: &[u8]) -> Self {
+        Self {
+            label: Bytes::new(label),
+            hash_function: H::default(),
+            mgf_hash: PhantomData,
+        }
+    }
+}
+
+impl<H: Hashing + Debug, M: Hashing + Debug> RsaEncryptionScheme for Oaep<H, M> {
+    fn pad(&self, message: &[u8], k: usize) -> Result<Vec<u8>> {
+        let h_len = self.hash_function.output_size();
+        if message.len() + 2 * h_len + 2 > k {
+            return Err("[Oaep] message too long".into());
+        }
+
+        // DB = lHash || PS || 01 || M
+        let mut db = self.hash_function.run(&self.label)?.to_vec();
+        db.resize(k - message.len() - h_len - 2, 0);
+        db.push(0x01);
+        db.extend_from_slice(message);
+
+        let seed = random_bytes(h_len)?;
+        let masked_db = Bytes::new(db).xor(&Bytes::new(mgf1::<M>(&seed, k - h_len - 1)?));
+        let masked_seed = Bytes::new(seed).xor(&Bytes::new(mgf1::<M>(&masked_db, h_len)?));
+
+        let mut encoded = vec![0x00];
+        encoded.extend_from_slice(&masked_seed);
+        encoded.extend_from_slice(&masked_db);
+        Ok(encoded)
+    }
+
+    fn unpad(&self, encoded: &[u8], k: usize) -> Result<Vec<u8>> {
+        let h_len = self.hash_function.output_size();
+        let error = || "[Oaep] decryption error".into();
+        if k < 2 * h_len + 2 || encoded.len() != k || encoded[0] != 0 {
+            return Err(error());
+        }
+
+        let (masked_seed, masked_db) = encoded[1..].split_at(h_len);
+        let seed = Bytes::new(masked_seed).xor(&Bytes::new(mgf1::<M>(masked_db, h_len)?));
+        let db = Bytes::new(masked_db).xor(&Bytes::new(mgf1::<M>(&seed, k - h_len - 1)?));
+
+        let label_hash = self.hash_function.run(&self.label)?;
+        if db[..h_len] != label_hash[..] {
+            return Err(error());
+        }
+        match db[h_len..].iter().position(|&b| b != 0) {
+            Some(i) if db[h_len + i] == 0x01 => Ok(db[h_len + i + 1..].to_vec()),
+            _ => Err(error()),
+        }
+    }
+}
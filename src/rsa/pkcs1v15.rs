@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+
+use crate::operation::Hashing;
+use crate::random::random_bytes;
+use crate::rsa::{RsaEncryptionScheme, RsaSignatureScheme};
+use crate::sm3::Sm3;
+use crate::types::Result;
+
+// OpenSSL 在 SM3 的 DigestInfo 中使用 1.2.156.10197.1.504 而非杂凑算法本身的 OID
+const SM3_DIGEST_INFO_OID: &[u8] = &[0x2a, 0x81, 0x1c, 0xcf, 0x55, 0x01, 0x83, 0x78];
+
+// RSAES-PKCS1-v1_5: 00 || 02 || PS (非零随机) || 00 || M
+#[derive(Debug, Default)]
+pub struct Pkcs1v15Encryption;
+
+impl RsaEncryptionScheme for Pkcs1v15Encryption {
+    fn pad(&self, message: &[u8], k: usize) -> Result<Vec<u8>> {
+        if message.len() + 11 > k {
+            return Err("[Pkcs1v15Encryption] message too long".into());
+        }
+        let mut padding = random_bytes(k - message.len() - 3)?;
+        for byte in padding.iter_mut() {
+            while *byte == 0 {
+                *byte = random_bytes(1)?[0];
+            }
+        }
+
+        let mut encoded = vec![0x00, 0x02];
+        encoded.extend_from_slice(&padding);
+        encoded.push(0x00);
+        encoded.extend_from_slice(message);
+        Ok(encoded)
+    }
+
+    fn unpad(&self, encoded: &[u8], k: usize) -> Result<Vec<u8>> {
+        let separator = encoded.iter().skip(2).position(|&b| b == 0);
+        match separator {
+            Some(i) if k >= 11 && encoded[..2] == [0x00, 0x02] && i >= 8 => {
+                Ok(encoded[i + 3..].to_vec())
+            }
+            _ => Err("[Pkcs1v15Encryption] decryption error".into()),
+        }
+    }
+}
+
+// RSASSA-PKCS1-v1_5: 00 || 01 || FF .. FF || 00 || DigestInfo
+#[derive(Debug, Default)]
+pub struct Pkcs1v15Signature<H: Hashing> {
+    hash_function: H,
+}
+
+impl<H: Hashing> Pkcs1v15Signature<H> {
+    pub fn new() -> Self {
+        Self {
+            hash_function: H::default(),
+        }
+    }
+
+    // DigestInfo ::= SEQUENCE { SEQUENCE { OID, NULL }, OCTET STRING }
+    fn digest_info(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let digest = self.hash_function.run(message)?;
+        let oid = match self.hash_function.oid() {
+            [] => return Err("[Pkcs1v15Signature] hash function has no OID".into()),
+            oid if oid == Sm3.oid() => SM3_DIGEST_INFO_OID,
+            oid => oid,
+        };
+        let algorithm_length = oid.len() + 4;
+        let mut info = vec![0x30, (algorithm_length + digest.len() + 4) as u8];
+        info.extend_from_slice(&[0x30, algorithm_length as u8, 0x06, oid.len() as u8]);
+        info.extend_from_slice(oid);
+        info.extend_from_slice(&[0x05, 0x00, 0x04, digest.len() as u8]);
+        info.extend_from_slice(&digest);
+        Ok(info)
+    }
+}
+
+impl<H: Hashing + Debug> RsaSignatureScheme for Pkcs1v15Signature<H> {
+    fn encode(&self, message: &[u8], mod_bits: usize) -> Result<Vec<u8>> {
+        let k = mod_bits.div_ceil(8);
+        let info = self.digest_info(message)?;
+        if info.len() + 11 > k {
+            return Err("[Pkcs1v15Signature] intended encoded message length too short".into());
+        }
+
+        let mut encoded = vec![0x00, 0x01];
+        encoded.resize(k - info.len() - 1, 0xff);
+        encoded.push(0x00);
+        encoded.extend_from_slice(&info);
+        Ok(encoded)
+    }
+
+    fn verify(&self, message: &[u8], encoded: &[u8], mod_bits: usize) -> Result<bool> {
+        Ok(self.encode(message, mod_bits)? == encoded)
+    }
+}
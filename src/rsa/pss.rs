@@ -0,0 +1,123 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::bytes::Bytes;
+use crate::operation::Hashing;
+use crate::random::random_bytes;
+use crate::rsa::{mgf1, RsaSignatureScheme};
+use crate::types::Result;
+
+// RSASSA-PSS, H 为消息杂凑函数, M 为 MGF1 使用的杂凑函数 (默认与 H 相同)
+#[derive(Debug, Default)]
+pub struct Pss<H: Hashing, M: Hashing = H> {
+    // 签名时默认取杂凑值长度, 验签时默认从编码中识别
+    salt_length: Option<usize>,
+    // 固定的盐 用于复现签名
+    salt: Option<Bytes>,
+    hash_function: H,
+    mgf_hash: PhantomData<M>,
+}
+
+impl<H: Hashing, M: Hashing> Pss<H, M> {
+    pub fn new() -> Self {
+        Self {
+            salt_length: None,
+            salt: None,
+            hash_function: H::default(),
+            mgf_hash: PhantomData,
+        }
+    }
+
+    pub fn with_salt_length(salt_length: usize) -> Self {
+        Self {
+            salt_length: Some(salt_length),
+            ..Self::new()
+        }
+    }
+
+    pub fn with_salt(salt: &[u8]) -> Self {
+        Self {
+            salt_length: Some(salt.len()),
+            salt: Some(Bytes::new(salt)),
+            ..Self::new()
+        }
+    }
+
+    // M' = 00 * 8 || mHash || salt
+    fn message_hash(&self, m_hash: &[u8], salt: &[u8]) -> Result<Bytes> {
+        let mut prefixed = vec![0u8; 8];
+        prefixed.extend_from_slice(m_hash);
+        prefixed.extend_from_slice(salt);
+        self.hash_function.run(&prefixed)
+    }
+}
+
+impl<H: Hashing + Debug, M: Hashing + Debug> RsaSignatureScheme for Pss<H, M> {
+    fn encode(&self, message: &[u8], mod_bits: usize) -> Result<Vec<u8>> {
+        let h_len = self.hash_function.output_size();
+        let em_bits = mod_bits - 1;
+        let em_len = em_bits.div_ceil(8);
+        let salt = match &self.salt {
+            Some(salt) => salt.to_vec(),
+            None => random_bytes(self.salt_length.unwrap_or(h_len))?,
+        };
+        if em_len < h_len + salt.len() + 2 {
+            return Err("[Pss] encoding error".into());
+        }
+
+        let m_hash = self.hash_function.run(message)?;
+        let h = self.message_hash(&m_hash, &salt)?;
+
+        // DB = PS || 01 || salt
+        let mut db = vec![0u8; em_len - salt.len() - h_len - 2];
+        db.push(0x01);
+        db.extend_from_slice(&salt);
+        let mut masked_db = Bytes::new(db)
+            .xor(&Bytes::new(mgf1::<M>(&h, em_len - h_len - 1)?))
+            .to_vec();
+        masked_db[0] &= 0xff >> (8 * em_len - em_bits);
+
+        // 模数比特长度为 8 的倍数加 1 时 EM 比模数少一个字节
+        let mut encoded = vec![0u8; mod_bits.div_ceil(8) - em_len];
+        encoded.extend_from_slice(&masked_db);
+        encoded.extend_from_slice(&h);
+        encoded.push(0xbc);
+        Ok(encoded)
+    }
+
+    fn verify(&self, message: &[u8], encoded: &[u8], mod_bits: usize) -> Result<bool> {
+        let h_len = self.hash_function.output_size();
+        let em_bits = mod_bits - 1;
+        let em_len = em_bits.div_ceil(8);
+        let (prefix, encoded) = encoded.split_at(encoded.len().saturating_sub(em_len));
+        if encoded.len() != em_len
+            || prefix.iter().any(|&b| b != 0)
+            || em_len < h_len + 2
+            || encoded[em_len - 1] != 0xbc
+        {
+            return Ok(false);
+        }
+
+        let (masked_db, h) = encoded[..em_len - 1].split_at(em_len - h_len - 1);
+        let top_mask = 0xff >> (8 * em_len - em_bits);
+        if masked_db[0] & !top_mask != 0 {
+            return Ok(false);
+        }
+        let mut db = Bytes::new(masked_db)
+            .xor(&Bytes::new(mgf1::<M>(h, em_len - h_len - 1)?))
+            .to_vec();
+        db[0] &= top_mask;
+
+        // PS 全为 0, 之后是 01 和盐
+        let salt = match db.iter().position(|&b| b != 0) {
+            Some(i) if db[i] == 0x01 => &db[i + 1..],
+            _ => return Ok(false),
+        };
+        if self.salt_length.is_some_and(|length| length != salt.len()) {
+            return Ok(false);
+        }
+
+        let m_hash = self.hash_function.run(message)?;
+        Ok(self.message_hash(&m_hash, salt)?[..] == h[..])
+    }
+}
@@ -0,0 +1,39 @@
+use crate::rsa::{RsaEncryptionScheme, RsaSignatureScheme};
+use crate::types::Result;
+
+// 无填充的教科书 RSA, 解密和验签时去掉前导 0
+#[derive(Debug, Default)]
+pub struct RsaRaw;
+
+impl RsaRaw {
+    fn left_pad(message: &[u8], k: usize) -> Result<Vec<u8>> {
+        let message = &message[message.iter().take_while(|&&b| b == 0).count()..];
+        if message.len() > k {
+            return Err("[RsaRaw] message too long".into());
+        }
+        let mut encoded = vec![0u8; k - message.len()];
+        encoded.extend_from_slice(message);
+        Ok(encoded)
+    }
+}
+
+impl RsaEncryptionScheme for RsaRaw {
+    fn pad(&self, message: &[u8], k: usize) -> Result<Vec<u8>> {
+        Self::left_pad(message, k)
+    }
+
+    fn unpad(&self, encoded: &[u8], _k: usize) -> Result<Vec<u8>> {
+        let zeros = encoded.iter().take_while(|&&b| b == 0).count();
+        Ok(encoded[zeros..].to_vec())
+    }
+}
+
+impl RsaSignatureScheme for RsaRaw {
+    fn encode(&self, message: &[u8], mod_bits: usize) -> Result<Vec<u8>> {
+        Self::left_pad(message, mod_bits.div_ceil(8))
+    }
+
+    fn verify(&self, message: &[u8], encoded: &[u8], mod_bits: usize) -> Result<bool> {
+        Ok(Self::left_pad(message, mod_bits.div_ceil(8)).is_ok_and(|expected| expected == encoded))
+    }
+}
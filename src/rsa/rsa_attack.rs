@@ -0,0 +1,388 @@
+use std::fmt;
+
+use crate::bigint::{pollard_rho, BigInt, BigUint};
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::rsa::{RsaPrivateKey, RsaPublicKey};
+use crate::types::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RsaAttackMethod {
+    PrivateExponent,
+    SmallExponent,
+    CommonModulus,
+    Hastad,
+    Wiener,
+    Fermat,
+    PollardPMinusOne,
+    PollardRho,
+}
+
+impl fmt::Display for RsaAttackMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::PrivateExponent => "leaked private exponent",
+            Self::SmallExponent => "small public exponent (e-th root)",
+            Self::CommonModulus => "common modulus",
+            Self::Hastad => "Hastad broadcast",
+            Self::Wiener => "Wiener (continued fractions)",
+            Self::Fermat => "Fermat factoring",
+            Self::PollardPMinusOne => "Pollard p-1",
+            Self::PollardRho => "Pollard rho",
+        };
+        f.write_str(name)
+    }
+}
+
+// 分解类攻击得到私钥, 明文类攻击只得到明文
+#[derive(Debug)]
+pub struct RsaAttackReport {
+    pub method: RsaAttackMethod,
+    pub private_key: Option<RsaPrivateKey>,
+    pub plaintext: Option<Bytes>,
+}
+
+impl RsaAttackReport {
+    fn with_private_key(method: RsaAttackMethod, private_key: RsaPrivateKey) -> Self {
+        Self {
+            method,
+            private_key: Some(private_key),
+            plaintext: None,
+        }
+    }
+
+    fn with_plaintext(method: RsaAttackMethod, plaintext: &BigUint) -> Self {
+        Self {
+            method,
+            private_key: None,
+            plaintext: Some(plaintext.to_bytes(Endian::Big)),
+        }
+    }
+}
+
+impl fmt::Display for RsaAttackReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "attack: {}", self.method)?;
+        if let Some(key) = &self.private_key {
+            writeln!(f, "n = {:#x}", key.n())?;
+            writeln!(f, "e = {:#x}", key.e())?;
+            writeln!(f, "d = {:#x}", key.d())?;
+            writeln!(f, "p = {:#x}", key.p())?;
+            writeln!(f, "q = {:#x}", key.q())?;
+        }
+        if let Some(plaintext) = &self.plaintext {
+            let hex: String = plaintext.iter().map(|b| format!("{:02x}", b)).collect();
+            writeln!(f, "plaintext = {}", hex)?;
+        }
+        Ok(())
+    }
+}
+
+// 依次尝试所有适用的攻击, 输入为密文 (可以为空), 输出攻击报告
+#[derive(Debug)]
+pub struct RsaAttack {
+    pub public_key: RsaPublicKey,
+    // 泄露的私钥指数
+    pub private_exponent: Option<BigUint>,
+    // 其他公钥及对同一明文的密文, 用于共模攻击和广播攻击
+    pub related: Vec<(RsaPublicKey, Bytes)>,
+    pub small_exponent_tries: usize,
+    pub fermat_iterations: usize,
+    pub pollard_bound: u64,
+    pub rho_iterations: usize,
+}
+
+impl RsaAttack {
+    pub fn new(public_key: &RsaPublicKey) -> Self {
+        Self {
+            public_key: public_key.clone(),
+            private_exponent: None,
+            related: vec![],
+            small_exponent_tries: 1 << 10,
+            fermat_iterations: 1 << 20,
+            pollard_bound: 1 << 17,
+            rho_iterations: 1 << 18,
+        }
+    }
+
+    pub fn attack(&self, ciphertext: &[u8]) -> Option<RsaAttackReport> {
+        let public_key = &self.public_key;
+        let mut report = None;
+
+        if let Some(d) = &self.private_exponent {
+            report = private_exponent_attack(public_key, d);
+        }
+        if report.is_none() && !ciphertext.is_empty() {
+            let target = (public_key.clone(), Bytes::new(ciphertext));
+            report = self
+                .related
+                .iter()
+                .find_map(|other| common_modulus_attack(&target, other))
+                .or_else(|| {
+                    let mut pairs = vec![target.clone()];
+                    pairs.extend(self.related.iter().cloned());
+                    hastad_attack(&pairs)
+                })
+                .or_else(|| {
+                    small_exponent_attack(public_key, ciphertext, self.small_exponent_tries)
+                });
+        }
+        let mut report = report
+            .or_else(|| wiener_attack(public_key))
+            .or_else(|| fermat_attack(public_key, self.fermat_iterations))
+            .or_else(|| pollard_p_minus_one_attack(public_key, self.pollard_bound))
+            .or_else(|| pollard_rho_attack(public_key, self.rho_iterations))?;
+
+        // 得到私钥后顺便解密
+        if let (Some(key), None, false) = (
+            &report.private_key,
+            &report.plaintext,
+            ciphertext.is_empty(),
+        ) {
+            let c = BigUint::from_bytes(ciphertext, Endian::Big);
+            report.plaintext = key.decrypt_raw(&c).ok().map(|m| m.to_bytes(Endian::Big));
+        }
+        Some(report)
+    }
+}
+
+impl Operation for RsaAttack {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let report = self.attack(input).ok_or("[RsaAttack] all attacks failed")?;
+        Ok(Bytes::new(report.to_string().as_bytes()))
+    }
+}
+
+// 两个因子都是素数时才构造私钥
+fn key_from_factor(
+    public_key: &RsaPublicKey,
+    p: &BigUint,
+    method: RsaAttackMethod,
+) -> Option<RsaAttackReport> {
+    let one = BigUint::one();
+    if *p <= one || p >= public_key.n() {
+        return None;
+    }
+    let (q, remainder) = public_key.n().div_rem(p);
+    if !remainder.is_zero() || !p.is_probable_prime(20) || !q.is_probable_prime(20) {
+        return None;
+    }
+    let key = RsaPrivateKey::from_primes(p.clone(), q, public_key.e().clone()).ok()?;
+    Some(RsaAttackReport::with_private_key(method, key))
+}
+
+// 精确的 e 次方根
+fn exact_root(value: &BigUint, e: &BigUint) -> Option<BigUint> {
+    let e = e.to_u64().filter(|&e| e <= u32::MAX as u64)? as u32;
+    let root = value.nth_root(e);
+    (root.pow(e) == *value).then_some(root)
+}
+
+pub fn private_exponent_attack(public_key: &RsaPublicKey, d: &BigUint) -> Option<RsaAttackReport> {
+    let key = RsaPrivateKey::new(public_key.n().clone(), public_key.e().clone(), d.clone()).ok()?;
+    Some(RsaAttackReport::with_private_key(
+        RsaAttackMethod::PrivateExponent,
+        key,
+    ))
+}
+
+// m^e = c + k * n, k 较小时直接开方
+pub fn small_exponent_attack(
+    public_key: &RsaPublicKey,
+    ciphertext: &[u8],
+    tries: usize,
+) -> Option<RsaAttackReport> {
+    let c = BigUint::from_bytes(ciphertext, Endian::Big);
+    let mut value = c;
+    for _ in 0..tries.max(1) {
+        if let Some(m) = exact_root(&value, public_key.e()) {
+            return Some(RsaAttackReport::with_plaintext(
+                RsaAttackMethod::SmallExponent,
+                &m,
+            ));
+        }
+        value = &value + public_key.n();
+    }
+    None
+}
+
+// 同一模数 互素的两个指数: a * e1 + b * e2 = 1, m = c1^a * c2^b
+pub fn common_modulus_attack(
+    first: &(RsaPublicKey, Bytes),
+    second: &(RsaPublicKey, Bytes),
+) -> Option<RsaAttackReport> {
+    let ((key1, c1), (key2, c2)) = (first, second);
+    let n = key1.n();
+    if n != key2.n() || key1.e() == key2.e() {
+        return None;
+    }
+    let (gcd, a, b) = BigInt::extended_gcd(
+        &BigInt::from(key1.e().clone()),
+        &BigInt::from(key2.e().clone()),
+    );
+    if !gcd.magnitude().is_one() {
+        return None;
+    }
+
+    // 负指数使用密文的逆元
+    let power = |c: &Bytes, exponent: &BigInt| {
+        let c = BigUint::from_bytes(c, Endian::Big);
+        let base = if exponent.is_negative() {
+            c.mod_inverse(n)?
+        } else {
+            c
+        };
+        Some(base.mod_pow(exponent.magnitude(), n))
+    };
+    let m = &(&power(c1, &a)? * &power(c2, &b)?) % n;
+    Some(RsaAttackReport::with_plaintext(
+        RsaAttackMethod::CommonModulus,
+        &m,
+    ))
+}
+
+// 同一小指数 e 加密给至少 e 个接收者, 中国剩余定理合并后开方
+pub fn hastad_attack(pairs: &[(RsaPublicKey, Bytes)]) -> Option<RsaAttackReport> {
+    let e = pairs.first()?.0.e();
+    let count = e.to_u64().filter(|&e| e as usize <= pairs.len())? as usize;
+    if pairs.iter().any(|(key, _)| key.e() != e) {
+        return None;
+    }
+
+    let mut modulus = BigUint::one();
+    let mut combined = BigUint::zero();
+    for (key, ciphertext) in &pairs[..count] {
+        let n = key.n();
+        let c = &BigUint::from_bytes(ciphertext, Endian::Big) % n;
+        // x ≡ combined (mod modulus), x ≡ c (mod n)
+        let inverse = modulus.mod_inverse(n)?;
+        let difference = BigInt::from(c) - BigInt::from(&combined % n);
+        let t = &(&difference.rem_euclid(n) * &inverse) % n;
+        combined = &combined + &(&modulus * &t);
+        modulus = &modulus * n;
+    }
+    let m = exact_root(&combined, e)?;
+    Some(RsaAttackReport::with_plaintext(RsaAttackMethod::Hastad, &m))
+}
+
+// d < n^(1/4) / 3 时 k/d 是 e/n 的某个渐近分数
+pub fn wiener_attack(public_key: &RsaPublicKey) -> Option<RsaAttackReport> {
+    let (n, e) = (public_key.n(), public_key.e());
+    let one = BigUint::one();
+    let (mut numerator, mut denominator) = (e.clone(), n.clone());
+    // 渐近分数 k/d 的递推
+    let (mut k_prev, mut k) = (BigUint::zero(), BigUint::one());
+    let (mut d_prev, mut d) = (BigUint::one(), BigUint::zero());
+
+    while !denominator.is_zero() {
+        let (a, remainder) = numerator.div_rem(&denominator);
+        (numerator, denominator) = (denominator, remainder);
+        (k_prev, k) = (k.clone(), &(&a * &k) + &k_prev);
+        (d_prev, d) = (d.clone(), &(&a * &d) + &d_prev);
+
+        if k.is_zero() {
+            continue;
+        }
+        let (phi, remainder) = (&(e * &d) - &one).div_rem(&k);
+        if !remainder.is_zero() || phi >= *n {
+            continue;
+        }
+        // p + q = n - phi + 1, p 和 q 是 x^2 - s x + n 的根
+        let s = &(n - &phi) + &one;
+        let square = &s * &s;
+        let discriminant = match square.checked_sub(&(n << 2)) {
+            Some(discriminant) => discriminant,
+            None => continue,
+        };
+        let root = discriminant.sqrt();
+        if &root * &root == discriminant && (&s + &root).is_even() {
+            let p = &(&s + &root) >> 1;
+            if let Some(report) = key_from_factor(public_key, &p, RsaAttackMethod::Wiener) {
+                return Some(report);
+            }
+        }
+    }
+    None
+}
+
+// 模 64、63、65、11 的平方剩余 快速排除非平方数
+fn maybe_square(value: &BigUint) -> bool {
+    let residue = (value % &BigUint::from(64u64 * 63 * 65 * 11))
+        .to_u64()
+        .unwrap();
+    [64, 63, 65, 11]
+        .iter()
+        .all(|&m| (0..m).any(|x| x * x % m == residue % m))
+}
+
+// p 和 q 接近时 n = a^2 - b^2, 从 a = ceil(sqrt(n)) 开始搜索
+pub fn fermat_attack(public_key: &RsaPublicKey, iterations: usize) -> Option<RsaAttackReport> {
+    let n = public_key.n();
+    let mut a = n.sqrt();
+    if &a * &a < *n {
+        a = &a + &BigUint::one();
+    }
+    let mut b2 = &(&a * &a) - n;
+    for _ in 0..iterations {
+        if maybe_square(&b2) {
+            let b = b2.sqrt();
+            if &b * &b == b2 {
+                return key_from_factor(public_key, &(&a - &b), RsaAttackMethod::Fermat);
+            }
+        }
+        // (a + 1)^2 - n = b2 + 2a + 1
+        b2 = &(&b2 + &(&a << 1)) + &BigUint::one();
+        a = &a + &BigUint::one();
+    }
+    None
+}
+
+fn primes_below(bound: u64) -> Vec<u64> {
+    let mut sieve = vec![true; bound as usize + 1];
+    let mut primes = vec![];
+    for i in 2..=bound as usize {
+        if sieve[i] {
+            primes.push(i as u64);
+            for j in (i * i..=bound as usize).step_by(i) {
+                sieve[j] = false;
+            }
+        }
+    }
+    primes
+}
+
+// p - 1 是 bound-光滑数时 gcd(a^M - 1, n) 得到 p
+pub fn pollard_p_minus_one_attack(
+    public_key: &RsaPublicKey,
+    bound: u64,
+) -> Option<RsaAttackReport> {
+    let n = public_key.n();
+    let one = BigUint::one();
+    let mut a = BigUint::from(2u64);
+    for (i, &prime) in primes_below(bound).iter().enumerate() {
+        // 取不超过 bound 的最大幂次
+        let mut power = prime;
+        while power <= bound / prime {
+            power *= prime;
+        }
+        a = a.mod_pow(&BigUint::from(power), n);
+        if i % 64 == 63 {
+            let g = (&a + n - &one).gcd(n);
+            if g == *n {
+                return None;
+            }
+            if !g.is_one() {
+                return key_from_factor(public_key, &g, RsaAttackMethod::PollardPMinusOne);
+            }
+        }
+    }
+    let g = (&a + n - &one).gcd(n);
+    key_from_factor(public_key, &g, RsaAttackMethod::PollardPMinusOne)
+}
+
+// Brent 改进的 rho 算法, 适合较小的因子
+pub fn pollard_rho_attack(public_key: &RsaPublicKey, iterations: usize) -> Option<RsaAttackReport> {
+    let p = pollard_rho(public_key.n(), iterations)?;
+    key_from_factor(public_key, &p, RsaAttackMethod::PollardRho)
+}
@@ -0,0 +1,34 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::rsa::{RsaEncryptionScheme, RsaPrivateKey};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct RsaDecrypt<S: RsaEncryptionScheme> {
+    pub private_key: RsaPrivateKey,
+    pub scheme: S,
+}
+
+impl<S: RsaEncryptionScheme> RsaDecrypt<S> {
+    pub fn new(private_key: &RsaPrivateKey, scheme: S) -> Self {
+        Self {
+            private_key: private_key.clone(),
+            scheme,
+        }
+    }
+}
+
+impl<S: RsaEncryptionScheme> Operation for RsaDecrypt<S> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let k = self.private_key.size();
+        if input.len() > k {
+            return Err("[RsaDecrypt] ciphertext longer than modulus".into());
+        }
+        let c = BigUint::from_bytes(input, Endian::Big);
+        let m = self.private_key.decrypt_raw(&c)?;
+        let encoded = m.to_bytes_padded(k, Endian::Big)?;
+        Ok(Bytes::new(self.scheme.unpad(&encoded, k)?))
+    }
+}
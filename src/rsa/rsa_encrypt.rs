@@ -0,0 +1,31 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::rsa::{RsaEncryptionScheme, RsaPublicKey};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct RsaEncrypt<S: RsaEncryptionScheme> {
+    pub public_key: RsaPublicKey,
+    pub scheme: S,
+}
+
+impl<S: RsaEncryptionScheme> RsaEncrypt<S> {
+    pub fn new(public_key: &RsaPublicKey, scheme: S) -> Self {
+        Self {
+            public_key: public_key.clone(),
+            scheme,
+        }
+    }
+}
+
+impl<S: RsaEncryptionScheme> Operation for RsaEncrypt<S> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let k = self.public_key.size();
+        let encoded = self.scheme.pad(input, k)?;
+        let m = BigUint::from_bytes(&encoded, Endian::Big);
+        let c = self.public_key.encrypt_raw(&m)?;
+        c.to_bytes_padded(k, Endian::Big)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::rsa::{RsaPrivateKey, RsaSignatureScheme};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct RsaSign<S: RsaSignatureScheme> {
+    pub private_key: RsaPrivateKey,
+    pub scheme: S,
+}
+
+impl<S: RsaSignatureScheme> RsaSign<S> {
+    pub fn new(private_key: &RsaPrivateKey, scheme: S) -> Self {
+        Self {
+            private_key: private_key.clone(),
+            scheme,
+        }
+    }
+}
+
+impl<S: RsaSignatureScheme> Operation for RsaSign<S> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let encoded = self.scheme.encode(input, self.private_key.n().bits())?;
+        let m = BigUint::from_bytes(&encoded, Endian::Big);
+        let s = self.private_key.decrypt_raw(&m)?;
+        s.to_bytes_padded(self.private_key.size(), Endian::Big)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::bigint::BigUint;
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::operation::Operation;
+use crate::rsa::{RsaPublicKey, RsaSignatureScheme};
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct RsaVerify<S: RsaSignatureScheme> {
+    pub public_key: RsaPublicKey,
+    pub scheme: S,
+    pub signature: Bytes,
+}
+
+impl<S: RsaSignatureScheme> RsaVerify<S> {
+    pub fn new(public_key: &RsaPublicKey, scheme: S, signature: &[u8]) -> Self {
+        Self {
+            public_key: public_key.clone(),
+            scheme,
+            signature: Bytes::new(signature),
+        }
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let k = self.public_key.size();
+        let s = BigUint::from_bytes(&self.signature, Endian::Big);
+        if self.signature.len() > k || s >= *self.public_key.n() {
+            return Ok(false);
+        }
+        let m = self.public_key.encrypt_raw(&s)?;
+        let encoded = m.to_bytes_padded(k, Endian::Big)?;
+        self.scheme
+            .verify(message, &encoded, self.public_key.n().bits())
+    }
+}
+
+impl<S: RsaSignatureScheme> Operation for RsaVerify<S> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[RsaVerify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
@@ -0,0 +1,172 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// "expand 32-byte k"
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+// "expand 16-byte k"
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+#[derive(Debug)]
+pub struct Salsa20 {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub counter: u64,
+    pub rounds: usize,
+}
+
+impl Salsa20 {
+    // 轮数一般为 20 (Salsa20/20), 也可以是 12 或 8
+    pub fn new(key: &[u8], nonce: &[u8], counter: u64, rounds: usize) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            counter,
+            rounds,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct XSalsa20 {
+    pub key: Bytes,
+    pub nonce: Bytes,
+    pub counter: u64,
+    pub rounds: usize,
+}
+
+impl XSalsa20 {
+    pub fn new(key: &[u8], nonce: &[u8], counter: u64, rounds: usize) -> Self {
+        Self {
+            key: Bytes::new(key),
+            nonce: Bytes::new(nonce),
+            counter,
+            rounds,
+        }
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+// 置换 不包含最后的相加
+fn permute(state: &[u32; 16], rounds: usize) -> [u32; 16] {
+    let mut x = *state;
+    for _ in 0..rounds / 2 {
+        // 列变换
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 5, 9, 13, 1);
+        quarter_round(&mut x, 10, 14, 2, 6);
+        quarter_round(&mut x, 15, 3, 7, 11);
+        // 行变换
+        quarter_round(&mut x, 0, 1, 2, 3);
+        quarter_round(&mut x, 5, 6, 7, 4);
+        quarter_round(&mut x, 10, 11, 8, 9);
+        quarter_round(&mut x, 15, 12, 13, 14);
+    }
+    x
+}
+
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+// 16字节密钥使用 TAU 常量 并重复使用密钥 32字节密钥使用 SIGMA 常量
+fn init_state(key: &[u8], nonce: &[u8]) -> [u32; 16] {
+    let (constants, key) = if key.len() == 16 {
+        (TAU, to_words(&[key, key].concat()))
+    } else {
+        (SIGMA, to_words(key))
+    };
+    let nonce = to_words(nonce);
+
+    let mut state = [0u32; 16];
+    state[0] = constants[0];
+    state[1..5].copy_from_slice(&key[0..4]);
+    state[5] = constants[1];
+    state[6..6 + nonce.len()].copy_from_slice(&nonce);
+    state[10] = constants[2];
+    state[11..15].copy_from_slice(&key[4..8]);
+    state[15] = constants[3];
+    state
+}
+
+// HSalsa20 由密钥和16字节nonce派生子密钥
+fn hsalsa20(key: &[u8], nonce: &[u8], rounds: usize) -> Vec<u8> {
+    let x = permute(&init_state(key, nonce), rounds);
+    [0, 5, 10, 15, 6, 7, 8, 9]
+        .iter()
+        .flat_map(|&i| x[i].to_le_bytes())
+        .collect()
+}
+
+fn keystream_xor(
+    key: &[u8],
+    nonce: &[u8],
+    counter: u64,
+    rounds: usize,
+    input: &[u8],
+) -> Result<Bytes> {
+    if key.len() != 16 && key.len() != 32 {
+        return Err("[Salsa20] key length must be 16 or 32 bytes".into());
+    }
+    if !matches!(rounds, 8 | 12 | 20) {
+        return Err("[Salsa20] rounds must be 8, 12 or 20".into());
+    }
+    if nonce.len() != 8 {
+        return Err("[Salsa20] nonce length must be 8 bytes".into());
+    }
+    let mut state = init_state(key, nonce);
+
+    let mut output = Vec::with_capacity(input.len());
+    for (i, chunk) in input.chunks(64).enumerate() {
+        let block_counter = counter.wrapping_add(i as u64);
+        state[8] = block_counter as u32;
+        state[9] = (block_counter >> 32) as u32;
+
+        let x = permute(&state, rounds);
+        let key_stream: Vec<u8> = x
+            .iter()
+            .zip(state.iter())
+            .flat_map(|(x, s)| x.wrapping_add(*s).to_le_bytes())
+            .collect();
+        output.extend(chunk.iter().zip(key_stream).map(|(byte, k)| byte ^ k));
+    }
+
+    Ok(Bytes::new(output))
+}
+
+impl Operation for Salsa20 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        keystream_xor(&self.key, &self.nonce, self.counter, self.rounds, input)
+    }
+}
+
+impl Operation for XSalsa20 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if self.key.len() != 32 {
+            return Err("[XSalsa20] key length must be 32 bytes".into());
+        }
+        if !matches!(self.rounds, 8 | 12 | 20) {
+            return Err("[XSalsa20] rounds must be 8, 12 or 20".into());
+        }
+        if self.nonce.len() != 24 {
+            return Err("[XSalsa20] nonce length must be 24 bytes".into());
+        }
+        let sub_key = hsalsa20(&self.key, &self.nonce[..16], self.rounds);
+        keystream_xor(
+            &sub_key,
+            &self.nonce[16..],
+            self.counter,
+            self.rounds,
+            input,
+        )
+    }
+}
@@ -0,0 +1,165 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod serpent_decrypt;
+mod serpent_encrypt;
+
+pub use serpent_decrypt::SerpentDecrypt;
+pub use serpent_encrypt::SerpentEncrypt;
+
+const ROUNDS: usize = 32;
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes16;
+
+const PHI: u32 = 0x9e3779b9;
+
+const S_BOXES: [[u8; 16]; 8] = [
+    [3, 8, 15, 1, 10, 6, 5, 11, 14, 13, 4, 2, 7, 0, 9, 12],
+    [15, 12, 2, 7, 9, 0, 5, 10, 1, 11, 14, 8, 6, 13, 3, 4],
+    [8, 6, 7, 9, 3, 12, 10, 15, 13, 1, 14, 4, 0, 11, 5, 2],
+    [0, 15, 11, 8, 12, 9, 6, 3, 13, 1, 2, 4, 10, 7, 5, 14],
+    [1, 15, 8, 3, 12, 0, 11, 6, 2, 5, 4, 10, 9, 14, 7, 13],
+    [15, 5, 2, 11, 4, 10, 9, 12, 0, 3, 14, 8, 13, 6, 7, 1],
+    [7, 2, 12, 5, 8, 4, 6, 11, 14, 9, 1, 15, 13, 3, 10, 0],
+    [1, 13, 15, 0, 14, 8, 2, 11, 7, 4, 12, 10, 9, 3, 5, 6],
+];
+
+// 位切片方式 4个字的第 j 位组成 S 盒的输入
+fn substitute(x: [u32; 4], s_box: &[u8; 16]) -> [u32; 4] {
+    let mut output = [0u32; 4];
+    for j in 0..32 {
+        let input = (0..4).fold(0, |acc, i| acc | ((x[i] >> j) & 1) << i);
+        let value = s_box[input as usize] as u32;
+        for (i, word) in output.iter_mut().enumerate() {
+            *word |= ((value >> i) & 1) << j;
+        }
+    }
+    output
+}
+
+fn linear_transform(x: [u32; 4]) -> [u32; 4] {
+    let [mut x0, mut x1, mut x2, mut x3] = x;
+    x0 = x0.rotate_left(13);
+    x2 = x2.rotate_left(3);
+    x1 ^= x0 ^ x2;
+    x3 ^= x2 ^ (x0 << 3);
+    x1 = x1.rotate_left(1);
+    x3 = x3.rotate_left(7);
+    x0 ^= x1 ^ x3;
+    x2 ^= x3 ^ (x1 << 7);
+    x0 = x0.rotate_left(5);
+    x2 = x2.rotate_left(22);
+    [x0, x1, x2, x3]
+}
+
+fn linear_transform_inv(x: [u32; 4]) -> [u32; 4] {
+    let [mut x0, mut x1, mut x2, mut x3] = x;
+    x2 = x2.rotate_right(22);
+    x0 = x0.rotate_right(5);
+    x2 ^= x3 ^ (x1 << 7);
+    x0 ^= x1 ^ x3;
+    x3 = x3.rotate_right(7);
+    x1 = x1.rotate_right(1);
+    x3 ^= x2 ^ (x0 << 3);
+    x1 ^= x0 ^ x2;
+    x2 = x2.rotate_right(3);
+    x0 = x0.rotate_right(13);
+    [x0, x1, x2, x3]
+}
+
+fn xor(a: [u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    [a[0] ^ b[0], a[1] ^ b[1], a[2] ^ b[2], a[3] ^ b[3]]
+}
+
+fn to_words(block: &[u8]) -> [u32; 4] {
+    let mut words = [0u32; 4];
+    for (word, chunk) in words.iter_mut().zip(block.chunks(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
+
+fn from_words(words: [u32; 4]) -> Bytes {
+    Bytes::new(
+        words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<u8>>(),
+    )
+}
+
+#[derive(Debug)]
+pub struct Serpent {
+    round_keys: [[u32; 4]; ROUNDS + 1],
+    inv_s_boxes: [[u8; 16]; 8],
+}
+
+impl BlockCipher for Serpent {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut x = to_words(block);
+        for round in 0..ROUNDS {
+            x = substitute(xor(x, &self.round_keys[round]), &S_BOXES[round % 8]);
+            if round < ROUNDS - 1 {
+                x = linear_transform(x);
+            }
+        }
+        from_words(xor(x, &self.round_keys[ROUNDS]))
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let mut x = xor(to_words(block), &self.round_keys[ROUNDS]);
+        for round in (0..ROUNDS).rev() {
+            if round < ROUNDS - 1 {
+                x = linear_transform_inv(x);
+            }
+            x = xor(
+                substitute(x, &self.inv_s_boxes[round % 8]),
+                &self.round_keys[round],
+            );
+        }
+        from_words(x)
+    }
+
+    // 密钥不足 32 字节时 在末尾补一个1比特 再补0
+    fn build(key: &[u8]) -> Self {
+        let mut key = key[..key.len().min(32)].to_vec();
+        if key.len() < 32 {
+            key.push(1);
+            key.resize(32, 0);
+        }
+
+        // 预密钥 w_{-8} .. w_{131}
+        let mut w: Vec<u32> = key
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        for i in 0..4 * (ROUNDS + 1) {
+            let j = i + 8;
+            let value = w[j - 8] ^ w[j - 5] ^ w[j - 3] ^ w[j - 1] ^ PHI ^ i as u32;
+            w.push(value.rotate_left(11));
+        }
+
+        // 第 i 个轮密钥使用 S_{(3 - i) mod 8}
+        let mut round_keys = [[0u32; 4]; ROUNDS + 1];
+        for (i, round_key) in round_keys.iter_mut().enumerate() {
+            let words = [w[8 + 4 * i], w[9 + 4 * i], w[10 + 4 * i], w[11 + 4 * i]];
+            *round_key = substitute(words, &S_BOXES[(35 - i) % 8]);
+        }
+
+        let mut inv_s_boxes = [[0u8; 16]; 8];
+        for (inv, s_box) in inv_s_boxes.iter_mut().zip(S_BOXES.iter()) {
+            for (i, &x) in s_box.iter().enumerate() {
+                inv[x as usize] = i as u8;
+            }
+        }
+
+        Self {
+            round_keys,
+            inv_s_boxes,
+        }
+    }
+}
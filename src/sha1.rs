@@ -126,7 +126,12 @@ impl Hashing for Sha1 {
         BLOCK_SIZE
     }
 
-    //fn output_size(&self) -> usize {
-    //    20
-    //}
+    fn output_size(&self) -> usize {
+        20
+    }
+
+    // 1.3.14.3.2.26
+    fn oid(&self) -> &'static [u8] {
+        &[0x2b, 0x0e, 0x03, 0x02, 0x1a]
+    }
 }
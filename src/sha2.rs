@@ -1,5 +1,7 @@
 mod sha256;
+mod sha384;
 mod sha512;
 
 pub use sha256::Sha256;
+pub use sha384::Sha384;
 pub use sha512::Sha512;
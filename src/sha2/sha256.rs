@@ -127,7 +127,13 @@ impl Hashing for Sha256 {
     fn block_size(&self) -> BlockSize {
         BLOCK_SIZE
     }
-    //fn output_size(&self) -> usize {
-    //    32
-    //}
+
+    fn output_size(&self) -> usize {
+        32
+    }
+
+    // 2.16.840.1.101.3.4.2.1
+    fn oid(&self) -> &'static [u8] {
+        &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]
+    }
 }
@@ -0,0 +1,44 @@
+use crate::bytes::Bytes;
+use crate::enums::BlockSize;
+use crate::operation::{Hashing, Operation};
+use crate::sha2::Sha512;
+use crate::types::Result;
+
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes128;
+
+// 第9到16个质数23..53的平方根的分数部分的前64位
+const IV: [u64; 8] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+// 与 SHA-512 的计算过程相同, 初始值不同, 输出截取前384位
+#[derive(Debug, Default)]
+pub struct Sha384;
+
+impl Operation for Sha384 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        Ok(Bytes::new(&Sha512::digest(input, IV)[..48]))
+    }
+}
+
+impl Hashing for Sha384 {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn output_size(&self) -> usize {
+        48
+    }
+
+    // 2.16.840.1.101.3.4.2.2
+    fn oid(&self) -> &'static [u8] {
+        &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02]
+    }
+}
@@ -103,18 +103,12 @@ const K: [u64; 80] = [
 #[derive(Debug, Default)]
 pub struct Sha512;
 
-impl Operation for Sha512 {
-    fn run(&self, input: &[u8]) -> Result<Bytes> {
+impl Sha512 {
+    // 与 SHA-384 共用, 仅初始值不同
+    pub(super) fn digest(input: &[u8], iv: [u64; 8]) -> Vec<u8> {
         let padded_data = BitPadding::new(BLOCK_SIZE, Endian::Big).pad(input);
 
-        let mut a0 = A;
-        let mut b0 = B;
-        let mut c0 = C;
-        let mut d0 = D;
-        let mut e0 = E;
-        let mut f0 = F;
-        let mut g0 = G;
-        let mut h0 = H;
+        let [mut a0, mut b0, mut c0, mut d0, mut e0, mut f0, mut g0, mut h0] = iv;
 
         for chunk in padded_data.chunks(128) {
             let mut a = a0;
@@ -183,19 +177,23 @@ impl Operation for Sha512 {
             h0 = h0.wrapping_add(h);
         }
 
-        Ok(Bytes::new(
-            [
-                a0.to_be_bytes(),
-                b0.to_be_bytes(),
-                c0.to_be_bytes(),
-                d0.to_be_bytes(),
-                e0.to_be_bytes(),
-                f0.to_be_bytes(),
-                g0.to_be_bytes(),
-                h0.to_be_bytes(),
-            ]
-            .concat(),
-        ))
+        [
+            a0.to_be_bytes(),
+            b0.to_be_bytes(),
+            c0.to_be_bytes(),
+            d0.to_be_bytes(),
+            e0.to_be_bytes(),
+            f0.to_be_bytes(),
+            g0.to_be_bytes(),
+            h0.to_be_bytes(),
+        ]
+        .concat()
+    }
+}
+
+impl Operation for Sha512 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        Ok(Bytes::new(Self::digest(input, [A, B, C, D, E, F, G, H])))
     }
 }
 
@@ -204,7 +202,12 @@ impl Hashing for Sha512 {
         BLOCK_SIZE
     }
 
-    //fn output_size(&self) -> usize {
-    //    64
-    //}
+    fn output_size(&self) -> usize {
+        64
+    }
+
+    // 2.16.840.1.101.3.4.2.3
+    fn oid(&self) -> &'static [u8] {
+        &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03]
+    }
 }
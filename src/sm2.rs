@@ -0,0 +1,185 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::random::random_bytes;
+use crate::sm3::Sm3;
+use crate::types::Result;
+
+mod field;
+mod point;
+mod sm2_decrypt;
+mod sm2_encrypt;
+mod sm2_key_exchange;
+mod sm2_sign;
+mod sm2_verify;
+
+use field::{Modulus, U256};
+use point::Point;
+
+pub use sm2_decrypt::Sm2Decrypt;
+pub use sm2_encrypt::Sm2Encrypt;
+pub use sm2_key_exchange::{Sm2Agreement, Sm2KeyExchange};
+pub use sm2_sign::Sm2Sign;
+pub use sm2_verify::Sm2Verify;
+
+// GM/T 0003.5 推荐曲线参数
+const P: U256 = U256::from_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFF");
+const A: U256 = U256::from_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFC");
+const B: U256 = U256::from_hex("28E9FA9E9D9F5E344D5A9E4BCF6509A7F39789F515AB8F92DDBCBD414D940E93");
+const N: U256 = U256::from_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123");
+const GX: U256 = U256::from_hex("32C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7");
+const GY: U256 = U256::from_hex("BC3736A2F4F6779C59BDCEE36B692153D0A9877CC62A474002DF32E52139F0A0");
+
+const FP: Modulus = Modulus::new(P);
+const FN: Modulus = Modulus::new(N);
+
+// 未指定用户标识时的默认值
+pub const DEFAULT_USER_ID: &[u8] = b"1234567812345678";
+
+// 密文中 C1 (椭圆曲线点)、C2 (密文)、C3 (杂凑值) 的排列顺序
+// 旧版标准为 C1C2C3, GM/T 0003-2012 起为 C1C3C2
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Sm2Layout {
+    #[default]
+    C1C3C2,
+    C1C2C3,
+}
+
+// 私钥为 32 字节, 公钥为 65 字节的未压缩点 04 || x || y
+#[derive(Debug)]
+pub struct Sm2KeyPair {
+    pub private_key: Bytes,
+    pub public_key: Bytes,
+}
+
+impl Sm2KeyPair {
+    pub fn generate() -> Result<Self> {
+        let d = random_scalar(&FN.sub(&N, &U256::ONE))?;
+        Self::from_private_key(&d.to_be_bytes())
+    }
+
+    pub fn from_private_key(private_key: &[u8]) -> Result<Self> {
+        let d = parse_private_key(private_key)?;
+        Ok(Self {
+            private_key: Bytes::new(d.to_be_bytes().as_ref()),
+            public_key: Bytes::new(encode_point(&Point::generator().mul(&d))),
+        })
+    }
+}
+
+// 取 [1, upper) 中的随机数
+fn random_scalar(upper: &U256) -> Result<U256> {
+    loop {
+        let k = U256::from_be_bytes(&random_bytes(32)?);
+        if !k.is_zero() && k < *upper {
+            return Ok(k);
+        }
+    }
+}
+
+// 私钥取值范围为 [1, n - 2]
+fn parse_private_key(private_key: &[u8]) -> Result<U256> {
+    if private_key.len() > 32 {
+        return Err("[Sm2] private key length must not exceed 32 bytes".into());
+    }
+    let d = U256::from_be_bytes(private_key);
+    if d.is_zero() || d >= FN.sub(&N, &U256::ONE) {
+        return Err("[Sm2] private key is out of range".into());
+    }
+    Ok(d)
+}
+
+// 支持 04 || x || y、x || y 以及 02/03 || x 压缩格式
+pub(crate) fn parse_public_key(public_key: &[u8]) -> Result<Point> {
+    let (x, y) = match (public_key.len(), public_key.first()) {
+        (65, Some(0x04)) => (
+            U256::from_be_bytes(&public_key[1..33]),
+            U256::from_be_bytes(&public_key[33..]),
+        ),
+        (64, _) => (
+            U256::from_be_bytes(&public_key[..32]),
+            U256::from_be_bytes(&public_key[32..]),
+        ),
+        (33, Some(&prefix)) if prefix == 0x02 || prefix == 0x03 => {
+            let x = U256::from_be_bytes(&public_key[1..]);
+            // p = 3 mod 4, 平方根为 a^((p + 1) / 4)
+            let rhs = FP.add(&FP.mul(&FP.add(&FP.square(&x), &A), &x), &B);
+            let exponent =
+                U256::from_hex("3FFFFFFFBFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC00000004000000000000000");
+            let y = FP.pow(&rhs, &exponent);
+            let y = if y.bit(0) == (prefix == 0x03) {
+                y
+            } else {
+                FP.neg(&y)
+            };
+            (x, y)
+        }
+        _ => return Err("[Sm2] invalid public key format".into()),
+    };
+    if !Point::is_on_curve(&x, &y) {
+        return Err("[Sm2] public key is not on the curve".into());
+    }
+    Ok(Point::new(x, y))
+}
+
+fn encode_point(point: &Point) -> Vec<u8> {
+    match point.to_affine() {
+        Some((x, y)) => [&[0x04], x.to_be_bytes().as_ref(), y.to_be_bytes().as_ref()].concat(),
+        None => vec![0x00],
+    }
+}
+
+// Z = SM3(ENTL || ID || a || b || xG || yG || xA || yA)
+fn z_value(user_id: &[u8], public_key: &Point) -> Result<Vec<u8>> {
+    let entl = user_id.len() * 8;
+    if entl > u16::MAX as usize {
+        return Err("[Sm2] user id is too long".into());
+    }
+    let (x, y) = public_key
+        .to_affine()
+        .ok_or("[Sm2] public key is the point at infinity")?;
+    let input = [
+        (entl as u16).to_be_bytes().as_ref(),
+        user_id,
+        &A.to_be_bytes(),
+        &B.to_be_bytes(),
+        &GX.to_be_bytes(),
+        &GY.to_be_bytes(),
+        &x.to_be_bytes(),
+        &y.to_be_bytes(),
+    ]
+    .concat();
+    Ok(Sm3.run(&input)?.to_vec())
+}
+
+// 基于 SM3 的密钥派生函数 计数器从 1 开始
+fn kdf(z: &[u8], length: usize) -> Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(length);
+    let mut counter = 1u32;
+    while output.len() < length {
+        output.extend_from_slice(&Sm3.run(&[z, &counter.to_be_bytes()].concat())?);
+        counter += 1;
+    }
+    output.truncate(length);
+    Ok(output)
+}
+
+// e = SM3(Z || M) mod n
+fn message_digest(user_id: &[u8], public_key: &Point, message: &[u8]) -> Result<U256> {
+    let z = z_value(user_id, public_key)?;
+    let e = Sm3.run(&[z.as_slice(), message].concat())?;
+    Ok(FN.reduce(&U256::from_be_bytes(&e)))
+}
+
+// 指定的随机数 k 需在 [1, n - 1] 中
+fn nonce(k: &Option<Bytes>) -> Result<U256> {
+    match k {
+        Some(k) => {
+            let k = U256::from_be_bytes(k);
+            if k.is_zero() || k >= N {
+                return Err("[Sm2] k is out of range".into());
+            }
+            Ok(k)
+        }
+        None => random_scalar(&N),
+    }
+}
@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+
+// 256 位无符号整数 小端序的 64 位字
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct U256(pub [u64; 4]);
+
+impl U256 {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    // 大端序十六进制字符串 长度为 64
+    pub const fn from_hex(hex: &str) -> Self {
+        let hex = hex.as_bytes();
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 64 {
+            let c = hex[i];
+            let v = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'f' => c - b'a' + 10,
+                _ => c - b'A' + 10,
+            };
+            let bit = (63 - i) * 4;
+            limbs[bit / 64] |= (v as u64) << (bit % 64);
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    // 不超过 32 字节 不足时高位补零
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, &byte) in bytes.iter().rev().take(32).enumerate() {
+            limbs[i / 8] |= (byte as u64) << (i % 8 * 8);
+        }
+        Self(limbs)
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().rev().enumerate() {
+            bytes[i * 8..i * 8 + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub fn bit(&self, i: usize) -> bool {
+        self.0[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn bits(&self) -> usize {
+        for i in (0..4).rev() {
+            if self.0[i] != 0 {
+                return i * 64 + 64 - self.0[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    // 带进位加法
+    pub const fn add(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        let mut i = 0;
+        while i < 4 {
+            let (v, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (v, c2) = v.overflowing_add(carry as u64);
+            limbs[i] = v;
+            carry = c1 | c2;
+            i += 1;
+        }
+        (Self(limbs), carry)
+    }
+
+    // 带借位减法
+    pub const fn sub(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        let mut i = 0;
+        while i < 4 {
+            let (v, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (v, b2) = v.overflowing_sub(borrow as u64);
+            limbs[i] = v;
+            borrow = b1 | b2;
+            i += 1;
+        }
+        (Self(limbs), borrow)
+    }
+
+    const fn ge(&self, other: &Self) -> bool {
+        !self.sub(other).1
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+// 模数需为大于 2^255 的奇数 (SM2 的 p 和 n)
+// 乘法使用 Montgomery 约减
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Modulus {
+    pub m: U256,
+    m_inv: u64, // -m^-1 mod 2^64
+    r2: U256,   // 2^512 mod m
+}
+
+impl Modulus {
+    pub const fn new(m: U256) -> Self {
+        // 牛顿迭代求 m^-1 mod 2^64
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m.0[0].wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // 2^256 mod m = 2^256 - m, 再倍加 256 次
+        let mut r2 = U256::ZERO.sub(&m).0;
+        let mut i = 0;
+        while i < 256 {
+            let (doubled, carry) = r2.add(&r2);
+            r2 = if carry || doubled.ge(&m) {
+                doubled.sub(&m).0
+            } else {
+                doubled
+            };
+            i += 1;
+        }
+
+        Self {
+            m,
+            m_inv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    // 输入小于 2^256 的数 约减一次即可
+    pub fn reduce(&self, a: &U256) -> U256 {
+        if *a >= self.m {
+            a.sub(&self.m).0
+        } else {
+            *a
+        }
+    }
+
+    pub fn add(&self, a: &U256, b: &U256) -> U256 {
+        let (sum, carry) = a.add(b);
+        if carry || sum >= self.m {
+            sum.sub(&self.m).0
+        } else {
+            sum
+        }
+    }
+
+    pub fn sub(&self, a: &U256, b: &U256) -> U256 {
+        let (difference, borrow) = a.sub(b);
+        if borrow {
+            difference.add(&self.m).0
+        } else {
+            difference
+        }
+    }
+
+    pub fn neg(&self, a: &U256) -> U256 {
+        self.sub(&U256::ZERO, a)
+    }
+
+    // a * b * 2^-256 mod m
+    fn mont_mul(&self, a: &U256, b: &U256) -> U256 {
+        let (a, b, m) = (&a.0, &b.0, &self.m.0);
+        let mut t = [0u64; 6];
+        for &bi in b {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let v = t[j] as u128 + a[j] as u128 * bi as u128 + carry;
+                t[j] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let q = t[0].wrapping_mul(self.m_inv);
+            let mut carry = (t[0] as u128 + q as u128 * m[0] as u128) >> 64;
+            for j in 1..4 {
+                let v = t[j] as u128 + q as u128 * m[j] as u128 + carry;
+                t[j - 1] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+
+        let result = U256([t[0], t[1], t[2], t[3]]);
+        if t[4] != 0 || result >= self.m {
+            result.sub(&self.m).0
+        } else {
+            result
+        }
+    }
+
+    pub fn mul(&self, a: &U256, b: &U256) -> U256 {
+        self.mont_mul(&self.mont_mul(a, b), &self.r2)
+    }
+
+    pub fn square(&self, a: &U256) -> U256 {
+        self.mul(a, a)
+    }
+
+    // 在 Montgomery 域中平方乘
+    pub fn pow(&self, a: &U256, e: &U256) -> U256 {
+        let base = self.mont_mul(a, &self.r2);
+        let mut result = self.mont_mul(&U256::ONE, &self.r2);
+        for i in (0..e.bits()).rev() {
+            result = self.mont_mul(&result, &result);
+            if e.bit(i) {
+                result = self.mont_mul(&result, &base);
+            }
+        }
+        self.mont_mul(&result, &U256::ONE)
+    }
+
+    // 模数为素数 由费马小定理求逆
+    pub fn inv(&self, a: &U256) -> U256 {
+        self.pow(a, &self.m.sub(&U256([2, 0, 0, 0])).0)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::sm2::field::U256;
+use crate::sm2::{A, B, FP, GX, GY};
+
+// Jacobian 坐标 (X, Y, Z) 对应仿射坐标 (X / Z^2, Y / Z^3)
+// Z = 0 表示无穷远点
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Point {
+    x: U256,
+    y: U256,
+    z: U256,
+}
+
+impl Point {
+    pub const INFINITY: Self = Self {
+        x: U256::ONE,
+        y: U256::ONE,
+        z: U256::ZERO,
+    };
+
+    pub fn new(x: U256, y: U256) -> Self {
+        Self { x, y, z: U256::ONE }
+    }
+
+    pub fn generator() -> Self {
+        Self::new(GX, GY)
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    // y^2 = x^3 + ax + b
+    pub fn is_on_curve(x: &U256, y: &U256) -> bool {
+        if *x >= FP.m || *y >= FP.m {
+            return false;
+        }
+        let rhs = FP.add(&FP.mul(&FP.add(&FP.square(x), &A), x), &B);
+        FP.square(y) == rhs
+    }
+
+    pub fn to_affine(self) -> Option<(U256, U256)> {
+        if self.is_infinity() {
+            return None;
+        }
+        let z_inv = FP.inv(&self.z);
+        let z_inv2 = FP.square(&z_inv);
+        Some((
+            FP.mul(&self.x, &z_inv2),
+            FP.mul(&self.y, &FP.mul(&z_inv2, &z_inv)),
+        ))
+    }
+
+    // a = -3 时的倍点公式
+    pub fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::INFINITY;
+        }
+        let delta = FP.square(&self.z);
+        let gamma = FP.square(&self.y);
+        let beta = FP.mul(&self.x, &gamma);
+        let t = FP.mul(&FP.sub(&self.x, &delta), &FP.add(&self.x, &delta));
+        let alpha = FP.add(&FP.add(&t, &t), &t);
+
+        let beta4 = FP.add(&FP.add(&beta, &beta), &FP.add(&beta, &beta));
+        let x = FP.sub(&FP.square(&alpha), &FP.add(&beta4, &beta4));
+        let yz = FP.add(&self.y, &self.z);
+        let z = FP.sub(&FP.sub(&FP.square(&yz), &gamma), &delta);
+        let gamma2 = FP.square(&gamma);
+        let gamma8 = FP.add(&gamma2, &gamma2);
+        let gamma8 = FP.add(&gamma8, &gamma8);
+        let gamma8 = FP.add(&gamma8, &gamma8);
+        let y = FP.sub(&FP.mul(&alpha, &FP.sub(&beta4, &x)), &gamma8);
+        Self { x, y, z }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return *other;
+        }
+        if other.is_infinity() {
+            return *self;
+        }
+        let z1z1 = FP.square(&self.z);
+        let z2z2 = FP.square(&other.z);
+        let u1 = FP.mul(&self.x, &z2z2);
+        let u2 = FP.mul(&other.x, &z1z1);
+        let s1 = FP.mul(&self.y, &FP.mul(&other.z, &z2z2));
+        let s2 = FP.mul(&other.y, &FP.mul(&self.z, &z1z1));
+        let h = FP.sub(&u2, &u1);
+        let r = FP.sub(&s2, &s1);
+        if h.is_zero() {
+            return if r.is_zero() {
+                self.double()
+            } else {
+                Self::INFINITY
+            };
+        }
+
+        let h2 = FP.square(&h);
+        let h3 = FP.mul(&h2, &h);
+        let u1h2 = FP.mul(&u1, &h2);
+        let x = FP.sub(&FP.sub(&FP.square(&r), &h3), &FP.add(&u1h2, &u1h2));
+        let y = FP.sub(&FP.mul(&r, &FP.sub(&u1h2, &x)), &FP.mul(&s1, &h3));
+        let z = FP.mul(&h, &FP.mul(&self.z, &other.z));
+        Self { x, y, z }
+    }
+
+    // 从高位开始的倍点-加法
+    pub fn mul(&self, k: &U256) -> Self {
+        let mut result = Self::INFINITY;
+        for i in (0..k.bits()).rev() {
+            result = result.double();
+            if k.bit(i) {
+                result = result.add(self);
+            }
+        }
+        result
+    }
+}
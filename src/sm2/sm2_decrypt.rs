@@ -0,0 +1,52 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::sm2::{kdf, parse_private_key, parse_public_key, Sm2Layout};
+use crate::sm3::Sm3;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct Sm2Decrypt {
+    pub private_key: Bytes,
+    pub layout: Sm2Layout,
+}
+
+impl Sm2Decrypt {
+    pub fn new(private_key: &[u8], layout: Sm2Layout) -> Self {
+        Self {
+            private_key: Bytes::new(private_key),
+            layout,
+        }
+    }
+}
+
+impl Operation for Sm2Decrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        // C1 为 65 字节的未压缩点, C3 为 32 字节
+        if input.len() < 65 + 32 {
+            return Err("[Sm2Decrypt] ciphertext is too short".into());
+        }
+        let d = parse_private_key(&self.private_key)?;
+        let c1 = parse_public_key(&input[..65])?;
+        let (c2, c3) = match self.layout {
+            Sm2Layout::C1C3C2 => (&input[97..], &input[65..97]),
+            Sm2Layout::C1C2C3 => (&input[65..input.len() - 32], &input[input.len() - 32..]),
+        };
+
+        let (x2, y2) = c1
+            .mul(&d)
+            .to_affine()
+            .ok_or("[Sm2Decrypt] d * C1 is the point at infinity")?;
+        let (x2, y2) = (x2.to_be_bytes(), y2.to_be_bytes());
+        let t = kdf(&[x2, y2].concat(), c2.len())?;
+        if !t.is_empty() && t.iter().all(|&byte| byte == 0) {
+            return Err("[Sm2Decrypt] derived key is all zero".into());
+        }
+
+        let message: Vec<u8> = c2.iter().zip(t).map(|(c, t)| c ^ t).collect();
+        let u = Sm3.run(&[x2.as_ref(), &message, y2.as_ref()].concat())?;
+        if u.as_ref() != c3 {
+            return Err("[Sm2Decrypt] C3 verification failed".into());
+        }
+        Ok(Bytes::new(message))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::sm2::point::Point;
+use crate::sm2::{encode_point, kdf, nonce, parse_public_key, Sm2Layout};
+use crate::sm3::Sm3;
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct Sm2Encrypt {
+    pub public_key: Bytes,
+    pub layout: Sm2Layout,
+    pub k: Option<Bytes>, // 指定随机数 k 以复现密文
+}
+
+impl Sm2Encrypt {
+    pub fn new(public_key: &[u8], layout: Sm2Layout) -> Self {
+        Self {
+            public_key: Bytes::new(public_key),
+            layout,
+            k: None,
+        }
+    }
+
+    pub fn with_k(public_key: &[u8], layout: Sm2Layout, k: &[u8]) -> Self {
+        Self {
+            k: Some(Bytes::new(k)),
+            ..Self::new(public_key, layout)
+        }
+    }
+}
+
+impl Operation for Sm2Encrypt {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let public_key = parse_public_key(&self.public_key)?;
+
+        loop {
+            let k = nonce(&self.k)?;
+            // C1 = [k]G, (x2, y2) = [k]P
+            let c1 = encode_point(&Point::generator().mul(&k));
+            let (x2, y2) = public_key
+                .mul(&k)
+                .to_affine()
+                .ok_or("[Sm2Encrypt] k * P is the point at infinity")?;
+            let (x2, y2) = (x2.to_be_bytes(), y2.to_be_bytes());
+
+            // 派生的密钥全为 0 时需更换 k
+            let t = kdf(&[x2, y2].concat(), input.len())?;
+            if !t.is_empty() && t.iter().all(|&byte| byte == 0) {
+                if self.k.is_some() {
+                    return Err("[Sm2Encrypt] k is not suitable for this message".into());
+                }
+                continue;
+            }
+
+            let c2: Vec<u8> = input.iter().zip(t).map(|(m, t)| m ^ t).collect();
+            let c3 = Sm3.run(&[x2.as_ref(), input, y2.as_ref()].concat())?;
+            let output = match self.layout {
+                Sm2Layout::C1C3C2 => [c1.as_slice(), &c3, &c2].concat(),
+                Sm2Layout::C1C2C3 => [c1.as_slice(), &c2, &c3].concat(),
+            };
+            return Ok(Bytes::new(output));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::sm2::field::U256;
+use crate::sm2::point::Point;
+use crate::sm2::{kdf, parse_private_key, parse_public_key, z_value, FN};
+use crate::sm3::Sm3;
+use crate::types::Result;
+
+// 协商结果 confirmation 发送给对方, expected_confirmation 用于校验对方发来的值
+#[derive(Debug)]
+pub struct Sm2Agreement {
+    pub key: Bytes,
+    pub confirmation: Bytes,
+    pub expected_confirmation: Bytes,
+}
+
+// 输入为对方的临时公钥 输出为协商的密钥
+#[derive(Debug)]
+pub struct Sm2KeyExchange {
+    pub private_key: Bytes,
+    pub ephemeral_private_key: Bytes,
+    pub user_id: Bytes,
+    pub peer_public_key: Bytes,
+    pub peer_user_id: Bytes,
+    pub initiator: bool, // 发起方 A 或响应方 B
+    pub key_length: usize,
+}
+
+impl Sm2KeyExchange {
+    pub fn new(
+        private_key: &[u8],
+        ephemeral_private_key: &[u8],
+        user_id: &[u8],
+        peer_public_key: &[u8],
+        peer_user_id: &[u8],
+        initiator: bool,
+        key_length: usize,
+    ) -> Self {
+        Self {
+            private_key: Bytes::new(private_key),
+            ephemeral_private_key: Bytes::new(ephemeral_private_key),
+            user_id: Bytes::new(user_id),
+            peer_public_key: Bytes::new(peer_public_key),
+            peer_user_id: Bytes::new(peer_user_id),
+            initiator,
+            key_length,
+        }
+    }
+
+    pub fn exchange(&self, peer_ephemeral_public_key: &[u8]) -> Result<Sm2Agreement> {
+        let d = parse_private_key(&self.private_key)?;
+        let r = parse_private_key(&self.ephemeral_private_key)?;
+        let peer_public_key = parse_public_key(&self.peer_public_key)?;
+        let peer_ephemeral = parse_public_key(peer_ephemeral_public_key)?;
+
+        let own_ephemeral = Point::generator()
+            .mul(&r)
+            .to_affine()
+            .ok_or("[Sm2KeyExchange] ephemeral public key is the point at infinity")?;
+        let peer_ephemeral_affine = peer_ephemeral
+            .to_affine()
+            .ok_or("[Sm2KeyExchange] peer ephemeral public key is the point at infinity")?;
+
+        // t = (d + x̄ * r) mod n
+        // U = [t](P' + [x̄']R')
+        let t = FN.add(&d, &FN.mul(&truncate(&own_ephemeral.0), &r));
+        let shared = peer_public_key
+            .add(&peer_ephemeral.mul(&truncate(&peer_ephemeral_affine.0)))
+            .mul(&t)
+            .to_affine()
+            .ok_or("[Sm2KeyExchange] shared point is the point at infinity")?;
+        let (x, y) = (shared.0.to_be_bytes(), shared.1.to_be_bytes());
+
+        // Z 与临时公钥均按 A、B 的顺序排列
+        let own_z = z_value(&self.user_id, &Point::generator().mul(&d))?;
+        let peer_z = z_value(&self.peer_user_id, &peer_public_key)?;
+        let (za, zb, ra, rb) = if self.initiator {
+            (own_z, peer_z, own_ephemeral, peer_ephemeral_affine)
+        } else {
+            (peer_z, own_z, peer_ephemeral_affine, own_ephemeral)
+        };
+
+        let key = kdf(&[x.as_ref(), &y, &za, &zb].concat(), self.key_length)?;
+
+        // S = SM3(tag || y || SM3(x || ZA || ZB || x1 || y1 || x2 || y2))
+        let inner = Sm3.run(
+            &[
+                x.as_ref(),
+                &za,
+                &zb,
+                &ra.0.to_be_bytes(),
+                &ra.1.to_be_bytes(),
+                &rb.0.to_be_bytes(),
+                &rb.1.to_be_bytes(),
+            ]
+            .concat(),
+        )?;
+        let s = |tag: u8| Sm3.run(&[&[tag], y.as_ref(), &inner].concat());
+        // B 发送 tag 为 0x02 的 SB, A 发送 tag 为 0x03 的 SA
+        let (confirmation, expected_confirmation) = if self.initiator {
+            (s(0x03)?, s(0x02)?)
+        } else {
+            (s(0x02)?, s(0x03)?)
+        };
+
+        Ok(Sm2Agreement {
+            key: Bytes::new(key),
+            confirmation,
+            expected_confirmation,
+        })
+    }
+}
+
+// x̄ = 2^w + (x & (2^w - 1)), w = 127
+fn truncate(x: &U256) -> U256 {
+    U256([x.0[0], x.0[1] | 1 << 63, 0, 0])
+}
+
+impl Operation for Sm2KeyExchange {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        Ok(self.exchange(input)?.key)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::sm2::field::U256;
+use crate::sm2::point::Point;
+use crate::sm2::{message_digest, nonce, parse_private_key, FN};
+use crate::types::Result;
+
+// 输出 r || s 共 64 字节
+#[derive(Debug)]
+pub struct Sm2Sign {
+    pub private_key: Bytes,
+    pub user_id: Bytes,
+    pub k: Option<Bytes>, // 指定随机数 k 以复现签名
+}
+
+impl Sm2Sign {
+    pub fn new(private_key: &[u8], user_id: &[u8]) -> Self {
+        Self {
+            private_key: Bytes::new(private_key),
+            user_id: Bytes::new(user_id),
+            k: None,
+        }
+    }
+
+    pub fn with_k(private_key: &[u8], user_id: &[u8], k: &[u8]) -> Self {
+        Self {
+            k: Some(Bytes::new(k)),
+            ..Self::new(private_key, user_id)
+        }
+    }
+}
+
+impl Operation for Sm2Sign {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let d = parse_private_key(&self.private_key)?;
+        let public_key = Point::generator().mul(&d);
+        let e = message_digest(&self.user_id, &public_key, input)?;
+        // (1 + d)^-1
+        let d_inv = FN.inv(&FN.add(&U256::ONE, &d));
+
+        loop {
+            let k = nonce(&self.k)?;
+            let (x1, _) = Point::generator()
+                .mul(&k)
+                .to_affine()
+                .ok_or("[Sm2Sign] k * G is the point at infinity")?;
+            // r = (e + x1) mod n, 且 r + k != n
+            let r = FN.add(&e, &FN.reduce(&x1));
+            // s = (1 + d)^-1 * (k - r * d) mod n
+            let s = FN.mul(&d_inv, &FN.sub(&k, &FN.mul(&r, &d)));
+            if r.is_zero() || FN.add(&r, &k).is_zero() || s.is_zero() {
+                if self.k.is_some() {
+                    return Err("[Sm2Sign] k is not suitable for this message".into());
+                }
+                continue;
+            }
+            return Ok(Bytes::new([r.to_be_bytes(), s.to_be_bytes()].concat()));
+        }
+    }
+}
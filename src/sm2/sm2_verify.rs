@@ -0,0 +1,57 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::sm2::field::U256;
+use crate::sm2::point::Point;
+use crate::sm2::{message_digest, parse_public_key, FN, N};
+use crate::types::Result;
+
+// 验证通过时原样输出消息 否则返回错误
+#[derive(Debug)]
+pub struct Sm2Verify {
+    pub public_key: Bytes,
+    pub user_id: Bytes,
+    pub signature: Bytes, // r || s
+}
+
+impl Sm2Verify {
+    pub fn new(public_key: &[u8], user_id: &[u8], signature: &[u8]) -> Self {
+        Self {
+            public_key: Bytes::new(public_key),
+            user_id: Bytes::new(user_id),
+            signature: Bytes::new(signature),
+        }
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let public_key = parse_public_key(&self.public_key)?;
+        if self.signature.len() != 64 {
+            return Err("[Sm2Verify] signature length must be 64 bytes".into());
+        }
+        let r = U256::from_be_bytes(&self.signature[..32]);
+        let s = U256::from_be_bytes(&self.signature[32..]);
+        if r.is_zero() || r >= N || s.is_zero() || s >= N {
+            return Ok(false);
+        }
+
+        let e = message_digest(&self.user_id, &public_key, message)?;
+        let t = FN.add(&r, &s);
+        if t.is_zero() {
+            return Ok(false);
+        }
+        // (x1, y1) = [s]G + [t]P
+        let point = Point::generator().mul(&s).add(&public_key.mul(&t));
+        Ok(match point.to_affine() {
+            Some((x1, _)) => FN.add(&e, &FN.reduce(&x1)) == r,
+            None => false,
+        })
+    }
+}
+
+impl Operation for Sm2Verify {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        if !self.verify(input)? {
+            return Err("[Sm2Verify] signature verification failed".into());
+        }
+        Ok(Bytes::new(input))
+    }
+}
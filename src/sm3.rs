@@ -147,4 +147,13 @@ impl Hashing for Sm3 {
     fn block_size(&self) -> BlockSize {
         BLOCK_SIZE
     }
+
+    fn output_size(&self) -> usize {
+        32
+    }
+
+    // 1.2.156.10197.1.401
+    fn oid(&self) -> &'static [u8] {
+        &[0x2a, 0x81, 0x1c, 0xcf, 0x55, 0x01, 0x83, 0x11]
+    }
 }
@@ -1,4 +1,5 @@
 use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
 use crate::enums::BlockSize;
 
 mod sm4_decrypt;
@@ -145,3 +146,34 @@ fn block_crypt(round_keys: &[u32]) -> impl Fn(&[u8]) -> Bytes + '_ {
         )
     }
 }
+
+#[derive(Debug)]
+pub struct Sm4 {
+    round_keys: Vec<u32>,
+    inv_round_keys: Vec<u32>,
+}
+
+impl BlockCipher for Sm4 {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(&self.round_keys)(block)
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        block_crypt(&self.inv_round_keys)(block)
+    }
+
+    fn build(key: &[u8]) -> Self {
+        let round_keys = key_schedule(&Bytes::new(key));
+        // SM4的解密过程用到的轮密钥是加密过程的轮密钥的逆序
+        let mut inv_round_keys = round_keys.clone();
+        inv_round_keys.reverse();
+        Self {
+            round_keys,
+            inv_round_keys,
+        }
+    }
+}
@@ -1,12 +1,225 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::{BlockSize, Endian};
+
+mod tea_decrypt;
+mod tea_encrypt;
+mod xtea_decrypt;
+mod xtea_encrypt;
 mod xxtea_decrypt;
 mod xxtea_encrypt;
 
+pub use tea_decrypt::TeaDecrypt;
+pub use tea_encrypt::TeaEncrypt;
+pub use xtea_decrypt::XteaDecrypt;
+pub use xtea_encrypt::XteaEncrypt;
 pub use xxtea_decrypt::XxteaDecrypt;
 pub use xxtea_encrypt::XxteaEncrypt;
 
-const DELTA: u32 = 0x9E3779B9;
+pub const DELTA: u32 = 0x9E3779B9;
+
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes8;
+// TEA 与 XTEA 的标准轮数(循环次数)
+const ROUNDS: usize = 32;
+
+// TEA 系列算法的可调参数
+// 样本中常见修改 delta、轮数 以及密钥和数据的字节序
+#[derive(Clone, Copy, Debug)]
+pub struct TeaParams {
+    pub delta: u32,
+    pub rounds: Option<usize>, // 为空时使用标准轮数 (XXTEA 为 6 + 52 / n)
+    pub key_endian: Endian,
+    pub data_endian: Endian,
+}
+
+impl TeaParams {
+    pub fn new(delta: u32, rounds: Option<usize>, key_endian: Endian, data_endian: Endian) -> Self {
+        Self {
+            delta,
+            rounds,
+            key_endian,
+            data_endian,
+        }
+    }
+}
+
+// 默认值与 TEA/XTEA 参考实现一致 (大端序)
+impl Default for TeaParams {
+    fn default() -> Self {
+        Self::new(DELTA, None, Endian::Big, Endian::Big)
+    }
+}
+
+fn to_words(bytes: &[u8], endian: Endian) -> Vec<u32> {
+    bytes
+        .chunks(4)
+        .map(|chunk| {
+            let word = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            match endian {
+                Endian::Big => u32::from_be_bytes(word),
+                Endian::Little => u32::from_le_bytes(word),
+            }
+        })
+        .collect()
+}
+
+fn from_words(words: &[u32], endian: Endian) -> Vec<u8> {
+    words
+        .iter()
+        .flat_map(|word| match endian {
+            Endian::Big => word.to_be_bytes(),
+            Endian::Little => word.to_le_bytes(),
+        })
+        .collect()
+}
+
+// key 只取前16字节 如果key长度小于16 则用0填充
+fn key_words(key: &[u8], endian: Endian) -> [u32; 4] {
+    let mut key_bytes = key.to_vec();
+    key_bytes.resize(16, 0);
+    let words = to_words(&key_bytes, endian);
+    [words[0], words[1], words[2], words[3]]
+}
 
 const fn xxtea_mx(z: u32, y: u32, sum: u32, key: &[u32], p: usize, e: usize) -> u32 {
     (((z >> 5) ^ (y << 2)).wrapping_add((y >> 3) ^ (z << 4)))
         ^ ((sum ^ y).wrapping_add(key[(p & 3) ^ e] ^ z))
 }
+
+#[derive(Debug)]
+pub struct Tea {
+    key: [u32; 4],
+    params: TeaParams,
+}
+
+impl Tea {
+    pub fn with_params(key: &[u8], params: TeaParams) -> Self {
+        Self {
+            key: key_words(key, params.key_endian),
+            params,
+        }
+    }
+}
+
+impl BlockCipher for Tea {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let TeaParams {
+            delta, data_endian, ..
+        } = self.params;
+        let k = self.key;
+        let v = to_words(block, data_endian);
+        let (mut v0, mut v1) = (v[0], v[1]);
+
+        let mut sum = 0u32;
+        for _ in 0..self.params.rounds.unwrap_or(ROUNDS) {
+            sum = sum.wrapping_add(delta);
+            v0 = v0.wrapping_add(
+                (v1 << 4).wrapping_add(k[0]) ^ v1.wrapping_add(sum) ^ (v1 >> 5).wrapping_add(k[1]),
+            );
+            v1 = v1.wrapping_add(
+                (v0 << 4).wrapping_add(k[2]) ^ v0.wrapping_add(sum) ^ (v0 >> 5).wrapping_add(k[3]),
+            );
+        }
+        Bytes::new(from_words(&[v0, v1], data_endian))
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let TeaParams {
+            delta, data_endian, ..
+        } = self.params;
+        let k = self.key;
+        let v = to_words(block, data_endian);
+        let (mut v0, mut v1) = (v[0], v[1]);
+
+        let rounds = self.params.rounds.unwrap_or(ROUNDS);
+        let mut sum = delta.wrapping_mul(rounds as u32);
+        for _ in 0..rounds {
+            v1 = v1.wrapping_sub(
+                (v0 << 4).wrapping_add(k[2]) ^ v0.wrapping_add(sum) ^ (v0 >> 5).wrapping_add(k[3]),
+            );
+            v0 = v0.wrapping_sub(
+                (v1 << 4).wrapping_add(k[0]) ^ v1.wrapping_add(sum) ^ (v1 >> 5).wrapping_add(k[1]),
+            );
+            sum = sum.wrapping_sub(delta);
+        }
+        Bytes::new(from_words(&[v0, v1], data_endian))
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_params(key, TeaParams::default())
+    }
+}
+
+#[derive(Debug)]
+pub struct Xtea {
+    key: [u32; 4],
+    params: TeaParams,
+}
+
+impl Xtea {
+    pub fn with_params(key: &[u8], params: TeaParams) -> Self {
+        Self {
+            key: key_words(key, params.key_endian),
+            params,
+        }
+    }
+}
+
+impl BlockCipher for Xtea {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let TeaParams {
+            delta, data_endian, ..
+        } = self.params;
+        let k = self.key;
+        let v = to_words(block, data_endian);
+        let (mut v0, mut v1) = (v[0], v[1]);
+
+        let mut sum = 0u32;
+        for _ in 0..self.params.rounds.unwrap_or(ROUNDS) {
+            v0 = v0.wrapping_add(
+                ((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1) ^ sum.wrapping_add(k[(sum & 3) as usize]),
+            );
+            sum = sum.wrapping_add(delta);
+            v1 = v1.wrapping_add(
+                ((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0)
+                    ^ sum.wrapping_add(k[((sum >> 11) & 3) as usize]),
+            );
+        }
+        Bytes::new(from_words(&[v0, v1], data_endian))
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let TeaParams {
+            delta, data_endian, ..
+        } = self.params;
+        let k = self.key;
+        let v = to_words(block, data_endian);
+        let (mut v0, mut v1) = (v[0], v[1]);
+
+        let rounds = self.params.rounds.unwrap_or(ROUNDS);
+        let mut sum = delta.wrapping_mul(rounds as u32);
+        for _ in 0..rounds {
+            v1 = v1.wrapping_sub(
+                ((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0)
+                    ^ sum.wrapping_add(k[((sum >> 11) & 3) as usize]),
+            );
+            sum = sum.wrapping_sub(delta);
+            v0 = v0.wrapping_sub(
+                ((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1) ^ sum.wrapping_add(k[(sum & 3) as usize]),
+            );
+        }
+        Bytes::new(from_words(&[v0, v1], data_endian))
+    }
+
+    fn build(key: &[u8]) -> Self {
+        Self::with_params(key, TeaParams::default())
+    }
+}
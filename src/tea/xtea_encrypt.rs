@@ -0,0 +1,47 @@
+use crate::bytes::Bytes;
+use crate::mode::Mode;
+use crate::operation::Operation;
+use crate::padding::Padding;
+use crate::tea::{TeaParams, Xtea, BLOCK_SIZE};
+use crate::types::Result;
+
+#[derive(Debug)]
+pub struct XteaEncrypt<M: Mode, P: Padding> {
+    pub key: Bytes,
+    pub mode: M,
+    pub padding: P,
+    pub params: Option<TeaParams>, // 自定义 delta、轮数、字节序
+}
+
+impl<M: Mode, P: Padding> XteaEncrypt<M, P> {
+    pub fn new(key: &[u8], mode: M) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            params: None,
+        }
+    }
+
+    pub fn with_params(key: &[u8], mode: M, params: TeaParams) -> Self {
+        Self {
+            key: Bytes::new(key),
+            mode,
+            padding: P::build(BLOCK_SIZE),
+            params: Some(params),
+        }
+    }
+}
+
+impl<M: Mode, P: Padding> Operation for XteaEncrypt<M, P> {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let padded_data = self.padding.pad(input);
+
+        Ok(match self.params {
+            Some(params) => self
+                .mode
+                .block_cipher_encrypt(&padded_data, &Xtea::with_params(&self.key, params)),
+            None => self.mode.cipher_encrypt::<Xtea>(&padded_data, &self.key),
+        })
+    }
+}
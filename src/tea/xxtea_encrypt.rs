@@ -1,19 +1,31 @@
 use crate::bytes::Bytes;
+use crate::enums::Endian;
 use crate::operation::Operation;
-use crate::tea::{xxtea_mx, DELTA};
+use crate::tea::{from_words, key_words, to_words, xxtea_mx, TeaParams, DELTA};
 use crate::types::Result;
 
 #[derive(Debug)]
 pub struct XxteaEncrypt {
     pub key: Bytes,
     pub include_length: bool, // 常见的实现中 输入后添加了一块 32bit的长度
+    pub params: TeaParams,
 }
 
 impl XxteaEncrypt {
+    // 常见实现中密钥和数据均按小端序处理
     pub fn new(key: &[u8], include_length: bool) -> Self {
+        Self::with_params(
+            key,
+            include_length,
+            TeaParams::new(DELTA, None, Endian::Little, Endian::Little),
+        )
+    }
+
+    pub fn with_params(key: &[u8], include_length: bool, params: TeaParams) -> Self {
         Self {
             key: Bytes::new(key),
             include_length,
+            params,
         }
     }
 }
@@ -24,50 +36,40 @@ impl Operation for XxteaEncrypt {
         // 长度不是4的倍数 需要补齐
         let mut input = input.to_vec();
         let length = input.len();
-        if length % 4 != 0 {
+        if !length.is_multiple_of(4) {
             let padding_length = 4 - length % 4;
             let padding = vec![0u8; padding_length];
             input.extend(padding);
         }
         // 如果开启了include_length 则在输入数据后面添加长度信息
         if self.include_length {
-            // length to u32 little-endian
-            let length_bytes = (length as u32).to_le_bytes();
-            input.extend_from_slice(&length_bytes);
-        }
-
-        // key 只取前16字节 如果key长度小于16 则用0填充
-        let mut key_bytes = self.key.to_vec();
-        if key_bytes.len() < 16 {
-            key_bytes.extend(vec![0u8; 16 - key_bytes.len()]);
-        } else {
-            key_bytes.truncate(16);
+            // 长度与数据使用相同的字节序
+            input.extend_from_slice(&from_words(&[length as u32], self.params.data_endian));
         }
 
-        // key转为u32数组
-        let key = key_bytes
-            .chunks(4)
-            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-            .collect::<Vec<u32>>();
+        let TeaParams {
+            delta,
+            key_endian,
+            data_endian,
+            ..
+        } = self.params;
+        let key = key_words(&self.key, key_endian);
 
         // 4字节一组 转为u32数组 进行加密
-        let mut v = input
-            .chunks(4)
-            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-            .collect::<Vec<u32>>();
+        let mut v = to_words(&input, data_endian);
 
         let n = v.len();
         if n == 0 {
             return Ok(Bytes::default());
         }
 
-        let rounds = 6 + 52 / n;
+        let rounds = self.params.rounds.unwrap_or(6 + 52 / n);
         let mut sum = 0u32;
         let mut e: usize;
         let mut y: u32;
         let mut z = v[n - 1];
         for _ in 0..rounds {
-            sum = sum.wrapping_add(DELTA);
+            sum = sum.wrapping_add(delta);
             e = (sum as usize >> 2) & 3;
             for p in 0..n - 1 {
                 y = v[p + 1];
@@ -80,10 +82,7 @@ impl Operation for XxteaEncrypt {
         }
 
         // 加密结果转为字节数组
-        let output = v
-            .iter()
-            .flat_map(|&v| v.to_le_bytes().to_vec())
-            .collect::<Vec<u8>>();
+        let output = from_words(&v, data_endian);
 
         Ok(Bytes::new(output))
     }
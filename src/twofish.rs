@@ -0,0 +1,279 @@
+use crate::bytes::Bytes;
+use crate::cipher::BlockCipher;
+use crate::enums::BlockSize;
+
+mod twofish_decrypt;
+mod twofish_encrypt;
+
+pub use twofish_decrypt::TwofishDecrypt;
+pub use twofish_encrypt::TwofishEncrypt;
+
+const ROUNDS: usize = 16;
+const BLOCK_SIZE: BlockSize = BlockSize::Bytes16;
+
+// 构造 q0 q1 置换所用的4位置换表
+const Q0_T: [[u8; 16]; 4] = [
+    [
+        0x8, 0x1, 0x7, 0xd, 0x6, 0xf, 0x3, 0x2, 0x0, 0xb, 0x5, 0x9, 0xe, 0xc, 0xa, 0x4,
+    ],
+    [
+        0xe, 0xc, 0xb, 0x8, 0x1, 0x2, 0x3, 0x5, 0xf, 0x4, 0xa, 0x6, 0x7, 0x0, 0x9, 0xd,
+    ],
+    [
+        0xb, 0xa, 0x5, 0xe, 0x6, 0xd, 0x9, 0x0, 0xc, 0x8, 0xf, 0x3, 0x2, 0x4, 0x7, 0x1,
+    ],
+    [
+        0xd, 0x7, 0xf, 0x4, 0x1, 0x2, 0x6, 0xe, 0x9, 0xb, 0x3, 0x0, 0x8, 0x5, 0xc, 0xa,
+    ],
+];
+
+const Q1_T: [[u8; 16]; 4] = [
+    [
+        0x2, 0x8, 0xb, 0xd, 0xf, 0x7, 0x6, 0xe, 0x3, 0x1, 0x9, 0x4, 0x0, 0xa, 0xc, 0x5,
+    ],
+    [
+        0x1, 0xe, 0x2, 0xb, 0x4, 0xc, 0x3, 0x7, 0x6, 0xd, 0xa, 0x5, 0xf, 0x9, 0x0, 0x8,
+    ],
+    [
+        0x4, 0xc, 0x7, 0x5, 0x1, 0x6, 0x9, 0xa, 0x0, 0xe, 0xd, 0x8, 0x2, 0xb, 0x3, 0xf,
+    ],
+    [
+        0xb, 0x9, 0x5, 0x1, 0xc, 0x3, 0xd, 0xe, 0x6, 0x4, 0x7, 0xf, 0x2, 0x0, 0x8, 0xa,
+    ],
+];
+
+// 最大距离可分矩阵 GF(2^8) 模 x^8 + x^6 + x^5 + x^3 + 1
+const MDS: [[u8; 4]; 4] = [
+    [0x01, 0xef, 0x5b, 0x5b],
+    [0x5b, 0xef, 0xef, 0x01],
+    [0xef, 0x5b, 0x01, 0xef],
+    [0xef, 0x01, 0xef, 0x5b],
+];
+const MDS_POLY: u16 = 0x169;
+
+// Reed-Solomon 矩阵 GF(2^8) 模 x^8 + x^6 + x^3 + x^2 + 1
+const RS: [[u8; 8]; 4] = [
+    [0x01, 0xa4, 0x55, 0x87, 0x5a, 0x58, 0xdb, 0x9e],
+    [0xa4, 0x56, 0x82, 0xf3, 0x1e, 0xc6, 0x68, 0xe5],
+    [0x02, 0xa1, 0xfc, 0xc1, 0x47, 0xae, 0x3d, 0x19],
+    [0xa4, 0x55, 0x87, 0x5a, 0x58, 0xdb, 0x9e, 0x03],
+];
+const RS_POLY: u16 = 0x14d;
+
+const RHO: u32 = 0x01010101;
+
+// 有限域乘法
+fn gf_mul(mut a: u8, mut b: u8, poly: u16) -> u8 {
+    let mut result = 0u8;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= (poly & 0xff) as u8;
+        }
+        b >>= 1;
+    }
+    result
+}
+
+fn q(table: &[[u8; 16]; 4], x: u8) -> u8 {
+    let ror4 = |x: u8| ((x >> 1) | (x << 3)) & 0xf;
+    let (a0, b0) = (x >> 4, x & 0xf);
+    let (a1, b1) = (a0 ^ b0, a0 ^ ror4(b0) ^ ((a0 << 3) & 0xf));
+    let (a2, b2) = (table[0][a1 as usize], table[1][b1 as usize]);
+    let (a3, b3) = (a2 ^ b2, a2 ^ ror4(b2) ^ ((a2 << 3) & 0xf));
+    let (a4, b4) = (table[2][a3 as usize], table[3][b3 as usize]);
+    (b4 << 4) | a4
+}
+
+fn q0(x: u8) -> u8 {
+    q(&Q0_T, x)
+}
+
+fn q1(x: u8) -> u8 {
+    q(&Q1_T, x)
+}
+
+// h 函数中第 i 个字节经过的置换 list 中的字按 L0, L1, ... 排列
+fn q_chain(i: usize, x: u8, list: &[u32]) -> u8 {
+    type Permutation = fn(u8) -> u8;
+    let l = |j: usize| list[j].to_le_bytes()[i];
+    let mut y = x;
+    if list.len() == 4 {
+        y = ([q1, q0, q0, q1] as [Permutation; 4])[i](y) ^ l(3);
+    }
+    if list.len() >= 3 {
+        y = ([q1, q1, q0, q0] as [Permutation; 4])[i](y) ^ l(2);
+    }
+    let chains: [(Permutation, Permutation, Permutation); 4] =
+        [(q0, q0, q1), (q1, q0, q0), (q0, q1, q1), (q1, q1, q0)];
+    let (first, second, third) = chains[i];
+    third(second(first(y) ^ l(1)) ^ l(0))
+}
+
+// 字节与 MDS 矩阵第 i 列相乘
+fn mds_column(i: usize, y: u8) -> u32 {
+    let column: Vec<u8> = MDS.iter().map(|row| gf_mul(row[i], y, MDS_POLY)).collect();
+    u32::from_le_bytes([column[0], column[1], column[2], column[3]])
+}
+
+fn h(x: u32, list: &[u32]) -> u32 {
+    x.to_le_bytes()
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, &y)| acc ^ mds_column(i, q_chain(i, y, list)))
+}
+
+#[derive(Debug)]
+pub struct Twofish {
+    sub_keys: [u32; 40],
+    // 与密钥相关的 S 盒 (已包含 MDS 乘法)
+    s_boxes: Box<[[u32; 256]; 4]>,
+}
+
+impl Twofish {
+    fn g(&self, x: u32) -> u32 {
+        let [a, b, c, d] = x.to_le_bytes();
+        self.s_boxes[0][a as usize]
+            ^ self.s_boxes[1][b as usize]
+            ^ self.s_boxes[2][c as usize]
+            ^ self.s_boxes[3][d as usize]
+    }
+
+    fn round_function(&self, r0: u32, r1: u32, round: usize) -> (u32, u32) {
+        let t0 = self.g(r0);
+        let t1 = self.g(r1.rotate_left(8));
+        let f0 = t0
+            .wrapping_add(t1)
+            .wrapping_add(self.sub_keys[2 * round + 8]);
+        let f1 = t0
+            .wrapping_add(t1.wrapping_mul(2))
+            .wrapping_add(self.sub_keys[2 * round + 9]);
+        (f0, f1)
+    }
+}
+
+fn to_words(block: &[u8]) -> [u32; 4] {
+    let mut words = [0u32; 4];
+    for (word, chunk) in words.iter_mut().zip(block.chunks(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
+
+fn from_words(words: [u32; 4]) -> Bytes {
+    Bytes::new(
+        words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<u8>>(),
+    )
+}
+
+impl BlockCipher for Twofish {
+    fn block_size(&self) -> BlockSize {
+        BLOCK_SIZE
+    }
+
+    fn encrypt_block(&self, block: &[u8]) -> Bytes {
+        let words = to_words(block);
+        // 输入白化
+        let mut r = [0u32; 4];
+        for i in 0..4 {
+            r[i] = words[i] ^ self.sub_keys[i];
+        }
+        for round in 0..ROUNDS {
+            let (f0, f1) = self.round_function(r[0], r[1], round);
+            let r2 = (r[2] ^ f0).rotate_right(1);
+            let r3 = r[3].rotate_left(1) ^ f1;
+            r = [r2, r3, r[0], r[1]];
+        }
+        // 撤销最后一次交换 并进行输出白化
+        from_words([
+            r[2] ^ self.sub_keys[4],
+            r[3] ^ self.sub_keys[5],
+            r[0] ^ self.sub_keys[6],
+            r[1] ^ self.sub_keys[7],
+        ])
+    }
+
+    fn decrypt_block(&self, block: &[u8]) -> Bytes {
+        let words = to_words(block);
+        let mut r = [
+            words[2] ^ self.sub_keys[6],
+            words[3] ^ self.sub_keys[7],
+            words[0] ^ self.sub_keys[4],
+            words[1] ^ self.sub_keys[5],
+        ];
+        for round in (0..ROUNDS).rev() {
+            // r = [r2', r3', r0, r1]
+            let (f0, f1) = self.round_function(r[2], r[3], round);
+            let r2 = r[0].rotate_left(1) ^ f0;
+            let r3 = (r[1] ^ f1).rotate_right(1);
+            r = [r[2], r[3], r2, r3];
+        }
+        from_words([
+            r[0] ^ self.sub_keys[0],
+            r[1] ^ self.sub_keys[1],
+            r[2] ^ self.sub_keys[2],
+            r[3] ^ self.sub_keys[3],
+        ])
+    }
+
+    // 密钥长度为 16, 24, 32 字节 其它长度补0到下一个标准长度
+    fn build(key: &[u8]) -> Self {
+        let mut key = key.to_vec();
+        let length = match key.len() {
+            0..=16 => 16,
+            17..=24 => 24,
+            _ => 32,
+        };
+        key.resize(length, 0);
+
+        // 偶数字与奇数字
+        let words: Vec<u32> = key
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        let even: Vec<u32> = words.iter().step_by(2).copied().collect();
+        let odd: Vec<u32> = words.iter().skip(1).step_by(2).copied().collect();
+
+        // 由 RS 矩阵计算 S 向量 使用时逆序
+        let mut s: Vec<u32> = key
+            .chunks(8)
+            .map(|chunk| {
+                let bytes: Vec<u8> = RS
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .zip(chunk)
+                            .fold(0u8, |acc, (&r, &m)| acc ^ gf_mul(r, m, RS_POLY))
+                    })
+                    .collect();
+                u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            })
+            .collect();
+        s.reverse();
+
+        let mut sub_keys = [0u32; 40];
+        for i in 0..20 {
+            let a = h(2 * i as u32 * RHO, &even);
+            let b = h((2 * i as u32 + 1) * RHO, &odd).rotate_left(8);
+            sub_keys[2 * i] = a.wrapping_add(b);
+            sub_keys[2 * i + 1] = a.wrapping_add(b.wrapping_mul(2)).rotate_left(9);
+        }
+
+        // 预先计算 g 函数中每个字节对应的结果
+        let mut s_boxes = Box::new([[0u32; 256]; 4]);
+        for (i, s_box) in s_boxes.iter_mut().enumerate() {
+            for (x, entry) in s_box.iter_mut().enumerate() {
+                *entry = mds_column(i, q_chain(i, x as u8, &s));
+            }
+        }
+
+        Self { sub_keys, s_boxes }
+    }
+}
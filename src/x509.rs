@@ -0,0 +1,278 @@
+use crate::asn1::{read_header, Asn1, Oid, Tag, TagClass};
+use crate::bigint::BigInt;
+use crate::bytes::Bytes;
+use crate::enums::Case;
+use crate::hex::ToHex;
+use crate::key::{parse_algorithm, PublicKey};
+use crate::operation::Operation;
+use crate::pem::Pem;
+use crate::types::Result;
+
+mod extension;
+mod name;
+mod signature;
+mod x509_parse;
+mod x509_verify;
+
+pub use extension::Extension;
+pub use name::Name;
+pub use x509_parse::X509Parse;
+pub use x509_verify::X509Verify;
+
+const SIGNED_DATA: &str = "1.2.840.113549.1.7.2";
+
+// Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signatureValue BIT STRING }
+#[derive(Clone, Debug)]
+pub struct Certificate {
+    pub der: Bytes,
+    // 被签名的 tbsCertificate 原始编码
+    pub tbs_certificate: Bytes,
+    pub version: u64,
+    pub serial_number: BigInt,
+    pub signature_algorithm: Oid,
+    pub signature_parameters: Option<Asn1>,
+    pub issuer: Name,
+    pub subject: Name,
+    pub not_before: String,
+    pub not_after: String,
+    pub subject_public_key_info: Bytes,
+    pub extensions: Vec<Extension>,
+    pub signature: Bytes,
+}
+
+impl Certificate {
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let asn1 = Asn1::from_ber(der)?;
+        let tbs = asn1
+            .expect(Tag::SEQUENCE)?
+            .child(0)?
+            .expect(Tag::SEQUENCE)?;
+
+        // 签名针对原始编码, 不重新编码以兼容不严格的 DER
+        let outer = read_header(der, false)?;
+        let inner = read_header(&der[outer.header_len..], false)?;
+        let tbs_certificate = match inner.length {
+            Some(length) => {
+                let start = outer.header_len;
+                Bytes::new(&der[start..start + inner.header_len + length])
+            }
+            None => tbs.to_der(),
+        };
+
+        // TBSCertificate ::= SEQUENCE { version [0] DEFAULT v1, serialNumber, signature, issuer,
+        //     validity, subject, subjectPublicKeyInfo, issuerUniqueID [1], subjectUniqueID [2], extensions [3] }
+        let mut version = 1;
+        let mut fields = tbs.children.iter().peekable();
+        if let Some(first) = fields.next_if(|field| field.tag.matches(&Tag::context(0, true))) {
+            version = first.child(0)?.as_u64()? + 1;
+        }
+        let mut next = || fields.next().ok_or("[X509] truncated tbsCertificate");
+        let serial_number = next()?.expect(Tag::INTEGER)?.as_integer()?;
+        next()?;
+        let issuer = Name::from_asn1(next()?)?;
+        let validity = next()?.expect(Tag::SEQUENCE)?;
+        let not_before = format_time(validity.child(0)?)?;
+        let not_after = format_time(validity.child(1)?)?;
+        let subject = Name::from_asn1(next()?)?;
+        let subject_public_key_info = next()?.expect(Tag::SEQUENCE)?.to_der();
+
+        let mut extensions = vec![];
+        for field in fields {
+            if field.tag.matches(&Tag::context(3, true)) {
+                for extension in &field.child(0)?.expect(Tag::SEQUENCE)?.children {
+                    extensions.push(Extension::from_asn1(extension)?);
+                }
+            }
+        }
+
+        let (signature_algorithm, signature_parameters) = parse_algorithm(asn1.child(1)?)?;
+        let (signature, _) = asn1.child(2)?.expect(Tag::BIT_STRING)?.as_bit_string()?;
+        Ok(Self {
+            der: Bytes::new(der),
+            tbs_certificate,
+            version,
+            serial_number,
+            signature_algorithm,
+            signature_parameters: signature_parameters.cloned(),
+            issuer,
+            subject,
+            not_before,
+            not_after,
+            subject_public_key_info,
+            extensions,
+            signature,
+        })
+    }
+
+    pub fn from_pem(text: &str) -> Result<Self> {
+        Pem::parse_all(text)?
+            .into_iter()
+            .find(|pem| pem.label == "CERTIFICATE" || pem.label == "X509 CERTIFICATE")
+            .ok_or("[X509] no certificate found in PEM")
+            .map(|pem| Self::from_der(&pem.contents))?
+    }
+
+    // 读取输入中的全部证书: PEM (可含多个块)、DER、相邻的多个 DER 以及 PKCS#7 SignedData
+    // 如 APK 中的 META-INF/*.RSA
+    pub fn parse_all(input: &[u8]) -> Result<Vec<Self>> {
+        if let Ok(text) = std::str::from_utf8(input) {
+            if text.contains("-----BEGIN ") {
+                let mut certificates = vec![];
+                for pem in Pem::parse_all(text)? {
+                    match pem.label.as_str() {
+                        "CERTIFICATE" | "X509 CERTIFICATE" | "TRUSTED CERTIFICATE" => {
+                            certificates.push(Self::from_der(&pem.contents)?)
+                        }
+                        "PKCS7" | "CMS" => certificates.extend(Self::parse_all(&pem.contents)?),
+                        _ => {}
+                    }
+                }
+                return Ok(certificates);
+            }
+        }
+
+        let mut certificates = vec![];
+        let mut position = 0;
+        while position < input.len() {
+            let header = read_header(&input[position..], false)?;
+            let length = match header.length {
+                Some(length) => header.header_len + length,
+                None => input.len() - position,
+            };
+            let element = input
+                .get(position..position + length)
+                .ok_or("[X509] truncated input")?;
+            let asn1 = Asn1::from_ber(element)?;
+            if asn1.child(0)?.tag == Tag::OBJECT_IDENTIFIER {
+                certificates.extend(signed_data_certificates(&asn1)?);
+            } else {
+                certificates.push(Self::from_der(element)?);
+            }
+            position += length;
+        }
+        Ok(certificates)
+    }
+
+    pub fn public_key(&self) -> Result<PublicKey> {
+        PublicKey::from_der(&self.subject_public_key_info)
+    }
+
+    // 用颁发者证书的公钥验证本证书的签名
+    pub fn verify_signature(&self, issuer: &Certificate) -> Result<bool> {
+        self.verify_signature_with_sm2_id(issuer, None)
+    }
+
+    // 指定 SM2 签名的用户 ID, 为 None 时依次尝试默认 ID 与空 ID
+    pub fn verify_signature_with_sm2_id(
+        &self,
+        issuer: &Certificate,
+        sm2_user_id: Option<&[u8]>,
+    ) -> Result<bool> {
+        signature::verify(
+            &issuer.public_key()?,
+            &self.signature_algorithm,
+            self.signature_parameters.as_ref(),
+            &self.tbs_certificate,
+            &self.signature,
+            sm2_user_id,
+        )
+    }
+
+    pub fn is_self_signed(&self) -> bool {
+        self.issuer.matches(&self.subject)
+    }
+
+    pub fn extension(&self, oid: &str) -> Option<&Extension> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.oid.to_string() == oid)
+    }
+
+    // 形如 "DNS:example.com", "IP Address:127.0.0.1"
+    pub fn subject_alt_names(&self) -> Result<Vec<String>> {
+        match self.extension(extension::SUBJECT_ALT_NAME) {
+            Some(extension) => extension::general_names(&extension.value),
+            None => Ok(vec![]),
+        }
+    }
+
+    // 形如 "Digital Signature", "Certificate Sign"
+    pub fn key_usage(&self) -> Result<Vec<&'static str>> {
+        match self.extension(extension::KEY_USAGE) {
+            Some(extension) => extension::key_usage(&extension.value),
+            None => Ok(vec![]),
+        }
+    }
+
+    // 返回 (cA, pathLenConstraint), 无此扩展时为 None
+    pub fn basic_constraints(&self) -> Result<Option<(bool, Option<u64>)>> {
+        self.extension(extension::BASIC_CONSTRAINTS)
+            .map(|extension| extension::basic_constraints(&extension.value))
+            .transpose()
+    }
+}
+
+// ContentInfo ::= SEQUENCE { contentType, [0] EXPLICIT SignedData }
+// SignedData ::= SEQUENCE { version, digestAlgorithms, encapContentInfo, certificates [0] IMPLICIT OPTIONAL, ... }
+fn signed_data_certificates(asn1: &Asn1) -> Result<Vec<Certificate>> {
+    let content_type = asn1.child(0)?.as_oid()?;
+    if content_type.to_string() != SIGNED_DATA {
+        return Err(format!("[X509] unsupported content type {}", content_type).into());
+    }
+    let signed_data = asn1.child(1)?.child(0)?.expect(Tag::SEQUENCE)?;
+    let mut certificates = vec![];
+    for field in &signed_data.children[1..] {
+        if field.tag.matches(&Tag::context(0, true)) {
+            // 其中的证书通常为 DER, 重新编码即为原始编码
+            for certificate in field.children.iter().filter(|c| c.tag == Tag::SEQUENCE) {
+                certificates.push(Certificate::from_der(&certificate.to_der())?);
+            }
+        }
+    }
+    Ok(certificates)
+}
+
+// UTCTime 与 GeneralizedTime 转为 "2024-01-31 12:00:00 UTC"
+fn format_time(asn1: &Asn1) -> Result<String> {
+    let text = asn1.as_string()?;
+    let invalid = || format!("[X509] invalid time {}", text);
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let (date, zone) = text.split_at(digits);
+    let date = match (asn1.tag.number, date.len()) {
+        // UTCTime 的两位年份 50-99 表示 19xx
+        (23, 10 | 12) if date < "50" => format!("20{}", date),
+        (23, 10 | 12) => format!("19{}", date),
+        (24, 10 | 12 | 14) => date.to_string(),
+        _ => return Err(invalid().into()),
+    };
+    // 省略的分钟与秒补零, 忽略秒的小数部分
+    let date = format!("{:0<14}", date);
+    let zone = match zone.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit()) {
+        "Z" => " UTC".to_string(),
+        "" => String::new(),
+        offset if offset.starts_with(['+', '-']) => format!(" {}", offset),
+        _ => return Err(invalid().into()),
+    };
+    Ok(format!(
+        "{}-{}-{} {}:{}:{}{}",
+        &date[0..4],
+        &date[4..6],
+        &date[6..8],
+        &date[8..10],
+        &date[10..12],
+        &date[12..14],
+        zone
+    ))
+}
+
+// 形如 "AB:CD:EF"
+fn colon_hex(bytes: &[u8]) -> String {
+    let hex = ToHex::new(":", "", Case::Upper)
+        .run(bytes)
+        .unwrap_or_default();
+    String::from_utf8_lossy(&hex).into_owned()
+}
+
+fn is_string(tag: &Tag) -> bool {
+    tag.class == TagClass::Universal && matches!(tag.number, 12 | 18..=22 | 26..=28 | 30)
+}
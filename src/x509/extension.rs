@@ -0,0 +1,221 @@
+use crate::asn1::{Asn1, Oid, Tag, TagClass};
+use crate::bytes::Bytes;
+use crate::key::oid_name;
+use crate::types::Result;
+use crate::x509::{colon_hex, is_string, Name};
+
+pub(super) const SUBJECT_KEY_IDENTIFIER: &str = "2.5.29.14";
+pub(super) const KEY_USAGE: &str = "2.5.29.15";
+pub(super) const SUBJECT_ALT_NAME: &str = "2.5.29.17";
+pub(super) const ISSUER_ALT_NAME: &str = "2.5.29.18";
+pub(super) const BASIC_CONSTRAINTS: &str = "2.5.29.19";
+pub(super) const CRL_DISTRIBUTION_POINTS: &str = "2.5.29.31";
+pub(super) const CERTIFICATE_POLICIES: &str = "2.5.29.32";
+pub(super) const AUTHORITY_KEY_IDENTIFIER: &str = "2.5.29.35";
+pub(super) const EXTENDED_KEY_USAGE: &str = "2.5.29.37";
+pub(super) const AUTHORITY_INFO_ACCESS: &str = "1.3.6.1.5.5.7.1.1";
+
+// KeyUsage 各比特的名称 (与 OpenSSL 一致)
+const KEY_USAGES: [&str; 9] = [
+    "Digital Signature",
+    "Non Repudiation",
+    "Key Encipherment",
+    "Data Encipherment",
+    "Key Agreement",
+    "Certificate Sign",
+    "CRL Sign",
+    "Encipher Only",
+    "Decipher Only",
+];
+
+// Extension ::= SEQUENCE { extnID, critical BOOLEAN DEFAULT FALSE, extnValue OCTET STRING }
+#[derive(Clone, Debug)]
+pub struct Extension {
+    pub oid: Oid,
+    pub critical: bool,
+    // extnValue 中的 DER 编码
+    pub value: Bytes,
+}
+
+impl Extension {
+    pub(super) fn from_asn1(asn1: &Asn1) -> Result<Self> {
+        let oid = asn1.expect(Tag::SEQUENCE)?.child(0)?.as_oid()?;
+        let (critical, value) = match asn1.children.len() {
+            2 => (false, asn1.child(1)?),
+            _ => (asn1.child(1)?.as_bool()?, asn1.child(2)?),
+        };
+        let value = value.expect(Tag::OCTET_STRING)?.as_octet_string()?;
+        Ok(Self {
+            oid,
+            critical,
+            value,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        oid_name(&self.oid)
+    }
+
+    // 可读的扩展值, 可能有多行, 未知的扩展输出十六进制
+    pub fn to_text(&self) -> Result<String> {
+        let value = &self.value;
+        let text = match self.oid.to_string().as_str() {
+            BASIC_CONSTRAINTS => {
+                let (ca, path_len) = basic_constraints(value)?;
+                let ca = if ca { "CA:TRUE" } else { "CA:FALSE" };
+                match path_len {
+                    Some(path_len) => format!("{}, pathlen:{}", ca, path_len),
+                    None => ca.to_string(),
+                }
+            }
+            KEY_USAGE => key_usage(value)?.join(", "),
+            EXTENDED_KEY_USAGE => {
+                let mut usages = vec![];
+                for usage in &Asn1::from_ber(value)?.expect(Tag::SEQUENCE)?.children {
+                    usages.push(oid_name(&usage.as_oid()?));
+                }
+                usages.join(", ")
+            }
+            SUBJECT_ALT_NAME | ISSUER_ALT_NAME => general_names(value)?.join(", "),
+            SUBJECT_KEY_IDENTIFIER => colon_hex(&Asn1::from_ber(value)?.as_octet_string()?),
+            // AuthorityKeyIdentifier ::= SEQUENCE { keyIdentifier [0], authorityCertIssuer [1], serial [2] }
+            AUTHORITY_KEY_IDENTIFIER => {
+                let mut lines = vec![];
+                for field in &Asn1::from_ber(value)?.expect(Tag::SEQUENCE)?.children {
+                    match field.tag.number {
+                        0 => lines.push(colon_hex(&field.as_octet_string()?)),
+                        1 => {
+                            for name in &field.children {
+                                lines.push(general_name(name)?);
+                            }
+                        }
+                        _ => lines.push(format!("serial:{}", colon_hex(&field.content))),
+                    }
+                }
+                lines.join("\n")
+            }
+            // AccessDescription ::= SEQUENCE { accessMethod, accessLocation GeneralName }
+            AUTHORITY_INFO_ACCESS => {
+                let mut lines = vec![];
+                for access in &Asn1::from_ber(value)?.expect(Tag::SEQUENCE)?.children {
+                    let method = oid_name(&access.child(0)?.as_oid()?);
+                    lines.push(format!("{} - {}", method, general_name(access.child(1)?)?));
+                }
+                lines.join("\n")
+            }
+            // DistributionPoint ::= SEQUENCE { distributionPoint [0] { fullName [0] GeneralNames, ... }, ... }
+            CRL_DISTRIBUTION_POINTS => {
+                let mut lines = vec![];
+                for point in &Asn1::from_ber(value)?.expect(Tag::SEQUENCE)?.children {
+                    for field in point.children.iter().filter(|f| f.tag.number == 0) {
+                        for full_name in field.children.iter().filter(|f| f.tag.number == 0) {
+                            for name in &full_name.children {
+                                lines.push(general_name(name)?);
+                            }
+                        }
+                    }
+                }
+                lines.join("\n")
+            }
+            // PolicyInformation ::= SEQUENCE { policyIdentifier, policyQualifiers OPTIONAL }
+            CERTIFICATE_POLICIES => {
+                let mut lines = vec![];
+                for policy in &Asn1::from_ber(value)?.expect(Tag::SEQUENCE)?.children {
+                    lines.push(format!("Policy: {}", oid_name(&policy.child(0)?.as_oid()?)));
+                }
+                lines.join("\n")
+            }
+            _ => colon_hex(value),
+        };
+        Ok(text)
+    }
+}
+
+// BasicConstraints ::= SEQUENCE { cA BOOLEAN DEFAULT FALSE, pathLenConstraint INTEGER OPTIONAL }
+pub(super) fn basic_constraints(value: &[u8]) -> Result<(bool, Option<u64>)> {
+    let asn1 = Asn1::from_ber(value)?;
+    let mut ca = false;
+    let mut path_len = None;
+    for field in &asn1.expect(Tag::SEQUENCE)?.children {
+        match field.tag {
+            Tag::BOOLEAN => ca = field.as_bool()?,
+            _ => path_len = Some(field.expect(Tag::INTEGER)?.as_u64()?),
+        }
+    }
+    Ok((ca, path_len))
+}
+
+// KeyUsage ::= BIT STRING, 第 0 位为最高位
+pub(super) fn key_usage(value: &[u8]) -> Result<Vec<&'static str>> {
+    let (bits, _) = Asn1::from_ber(value)?
+        .expect(Tag::BIT_STRING)?
+        .as_bit_string()?;
+    Ok(KEY_USAGES
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| bits.get(i / 8).is_some_and(|b| b & (0x80 >> (i % 8)) != 0))
+        .map(|(_, name)| *name)
+        .collect())
+}
+
+// GeneralNames ::= SEQUENCE OF GeneralName
+pub(super) fn general_names(value: &[u8]) -> Result<Vec<String>> {
+    Asn1::from_ber(value)?
+        .expect(Tag::SEQUENCE)?
+        .children
+        .iter()
+        .map(general_name)
+        .collect()
+}
+
+// GeneralName 为隐式标记的 CHOICE, 前缀与 OpenSSL 一致
+fn general_name(asn1: &Asn1) -> Result<String> {
+    if asn1.tag.class != TagClass::ContextSpecific {
+        return Err("[X509] invalid GeneralName".into());
+    }
+    let text =
+        || -> Result<String> { Ok(String::from_utf8_lossy(&asn1.as_octet_string()?).into_owned()) };
+    let name = match asn1.tag.number {
+        // OtherName ::= SEQUENCE { type-id, value [0] EXPLICIT ANY }
+        0 => {
+            let value = asn1.child(1)?.child(0)?;
+            let value = if is_string(&value.tag) {
+                value.as_string()?
+            } else {
+                colon_hex(&value.to_der())
+            };
+            format!(
+                "othername:{}::{}",
+                oid_name(&asn1.child(0)?.as_oid()?),
+                value
+            )
+        }
+        1 => format!("email:{}", text()?),
+        2 => format!("DNS:{}", text()?),
+        4 => format!("DirName:{}", Name::from_asn1(asn1.child(0)?)?),
+        6 => format!("URI:{}", text()?),
+        7 => format!("IP Address:{}", ip_address(&asn1.as_octet_string()?)),
+        8 => format!("Registered ID:{}", oid_name(&Oid::from_der(&asn1.content)?)),
+        3 => "X400Name:<unsupported>".to_string(),
+        5 => "EdiPartyName:<unsupported>".to_string(),
+        _ => return Err("[X509] invalid GeneralName".into()),
+    };
+    Ok(name)
+}
+
+// IPv6 地址与 OpenSSL 一样不压缩连续的 0
+fn ip_address(bytes: &[u8]) -> String {
+    match bytes.len() {
+        4 => bytes
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join("."),
+        16 => bytes
+            .chunks(2)
+            .map(|c| format!("{:X}", u16::from_be_bytes([c[0], c[1]])))
+            .collect::<Vec<_>>()
+            .join(":"),
+        _ => format!("<invalid {}>", colon_hex(bytes)),
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use crate::asn1::{Asn1, Oid, Tag};
+use crate::types::Result;
+use crate::x509::{colon_hex, is_string};
+
+// 与 OpenSSL 相同的属性短名称
+const SHORT_NAMES: &[(&str, &str)] = &[
+    ("2.5.4.3", "CN"),
+    ("2.5.4.4", "SN"),
+    ("2.5.4.5", "serialNumber"),
+    ("2.5.4.6", "C"),
+    ("2.5.4.7", "L"),
+    ("2.5.4.8", "ST"),
+    ("2.5.4.9", "street"),
+    ("2.5.4.10", "O"),
+    ("2.5.4.11", "OU"),
+    ("2.5.4.12", "title"),
+    ("2.5.4.42", "GN"),
+    ("0.9.2342.19200300.100.1.1", "UID"),
+    ("0.9.2342.19200300.100.1.25", "DC"),
+    ("1.2.840.113549.1.9.1", "emailAddress"),
+];
+
+// Name ::= SEQUENCE OF RelativeDistinguishedName
+// RelativeDistinguishedName ::= SET OF AttributeTypeAndValue
+#[derive(Clone, Debug, Default)]
+pub struct Name {
+    pub rdns: Vec<Vec<(Oid, String)>>,
+}
+
+impl Name {
+    pub fn from_asn1(asn1: &Asn1) -> Result<Self> {
+        let mut rdns = vec![];
+        for rdn in &asn1.expect(Tag::SEQUENCE)?.children {
+            let mut attributes = vec![];
+            for attribute in &rdn.expect(Tag::SET)?.children {
+                let oid = attribute.expect(Tag::SEQUENCE)?.child(0)?.as_oid()?;
+                let value = attribute.child(1)?;
+                // 非字符串的值按 RFC 4514 输出为 # 加 DER 编码
+                let text = if is_string(&value.tag) {
+                    value.as_string()?
+                } else {
+                    format!("#{}", colon_hex(&value.to_der()).replace(':', ""))
+                };
+                attributes.push((oid, text));
+            }
+            rdns.push(attributes);
+        }
+        Ok(Self { rdns })
+    }
+
+    // 第一个指定类型的属性值, 如 "2.5.4.3" (commonName)
+    pub fn get(&self, oid: &str) -> Option<&str> {
+        self.rdns
+            .iter()
+            .flatten()
+            .find(|(attribute, _)| attribute.to_string() == oid)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // 简化的 RFC 5280 名称比较: 忽略大小写及首尾和连续的空白
+    pub fn matches(&self, other: &Self) -> bool {
+        let normalize = |value: &str| {
+            value
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        };
+        self.rdns.len() == other.rdns.len()
+            && self.rdns.iter().zip(&other.rdns).all(|(a, b)| {
+                a.len() == b.len()
+                    && a.iter().zip(b).all(|((oid_a, value_a), (oid_b, value_b))| {
+                        oid_a == oid_b && normalize(value_a) == normalize(value_b)
+                    })
+            })
+    }
+}
+
+// 形如 "C=CN, O=Example, CN=example.com"
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rdns: Vec<String> = self
+            .rdns
+            .iter()
+            .map(|rdn| {
+                rdn.iter()
+                    .map(|(oid, value)| format!("{}={}", short_name(oid), value))
+                    .collect::<Vec<_>>()
+                    .join(" + ")
+            })
+            .collect();
+        write!(f, "{}", rdns.join(", "))
+    }
+}
+
+fn short_name(oid: &Oid) -> String {
+    let dotted = oid.to_string();
+    match SHORT_NAMES.iter().find(|(name, _)| *name == dotted) {
+        Some((_, short)) => short.to_string(),
+        None => oid.name().map_or(dotted, str::to_string),
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Debug;
+
+use crate::asn1::{Asn1, Oid, Tag};
+use crate::curve25519::Ed25519Verify;
+use crate::ec::{EcCurve, EcdsaVerify};
+use crate::enums::Endian;
+use crate::key::{oid_name, parse_algorithm, PublicKey};
+use crate::md5::Md5;
+use crate::operation::Hashing;
+use crate::rsa::{Pkcs1v15Signature, Pss, RsaPublicKey, RsaVerify};
+use crate::sha1::Sha1;
+use crate::sha2::{Sha256, Sha384, Sha512};
+use crate::sm2::{Sm2Verify, DEFAULT_USER_ID};
+use crate::types::Result;
+
+const MD5_WITH_RSA: &str = "1.2.840.113549.1.1.4";
+const SHA1_WITH_RSA: &str = "1.2.840.113549.1.1.5";
+const RSASSA_PSS: &str = "1.2.840.113549.1.1.10";
+const SHA256_WITH_RSA: &str = "1.2.840.113549.1.1.11";
+const SHA384_WITH_RSA: &str = "1.2.840.113549.1.1.12";
+const SHA512_WITH_RSA: &str = "1.2.840.113549.1.1.13";
+const ECDSA_WITH_SHA1: &str = "1.2.840.10045.4.1";
+const ECDSA_WITH_SHA256: &str = "1.2.840.10045.4.3.2";
+const ECDSA_WITH_SHA384: &str = "1.2.840.10045.4.3.3";
+const ECDSA_WITH_SHA512: &str = "1.2.840.10045.4.3.4";
+const SM2_WITH_SM3: &str = "1.2.156.10197.1.501";
+const ED25519: &str = "1.3.101.112";
+
+const SHA1: &str = "1.3.14.3.2.26";
+const SHA256: &str = "2.16.840.1.101.3.4.2.1";
+const SHA384: &str = "2.16.840.1.101.3.4.2.2";
+const SHA512: &str = "2.16.840.1.101.3.4.2.3";
+const MGF1: &str = "1.2.840.113549.1.1.8";
+
+// 签名值不合法时返回 false, 不支持的算法或与公钥类型不符时返回错误
+// 未指定 SM2 用户 ID 时先尝试 GM/T 0009 默认 ID, 再尝试空 ID (OpenSSL 3 的默认值)
+pub(super) fn verify(
+    public_key: &PublicKey,
+    algorithm: &Oid,
+    parameters: Option<&Asn1>,
+    message: &[u8],
+    signature: &[u8],
+    sm2_user_id: Option<&[u8]>,
+) -> Result<bool> {
+    match (algorithm.to_string().as_str(), public_key) {
+        (MD5_WITH_RSA, PublicKey::Rsa(key)) => verify_pkcs1v15::<Md5>(key, message, signature),
+        (SHA1_WITH_RSA, PublicKey::Rsa(key)) => verify_pkcs1v15::<Sha1>(key, message, signature),
+        (SHA256_WITH_RSA, PublicKey::Rsa(key)) => {
+            verify_pkcs1v15::<Sha256>(key, message, signature)
+        }
+        (SHA384_WITH_RSA, PublicKey::Rsa(key)) => {
+            verify_pkcs1v15::<Sha384>(key, message, signature)
+        }
+        (SHA512_WITH_RSA, PublicKey::Rsa(key)) => {
+            verify_pkcs1v15::<Sha512>(key, message, signature)
+        }
+        (RSASSA_PSS, PublicKey::Rsa(key)) => verify_pss(key, parameters, message, signature),
+        (ECDSA_WITH_SHA1, PublicKey::Ec(curve, point)) => {
+            verify_ecdsa::<Sha1>(curve, point, message, signature)
+        }
+        (ECDSA_WITH_SHA256, PublicKey::Ec(curve, point)) => {
+            verify_ecdsa::<Sha256>(curve, point, message, signature)
+        }
+        (ECDSA_WITH_SHA384, PublicKey::Ec(curve, point)) => {
+            verify_ecdsa::<Sha384>(curve, point, message, signature)
+        }
+        (ECDSA_WITH_SHA512, PublicKey::Ec(curve, point)) => {
+            verify_ecdsa::<Sha512>(curve, point, message, signature)
+        }
+        (SM2_WITH_SM3, PublicKey::Sm2(point)) => {
+            let Some(signature) = raw_signature(signature, 32) else {
+                return Ok(false);
+            };
+            let user_ids = match sm2_user_id {
+                Some(user_id) => vec![user_id],
+                None => vec![DEFAULT_USER_ID, b""],
+            };
+            for user_id in user_ids {
+                if Sm2Verify::new(point, user_id, &signature).verify(message)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        (ED25519, PublicKey::Ed25519(point)) => match signature.len() {
+            64 => Ed25519Verify::new(point, signature).verify(message),
+            _ => Ok(false),
+        },
+        (
+            MD5_WITH_RSA | SHA1_WITH_RSA | SHA256_WITH_RSA | SHA384_WITH_RSA | SHA512_WITH_RSA
+            | RSASSA_PSS | ECDSA_WITH_SHA1 | ECDSA_WITH_SHA256 | ECDSA_WITH_SHA384
+            | ECDSA_WITH_SHA512 | SM2_WITH_SM3 | ED25519,
+            _,
+        ) => Err(format!(
+            "[X509] issuer key does not match the signature algorithm {}",
+            oid_name(algorithm)
+        )
+        .into()),
+        _ => Err(format!(
+            "[X509] unsupported signature algorithm {}",
+            oid_name(algorithm)
+        )
+        .into()),
+    }
+}
+
+fn verify_pkcs1v15<H: Hashing + Debug>(
+    key: &RsaPublicKey,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    RsaVerify::new(key, Pkcs1v15Signature::<H>::new(), signature).verify(message)
+}
+
+// RSASSA-PSS-params ::= SEQUENCE { hashAlgorithm [0] DEFAULT sha1,
+//     maskGenAlgorithm [1] DEFAULT mgf1SHA1, saltLength [2] DEFAULT 20, trailerField [3] DEFAULT 1 }
+fn verify_pss(
+    key: &RsaPublicKey,
+    parameters: Option<&Asn1>,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    let mut hash = SHA1.to_string();
+    let mut mgf_hash = SHA1.to_string();
+    let mut salt_length = 20;
+    let fields = match parameters {
+        Some(parameters) => parameters.expect(Tag::SEQUENCE)?.children.as_slice(),
+        None => &[],
+    };
+    for field in fields {
+        match field.tag.number {
+            0 => hash = parse_algorithm(field.child(0)?)?.0.to_string(),
+            1 => {
+                let (mgf, mgf_parameters) = parse_algorithm(field.child(0)?)?;
+                if mgf.to_string() != MGF1 {
+                    return Err(
+                        format!("[X509] unsupported mask generation function {}", mgf).into(),
+                    );
+                }
+                let mgf_parameters = mgf_parameters.ok_or("[X509] missing MGF1 parameters")?;
+                mgf_hash = parse_algorithm(mgf_parameters)?.0.to_string();
+            }
+            2 => salt_length = field.child(0)?.as_u64()? as usize,
+            _ => {}
+        }
+    }
+    if hash != mgf_hash {
+        return Err("[X509] PSS with different message and MGF1 hashes is not supported".into());
+    }
+    match hash.as_str() {
+        SHA1 => verify_pss_with::<Sha1>(key, salt_length, message, signature),
+        SHA256 => verify_pss_with::<Sha256>(key, salt_length, message, signature),
+        SHA384 => verify_pss_with::<Sha384>(key, salt_length, message, signature),
+        SHA512 => verify_pss_with::<Sha512>(key, salt_length, message, signature),
+        _ => Err(format!("[X509] unsupported PSS hash {}", hash).into()),
+    }
+}
+
+fn verify_pss_with<H: Hashing + Debug>(
+    key: &RsaPublicKey,
+    salt_length: usize,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    RsaVerify::new(key, Pss::<H>::with_salt_length(salt_length), signature).verify(message)
+}
+
+fn verify_ecdsa<H: Hashing>(
+    curve: &EcCurve,
+    point: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    match raw_signature(signature, curve.scalar_size()) {
+        Some(signature) => EcdsaVerify::<H>::new(curve, point, &signature).verify(message),
+        None => Ok(false),
+    }
+}
+
+// Ecdsa-Sig-Value ::= SEQUENCE { r INTEGER, s INTEGER } 转为 r || s
+fn raw_signature(signature: &[u8], size: usize) -> Option<Vec<u8>> {
+    let asn1 = Asn1::from_ber(signature).ok()?;
+    let mut raw = vec![];
+    for i in 0..2 {
+        let value = asn1.child(i).ok()?.as_biguint().ok()?;
+        raw.extend(value.to_bytes_padded(size, Endian::Big).ok()?.iter());
+    }
+    Some(raw)
+}
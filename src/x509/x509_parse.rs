@@ -0,0 +1,100 @@
+use crate::asn1::{Asn1, Tag};
+use crate::bytes::Bytes;
+use crate::enums::Endian;
+use crate::key::{oid_name, parse_algorithm, PublicKey};
+use crate::operation::Operation;
+use crate::sha1::Sha1;
+use crate::sha2::Sha256;
+use crate::types::Result;
+use crate::x509::{colon_hex, Certificate};
+
+// 输入 PEM / DER 证书或 PKCS#7 (如 APK 中的 META-INF/*.RSA), 输出证书的可读字段
+// 多个证书之间以空行分隔
+#[derive(Debug, Default)]
+pub struct X509Parse {
+    // 同时输出签名值
+    pub show_signature: bool,
+}
+
+impl X509Parse {
+    pub fn new(show_signature: bool) -> Self {
+        Self { show_signature }
+    }
+
+    fn print(&self, output: &mut String, certificate: &Certificate) -> Result<()> {
+        let serial_number = certificate.serial_number.to_signed_bytes(Endian::Big);
+        output.push_str(&format!("Version: {}\n", certificate.version));
+        output.push_str(&format!("Serial Number: {}\n", colon_hex(&serial_number)));
+        output.push_str(&format!(
+            "Signature Algorithm: {}\n",
+            oid_name(&certificate.signature_algorithm)
+        ));
+        output.push_str(&format!("Issuer: {}\n", certificate.issuer));
+        output.push_str("Validity:\n");
+        output.push_str(&format!("    Not Before: {}\n", certificate.not_before));
+        output.push_str(&format!("    Not After : {}\n", certificate.not_after));
+        output.push_str(&format!("Subject: {}\n", certificate.subject));
+        output.push_str(&format!(
+            "Subject Public Key: {}\n",
+            public_key_description(certificate)?
+        ));
+
+        if !certificate.extensions.is_empty() {
+            output.push_str("Extensions:\n");
+        }
+        for extension in &certificate.extensions {
+            let critical = if extension.critical { " critical" } else { "" };
+            output.push_str(&format!("    {}:{}\n", extension.name(), critical));
+            for line in extension.to_text()?.lines() {
+                output.push_str(&format!("        {}\n", line));
+            }
+        }
+
+        if self.show_signature {
+            output.push_str("Signature Value:\n");
+            for chunk in certificate.signature.chunks(18) {
+                output.push_str(&format!("    {}\n", colon_hex(chunk)));
+            }
+        }
+        let sha1 = Sha1::default().run(&certificate.der)?;
+        let sha256 = Sha256.run(&certificate.der)?;
+        output.push_str(&format!("SHA1 Fingerprint: {}\n", colon_hex(&sha1)));
+        output.push_str(&format!("SHA256 Fingerprint: {}\n", colon_hex(&sha256)));
+        Ok(())
+    }
+}
+
+impl Operation for X509Parse {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let certificates = Certificate::parse_all(input)?;
+        if certificates.is_empty() {
+            return Err("[X509Parse] no certificate found".into());
+        }
+        let mut output = String::new();
+        for (i, certificate) in certificates.iter().enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            self.print(&mut output, certificate)?;
+        }
+        Ok(Bytes::new(output.as_bytes()))
+    }
+}
+
+// 如 "rsaEncryption (2048 bit)", "id-ecPublicKey (prime256v1)"
+// 不支持的公钥算法只输出名称
+fn public_key_description(certificate: &Certificate) -> Result<String> {
+    let spki = Asn1::from_ber(&certificate.subject_public_key_info)?;
+    let (algorithm, parameters) = parse_algorithm(spki.child(0)?)?;
+    let mut description = oid_name(&algorithm);
+    match certificate.public_key() {
+        Ok(PublicKey::Rsa(key)) => description.push_str(&format!(" ({} bit)", key.n().bits())),
+        Ok(PublicKey::Ec(..) | PublicKey::Sm2(_)) => {
+            if let Some(curve) = parameters.filter(|p| p.tag == Tag::OBJECT_IDENTIFIER) {
+                description.push_str(&format!(" ({})", oid_name(&curve.as_oid()?)));
+            }
+        }
+        _ => {}
+    }
+    Ok(description)
+}
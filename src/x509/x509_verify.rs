@@ -0,0 +1,131 @@
+use crate::bytes::Bytes;
+use crate::key::oid_name;
+use crate::operation::Operation;
+use crate::types::Result;
+use crate::x509::Certificate;
+
+// 输入证书链 (PEM、DER 或 PKCS#7), 离线逐级验证签名
+// 从未签发输入中其他证书的终端证书开始 (PKCS#7 中证书无序)
+// 按颁发者名称在输入及可信证书中查找上级证书, 直到可信证书或自签名的根证书
+// 验证通过时输出链路, 否则返回错误; 不检查有效期、用途与吊销状态
+#[derive(Debug, Default)]
+pub struct X509Verify {
+    // 为空时信任链末端的自签名证书
+    pub trusted: Vec<Certificate>,
+    // SM2 签名的用户 ID, 为 None 时依次尝试 GM/T 0009 默认 ID 与空 ID
+    pub sm2_user_id: Option<Bytes>,
+}
+
+impl X509Verify {
+    pub fn new(trusted: &[Certificate]) -> Self {
+        Self {
+            trusted: trusted.to_vec(),
+            sm2_user_id: None,
+        }
+    }
+
+    pub fn with_sm2_user_id(trusted: &[Certificate], sm2_user_id: &[u8]) -> Self {
+        Self {
+            sm2_user_id: Some(Bytes::new(sm2_user_id)),
+            ..Self::new(trusted)
+        }
+    }
+
+    fn verify_signature(&self, certificate: &Certificate, issuer: &Certificate) -> Result<bool> {
+        certificate.verify_signature_with_sm2_id(issuer, self.sm2_user_id.as_deref())
+    }
+
+    // 名称匹配且签名验证通过的颁发者证书
+    fn find_issuer<'a>(
+        &self,
+        certificate: &Certificate,
+        candidates: impl IntoIterator<Item = &'a Certificate>,
+    ) -> Result<Option<&'a Certificate>> {
+        for candidate in candidates {
+            if candidate.subject.matches(&certificate.issuer)
+                && self.verify_signature(certificate, candidate)?
+            {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Operation for X509Verify {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let certificates = Certificate::parse_all(input)?;
+        let issued = |issuer: &Certificate| {
+            certificates
+                .iter()
+                .any(|c| c.der[..] != issuer.der[..] && c.issuer.matches(&issuer.subject))
+        };
+        let mut current = certificates
+            .iter()
+            .find(|c| !issued(c))
+            .or(certificates.first())
+            .ok_or("[X509Verify] no certificate found")?;
+        let mut output = String::new();
+
+        // 每一级至少消耗一个证书, 防止循环
+        for depth in 0..=certificates.len() {
+            output.push_str(&format!("{}: {}\n", depth, current.subject));
+            let algorithm = oid_name(&current.signature_algorithm);
+
+            if let Some(anchor) = self.find_issuer(current, &self.trusted)? {
+                output.push_str(&format!(
+                    "   {} signature verified by trusted certificate {}\n",
+                    algorithm, anchor.subject
+                ));
+                return Ok(Bytes::new(output.as_bytes()));
+            }
+
+            if current.is_self_signed() {
+                if !self.verify_signature(current, current)? {
+                    return Err(format!(
+                        "[X509Verify] invalid self-signature of {}",
+                        current.subject
+                    )
+                    .into());
+                }
+                if !self.trusted.is_empty() {
+                    return Err(format!(
+                        "[X509Verify] self-signed certificate {} is not trusted",
+                        current.subject
+                    )
+                    .into());
+                }
+                output.push_str(&format!("   {} self-signature verified\n", algorithm));
+                return Ok(Bytes::new(output.as_bytes()));
+            }
+
+            let others = || certificates.iter().filter(|c| c.der[..] != current.der[..]);
+            let issuer = match self.find_issuer(current, others())? {
+                Some(issuer) => issuer,
+                None if self
+                    .trusted
+                    .iter()
+                    .chain(others())
+                    .any(|c| c.subject.matches(&current.issuer)) =>
+                {
+                    return Err(
+                        format!("[X509Verify] invalid signature of {}", current.subject).into(),
+                    )
+                }
+                None => {
+                    return Err(format!(
+                        "[X509Verify] issuer certificate not found: {}",
+                        current.issuer
+                    )
+                    .into())
+                }
+            };
+            current = issuer;
+            output.push_str(&format!(
+                "   {} signature verified by {}\n",
+                algorithm, current.subject
+            ));
+        }
+        Err("[X509Verify] certificate chain contains a loop".into())
+    }
+}
@@ -0,0 +1,317 @@
+use crate::bytes::Bytes;
+use crate::operation::Operation;
+use crate::types::Result;
+
+// ZUC-128 (GM/T 0001-2012, 3GPP 128-EEA3 / 128-EIA3)
+
+const S0: [u8; 256] = [
+    0x3e, 0x72, 0x5b, 0x47, 0xca, 0xe0, 0x00, 0x33, 0x04, 0xd1, 0x54, 0x98, 0x09, 0xb9, 0x6d, 0xcb,
+    0x7b, 0x1b, 0xf9, 0x32, 0xaf, 0x9d, 0x6a, 0xa5, 0xb8, 0x2d, 0xfc, 0x1d, 0x08, 0x53, 0x03, 0x90,
+    0x4d, 0x4e, 0x84, 0x99, 0xe4, 0xce, 0xd9, 0x91, 0xdd, 0xb6, 0x85, 0x48, 0x8b, 0x29, 0x6e, 0xac,
+    0xcd, 0xc1, 0xf8, 0x1e, 0x73, 0x43, 0x69, 0xc6, 0xb5, 0xbd, 0xfd, 0x39, 0x63, 0x20, 0xd4, 0x38,
+    0x76, 0x7d, 0xb2, 0xa7, 0xcf, 0xed, 0x57, 0xc5, 0xf3, 0x2c, 0xbb, 0x14, 0x21, 0x06, 0x55, 0x9b,
+    0xe3, 0xef, 0x5e, 0x31, 0x4f, 0x7f, 0x5a, 0xa4, 0x0d, 0x82, 0x51, 0x49, 0x5f, 0xba, 0x58, 0x1c,
+    0x4a, 0x16, 0xd5, 0x17, 0xa8, 0x92, 0x24, 0x1f, 0x8c, 0xff, 0xd8, 0xae, 0x2e, 0x01, 0xd3, 0xad,
+    0x3b, 0x4b, 0xda, 0x46, 0xeb, 0xc9, 0xde, 0x9a, 0x8f, 0x87, 0xd7, 0x3a, 0x80, 0x6f, 0x2f, 0xc8,
+    0xb1, 0xb4, 0x37, 0xf7, 0x0a, 0x22, 0x13, 0x28, 0x7c, 0xcc, 0x3c, 0x89, 0xc7, 0xc3, 0x96, 0x56,
+    0x07, 0xbf, 0x7e, 0xf0, 0x0b, 0x2b, 0x97, 0x52, 0x35, 0x41, 0x79, 0x61, 0xa6, 0x4c, 0x10, 0xfe,
+    0xbc, 0x26, 0x95, 0x88, 0x8a, 0xb0, 0xa3, 0xfb, 0xc0, 0x18, 0x94, 0xf2, 0xe1, 0xe5, 0xe9, 0x5d,
+    0xd0, 0xdc, 0x11, 0x66, 0x64, 0x5c, 0xec, 0x59, 0x42, 0x75, 0x12, 0xf5, 0x74, 0x9c, 0xaa, 0x23,
+    0x0e, 0x86, 0xab, 0xbe, 0x2a, 0x02, 0xe7, 0x67, 0xe6, 0x44, 0xa2, 0x6c, 0xc2, 0x93, 0x9f, 0xf1,
+    0xf6, 0xfa, 0x36, 0xd2, 0x50, 0x68, 0x9e, 0x62, 0x71, 0x15, 0x3d, 0xd6, 0x40, 0xc4, 0xe2, 0x0f,
+    0x8e, 0x83, 0x77, 0x6b, 0x25, 0x05, 0x3f, 0x0c, 0x30, 0xea, 0x70, 0xb7, 0xa1, 0xe8, 0xa9, 0x65,
+    0x8d, 0x27, 0x1a, 0xdb, 0x81, 0xb3, 0xa0, 0xf4, 0x45, 0x7a, 0x19, 0xdf, 0xee, 0x78, 0x34, 0x60,
+];
+
+const S1: [u8; 256] = [
+    0x55, 0xc2, 0x63, 0x71, 0x3b, 0xc8, 0x47, 0x86, 0x9f, 0x3c, 0xda, 0x5b, 0x29, 0xaa, 0xfd, 0x77,
+    0x8c, 0xc5, 0x94, 0x0c, 0xa6, 0x1a, 0x13, 0x00, 0xe3, 0xa8, 0x16, 0x72, 0x40, 0xf9, 0xf8, 0x42,
+    0x44, 0x26, 0x68, 0x96, 0x81, 0xd9, 0x45, 0x3e, 0x10, 0x76, 0xc6, 0xa7, 0x8b, 0x39, 0x43, 0xe1,
+    0x3a, 0xb5, 0x56, 0x2a, 0xc0, 0x6d, 0xb3, 0x05, 0x22, 0x66, 0xbf, 0xdc, 0x0b, 0xfa, 0x62, 0x48,
+    0xdd, 0x20, 0x11, 0x06, 0x36, 0xc9, 0xc1, 0xcf, 0xf6, 0x27, 0x52, 0xbb, 0x69, 0xf5, 0xd4, 0x87,
+    0x7f, 0x84, 0x4c, 0xd2, 0x9c, 0x57, 0xa4, 0xbc, 0x4f, 0x9a, 0xdf, 0xfe, 0xd6, 0x8d, 0x7a, 0xeb,
+    0x2b, 0x53, 0xd8, 0x5c, 0xa1, 0x14, 0x17, 0xfb, 0x23, 0xd5, 0x7d, 0x30, 0x67, 0x73, 0x08, 0x09,
+    0xee, 0xb7, 0x70, 0x3f, 0x61, 0xb2, 0x19, 0x8e, 0x4e, 0xe5, 0x4b, 0x93, 0x8f, 0x5d, 0xdb, 0xa9,
+    0xad, 0xf1, 0xae, 0x2e, 0xcb, 0x0d, 0xfc, 0xf4, 0x2d, 0x46, 0x6e, 0x1d, 0x97, 0xe8, 0xd1, 0xe9,
+    0x4d, 0x37, 0xa5, 0x75, 0x5e, 0x83, 0x9e, 0xab, 0x82, 0x9d, 0xb9, 0x1c, 0xe0, 0xcd, 0x49, 0x89,
+    0x01, 0xb6, 0xbd, 0x58, 0x24, 0xa2, 0x5f, 0x38, 0x78, 0x99, 0x15, 0x90, 0x50, 0xb8, 0x95, 0xe4,
+    0xd0, 0x91, 0xc7, 0xce, 0xed, 0x0f, 0xb4, 0x6f, 0xa0, 0xcc, 0xf0, 0x02, 0x4a, 0x79, 0xc3, 0xde,
+    0xa3, 0xef, 0xea, 0x51, 0xe6, 0x6b, 0x18, 0xec, 0x1b, 0x2c, 0x80, 0xf7, 0x74, 0xe7, 0xff, 0x21,
+    0x5a, 0x6a, 0x54, 0x1e, 0x41, 0x31, 0x92, 0x35, 0xc4, 0x33, 0x07, 0x0a, 0xba, 0x7e, 0x0e, 0x34,
+    0x88, 0xb1, 0x98, 0x7c, 0xf3, 0x3d, 0x60, 0x6c, 0x7b, 0xca, 0xd3, 0x1f, 0x32, 0x65, 0x04, 0x28,
+    0x64, 0xbe, 0x85, 0x9b, 0x2f, 0x59, 0x8a, 0xd7, 0xb0, 0x25, 0xac, 0xaf, 0x12, 0x03, 0xe2, 0xf2,
+];
+
+// 密钥装入时使用的 15 位常量
+const D: [u32; 16] = [
+    0x44d7, 0x26bc, 0x626b, 0x135e, 0x5789, 0x35e2, 0x7135, 0x09af, 0x4d78, 0x2f13, 0x6bc4, 0x1af1,
+    0x5e26, 0x3c4d, 0x789a, 0x47ac,
+];
+
+// 模 2^31 - 1
+const MODULUS: u32 = 0x7fffffff;
+
+// 密钥和 iv 均为 16 字节
+#[derive(Debug)]
+pub struct Zuc {
+    pub key: Bytes,
+    pub iv: Bytes,
+}
+
+impl Zuc {
+    pub fn new(key: &[u8], iv: &[u8]) -> Self {
+        Self {
+            key: Bytes::new(key),
+            iv: Bytes::new(iv),
+        }
+    }
+}
+
+// 128-EEA3 机密性算法
+// length 为消息的比特长度, 默认为输入的全部比特
+#[derive(Debug)]
+pub struct Eea3 {
+    pub key: Bytes,
+    pub count: u32,
+    pub bearer: u8,    // 5 位
+    pub direction: u8, // 1 位
+    pub length: Option<usize>,
+}
+
+impl Eea3 {
+    pub fn new(key: &[u8], count: u32, bearer: u8, direction: u8) -> Self {
+        Self {
+            key: Bytes::new(key),
+            count,
+            bearer,
+            direction,
+            length: None,
+        }
+    }
+
+    pub fn with_length(key: &[u8], count: u32, bearer: u8, direction: u8, length: usize) -> Self {
+        Self {
+            length: Some(length),
+            ..Self::new(key, count, bearer, direction)
+        }
+    }
+}
+
+// 128-EIA3 完整性算法 输出 32 位 MAC
+#[derive(Debug)]
+pub struct Eia3 {
+    pub key: Bytes,
+    pub count: u32,
+    pub bearer: u8,
+    pub direction: u8,
+    pub length: Option<usize>,
+}
+
+impl Eia3 {
+    pub fn new(key: &[u8], count: u32, bearer: u8, direction: u8) -> Self {
+        Self {
+            key: Bytes::new(key),
+            count,
+            bearer,
+            direction,
+            length: None,
+        }
+    }
+
+    pub fn with_length(key: &[u8], count: u32, bearer: u8, direction: u8, length: usize) -> Self {
+        Self {
+            length: Some(length),
+            ..Self::new(key, count, bearer, direction)
+        }
+    }
+}
+
+fn add_mod(a: u32, b: u32) -> u32 {
+    let c = a + b;
+    (c & MODULUS) + (c >> 31)
+}
+
+// 模 2^31 - 1 下乘以 2^k 即循环左移 k 位
+fn mul_pow2(x: u32, k: u32) -> u32 {
+    ((x << k) | (x >> (31 - k))) & MODULUS
+}
+
+fn l1(x: u32) -> u32 {
+    x ^ x.rotate_left(2) ^ x.rotate_left(10) ^ x.rotate_left(18) ^ x.rotate_left(24)
+}
+
+fn l2(x: u32) -> u32 {
+    x ^ x.rotate_left(8) ^ x.rotate_left(14) ^ x.rotate_left(22) ^ x.rotate_left(30)
+}
+
+fn s_box(x: u32) -> u32 {
+    let [a, b, c, d] = x.to_be_bytes();
+    u32::from_be_bytes([
+        S0[a as usize],
+        S1[b as usize],
+        S0[c as usize],
+        S1[d as usize],
+    ])
+}
+
+struct State {
+    lfsr: [u32; 16],
+    r1: u32,
+    r2: u32,
+}
+
+impl State {
+    fn new(key: &[u8], iv: &[u8]) -> Self {
+        let mut lfsr = [0u32; 16];
+        for (i, s) in lfsr.iter_mut().enumerate() {
+            *s = (key[i] as u32) << 23 | D[i] << 8 | iv[i] as u32;
+        }
+        let mut state = Self { lfsr, r1: 0, r2: 0 };
+
+        // 初始化 32 轮, 非线性函数的输出参与反馈
+        for _ in 0..32 {
+            let x = state.bit_reorganization();
+            let w = state.f(x);
+            state.lfsr_shift(w >> 1);
+        }
+        // 工作模式 丢弃第一个输出
+        let x = state.bit_reorganization();
+        state.f(x);
+        state.lfsr_shift(0);
+        state
+    }
+
+    fn bit_reorganization(&self) -> [u32; 4] {
+        let s = &self.lfsr;
+        [
+            (s[15] & 0x7fff8000) << 1 | (s[14] & 0xffff),
+            (s[11] & 0xffff) << 16 | s[9] >> 15,
+            (s[7] & 0xffff) << 16 | s[5] >> 15,
+            (s[2] & 0xffff) << 16 | s[0] >> 15,
+        ]
+    }
+
+    fn f(&mut self, x: [u32; 4]) -> u32 {
+        let w = (x[0] ^ self.r1).wrapping_add(self.r2);
+        let w1 = self.r1.wrapping_add(x[1]);
+        let w2 = self.r2 ^ x[2];
+        self.r1 = s_box(l1(w1 << 16 | w2 >> 16));
+        self.r2 = s_box(l2(w2 << 16 | w1 >> 16));
+        w
+    }
+
+    // s16 = 2^15 s15 + 2^17 s13 + 2^21 s10 + 2^20 s4 + (1 + 2^8) s0 (+ u)
+    fn lfsr_shift(&mut self, u: u32) {
+        let s = &self.lfsr;
+        let mut v = s[0];
+        for (x, k) in [(s[0], 8), (s[4], 20), (s[10], 21), (s[13], 17), (s[15], 15)] {
+            v = add_mod(v, mul_pow2(x, k));
+        }
+        v = add_mod(v, u);
+        if v == 0 {
+            v = MODULUS;
+        }
+        self.lfsr.rotate_left(1);
+        self.lfsr[15] = v;
+    }
+
+    fn next(&mut self) -> u32 {
+        let x = self.bit_reorganization();
+        let z = self.f(x) ^ x[3];
+        self.lfsr_shift(0);
+        z
+    }
+}
+
+fn check(key: &[u8], iv: &[u8]) -> Result<()> {
+    if key.len() != 16 {
+        return Err("[Zuc] key length must be 16 bytes".into());
+    }
+    if iv.len() != 16 {
+        return Err("[Zuc] iv length must be 16 bytes".into());
+    }
+    Ok(())
+}
+
+// 生成 32 位字的密钥流
+pub(crate) fn keystream(key: &[u8], iv: &[u8], words: usize) -> Vec<u32> {
+    let mut state = State::new(key, iv);
+    (0..words).map(|_| state.next()).collect()
+}
+
+fn keystream_xor(key: &[u8], iv: &[u8], input: &[u8]) -> Vec<u8> {
+    keystream(key, iv, input.len().div_ceil(4))
+        .iter()
+        .flat_map(|z| z.to_be_bytes())
+        .zip(input)
+        .map(|(k, byte)| k ^ byte)
+        .collect()
+}
+
+// 比特长度 不得超过输入
+fn bit_length(length: Option<usize>, input: &[u8], name: &str) -> Result<usize> {
+    match length {
+        Some(length) if length > input.len() * 8 => {
+            Err(format!("[{}] length exceeds input", name).into())
+        }
+        Some(length) => Ok(length),
+        None => Ok(input.len() * 8),
+    }
+}
+
+impl Operation for Zuc {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        check(&self.key, &self.iv)?;
+        Ok(Bytes::new(keystream_xor(&self.key, &self.iv, input)))
+    }
+}
+
+impl Operation for Eea3 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let length = bit_length(self.length, input, "Eea3")?;
+        let mut iv = [0u8; 16];
+        iv[0..4].copy_from_slice(&self.count.to_be_bytes());
+        iv[4] = (self.bearer & 0x1f) << 3 | (self.direction & 1) << 2;
+        iv.copy_within(0..8, 8);
+        check(&self.key, &iv)?;
+
+        // 截断到比特长度 末尾多余的位置零
+        let mut output = keystream_xor(&self.key, &iv, &input[..length.div_ceil(8)]);
+        if length % 8 != 0 {
+            if let Some(last) = output.last_mut() {
+                *last &= 0xff << (8 - length % 8);
+            }
+        }
+        Ok(Bytes::new(output))
+    }
+}
+
+impl Operation for Eia3 {
+    fn run(&self, input: &[u8]) -> Result<Bytes> {
+        let length = bit_length(self.length, input, "Eia3")?;
+        let mut iv = [0u8; 16];
+        iv[0..4].copy_from_slice(&self.count.to_be_bytes());
+        iv[4] = (self.bearer & 0x1f) << 3;
+        iv.copy_within(0..8, 8);
+        iv[8] ^= (self.direction & 1) << 7;
+        iv[14] ^= (self.direction & 1) << 7;
+        check(&self.key, &iv)?;
+
+        let z = keystream(&self.key, &iv, (length + 64).div_ceil(32));
+        // 从第 i 位开始的 32 位密钥字
+        let word = |i: usize| match i % 32 {
+            0 => z[i / 32],
+            shift => z[i / 32] << shift | z[i / 32 + 1] >> (32 - shift),
+        };
+
+        let mut t = 0u32;
+        for i in 0..length {
+            if input[i / 8] >> (7 - i % 8) & 1 == 1 {
+                t ^= word(i);
+            }
+        }
+        t ^= word(length);
+        let mac = t ^ z[z.len() - 1];
+        Ok(Bytes::new(mac.to_be_bytes().as_ref()))
+    }
+}